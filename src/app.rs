@@ -1,14 +1,24 @@
-use crate::enums::{InputMode, Library, Menu, SearchMenu};
+use crate::duplicates::DuplicateGroup;
+use crate::enums::{
+    ExportSource, InputMode, InsertPosition, Library, Menu, SearchMenu, TextInputAction,
+};
 use crate::handlers::key_event::handle_key_event;
 use crate::handlers::key_event::search_input;
-use crate::spotify::player::player::process_currently_playing;
-use crate::structs::{Key, Settings, Themes};
+use crate::import::matching::ImportMatch;
+use crate::lyrics::lrc::load_lyrics;
+use crate::spotify::player::player::{interpolate_progress, process_currently_playing};
+use crate::spotify::player::queue::process_queue;
+use crate::structs::{Key, LyricLine, Settings, Themes};
+use crate::ui::album_art::draw_album_art_graphics;
 use crate::ui::tui;
 use crate::ui::ui::render_frame;
 use crossterm::event::{self, Event};
+use image::RgbImage;
+use ratatui::layout::Rect;
 use ratatui::widgets::{ListState, TableState};
+use std::collections::{HashMap, HashSet};
 use std::io;
-use std::sync::mpsc::Receiver;
+use std::sync::mpsc::{Receiver, Sender};
 use std::time::{Duration, Instant};
 
 #[derive(Clone, Debug)]
@@ -44,16 +54,21 @@ pub struct App {
     pub album_links_search_results: Vec<String>,
     pub track_links_search_results: Vec<String>,
     pub playlist_links_search_results: Vec<String>,
+    pub show_names_search_results: Vec<String>,
+    pub show_links_search_results: Vec<String>,
+    pub show_descriptions_search_results: Vec<String>,
     pub artist_links_search_results: Vec<String>,
 
     pub album_index: usize,
     pub track_index: usize,
     pub playlist_index: usize,
+    pub show_index: usize,
     pub artist_index: usize,
 
     pub selected_album_in_search_result: bool,
     pub selected_track_in_search_result: bool,
     pub selected_playlist_in_search_result: bool,
+    pub selected_show_in_search_result: bool,
     pub selected_artist_in_search_result: bool,
     pub selected_search: bool,
 
@@ -61,6 +76,7 @@ pub struct App {
     pub album_state_in_search_result: ListState,
     pub track_state_in_search_result: ListState,
     pub playlist_state_in_search_result: ListState,
+    pub show_state_in_search_result: ListState,
     pub artist_state_in_search_result: ListState,
 
     pub selected_album_tracks_names: Vec<String>,
@@ -97,7 +113,16 @@ pub struct App {
     pub user_playlist_links: Vec<String>,
     pub user_playlist_track_links: Vec<String>,
 
+    // Which playlists the user owns or may edit, their visibility and the id of the current user
+    pub user_playlist_owned: Vec<bool>,
+    pub user_playlist_public: Vec<bool>,
+    pub user_playlist_collaborative: Vec<bool>,
+    pub user_id: String,
+    // Playlist versions the tracks were loaded from, edits are made against them
+    pub user_playlist_snapshot_ids: Vec<String>,
+
     pub user_playlist_track_duration: Vec<i64>,
+    pub user_playlist_track_positions: Vec<u32>,
 
     pub current_user_playlist: String,
     pub selected_playlist_uri: String,
@@ -122,6 +147,10 @@ pub struct App {
     pub liked_songs_state: TableState,
     pub liked_songs_index: usize,
     pub enter_for_playback_in_liked_song: bool,
+    // Liked state of every track checked so far, keyed by track id
+    pub liked_track_ids: HashMap<String, bool>,
+    // Tracks liked or unliked in this session, the player thread's checks never override them
+    pub liked_changed_ids: HashSet<String>,
 
     // Handles User's Saved Albums
     pub user_album_names: Vec<String>,
@@ -152,6 +181,42 @@ pub struct App {
     pub podcast_display: bool,
     pub podcast_state: TableState,
     pub podcast_index: usize,
+    pub podcast_descriptions: Vec<String>,
+    pub podcast_current_show_selected: bool,
+    pub enter_for_playback_in_podcast: bool,
+
+    pub podcast_episode_names: Vec<String>,
+    pub podcast_episode_dates: Vec<String>,
+    pub podcast_episode_durations: Vec<i64>,
+    pub podcast_episode_resume_points: Vec<i64>,
+    pub podcast_episode_fully_played: Vec<bool>,
+    pub podcast_episode_links: Vec<String>,
+    pub podcast_episode_index: usize,
+    pub podcast_episode_state: TableState,
+    pub podcast_episode_display: bool,
+    pub podcast_episode_selected: bool,
+    pub podcast_episode_saved: Vec<bool>,
+    pub enter_for_playback_in_saved_episodes: bool,
+
+    // Handles User's Saved Audiobooks, their chapters use the episode table
+    pub audiobook_names: Vec<String>,
+    pub audiobook_ids: Vec<String>,
+    pub audiobook_authors: Vec<String>,
+    pub audiobook_narrators: Vec<String>,
+    pub audiobook_descriptions: Vec<String>,
+    pub audiobook_selected: bool,
+    pub audiobook_display: bool,
+    pub audiobook_state: TableState,
+    pub audiobook_index: usize,
+    pub audiobook_current_selected: bool,
+    pub enter_for_playback_in_audiobook: bool,
+
+    // Show whose episodes are listed, opened from the library or from search
+    pub selected_show_link: String,
+    pub selected_show_name: String,
+    pub selected_show_description: String,
+    pub selected_show_saved: bool,
+    pub show_link_to_save: String,
 
     // Handles User's Recently Played Songs
     pub recently_played_names: Vec<String>,
@@ -218,9 +283,12 @@ pub struct App {
     pub current_playing_name: String,
     pub current_playing_id: String,
     pub current_playing_album: String,
+    pub current_playing_album_uri: String,
+    pub current_playing_artist_uri: String,
     pub is_playing: bool,
     pub progress_bar_ratio: f64,
     pub currently_playing_media_type: String,
+    pub current_playing_image_url: String,
 
     // Handle New Release section
     pub new_release_artist: Vec<String>,
@@ -258,16 +326,134 @@ pub struct App {
     pub top_tracks_6_months_names: Vec<String>,
     pub top_tracks_4_weeks_names: Vec<String>,
 
+    // Rows selected for bulk actions, only valid while the table still has the same links
+    pub track_selection_links: Vec<String>,
+    pub track_selection_rows: Vec<usize>,
+    pub track_selection_anchor: usize,
+
     // Add track to playlist
     pub add_track_to_playlist_state: ListState,
     pub track_added_to_playlist_name: String,
     pub playlist_index_for_track_addition: usize,
-    pub track_added_to_playlist_link: String,
+    pub playlist_insert_position: InsertPosition,
+    /// Adding a whole album, playlist or artist skips tracks the playlist already has
+    pub add_whole_source_to_playlist: bool,
+    /// Playlist the user was warned already has the track, a second Enter adds it anyway
+    pub duplicate_warning_playlist_link: String,
+    pub track_added_to_playlist_links: Vec<String>,
     pub playlist_link_for_track_addition: String,
 
     // Follow/Unfollow Playlist
     pub playlist_link_to_follow: String,
     pub have_playlist: bool,
+
+    // Album Art
+    pub user_playlist_image_urls: Vec<String>,
+    pub user_album_image_urls: Vec<String>,
+    pub new_release_image_urls: Vec<String>,
+    // Decoded covers with the pixel size they were scaled to fit, most recently used last
+    pub album_art_images: Vec<(String, (u32, u32), RgbImage)>,
+    pub album_art_placements: Vec<(Rect, String)>,
+    pub album_art_drawn: Vec<(Rect, String)>,
+
+    // Help
+    pub help_origin_menu: Menu,
+    pub help_filter: String,
+    pub help_state: TableState,
+    pub help_index: usize,
+
+    // Theme Picker
+    pub theme_names: Vec<String>,
+    pub theme_picker_state: ListState,
+    pub theme_picker_index: usize,
+    pub theme_before_preview: String,
+
+    // Lyrics
+    pub lyrics_display: bool,
+    pub lyrics_lines: Vec<LyricLine>,
+    pub lyrics_synced: bool,
+    pub lyrics_loaded_for: String,
+
+    // Queue
+    pub selected_link_for_queue: String,
+    pub queue_names: Vec<String>,
+    pub queue_artist_names: Vec<String>,
+    pub queue_album_names: Vec<String>,
+    pub queue_durations: Vec<i64>,
+    pub queue_links: Vec<String>,
+    pub queue_has_current: bool,
+    pub queue_state: TableState,
+    pub queue_index: usize,
+
+    // Context the selected track is played in, a uri or else the list of track links
+    pub playback_context_uri: String,
+    pub playback_context_track_links: Vec<String>,
+    // Where the next playback starts, used to resume episodes
+    pub playback_start_position_ms: i64,
+
+    // Device Picker
+    pub device_names: Vec<String>,
+    pub device_types: Vec<String>,
+    pub device_volumes: Vec<String>,
+    pub device_ids: Vec<String>,
+    pub device_active: Vec<bool>,
+    pub device_picker_state: TableState,
+    pub device_picker_index: usize,
+    pub selected_device_id: String,
+
+    // Position typed by the user and the input mode to go back to afterwards
+    pub seek_input: String,
+    pub seek_return_mode: InputMode,
+
+    // Volume typed by the user, the input mode to go back to and the level to restore when unmuting
+    pub volume_input: String,
+    pub volume_return_mode: InputMode,
+    pub volume_before_mute: Option<u8>,
+
+    // Text typed into the input dialog, what it is used for once submitted and the input mode to go back to
+    pub text_input: String,
+    pub text_input_title: String,
+    pub text_input_action: TextInputAction,
+    pub text_input_return_mode: InputMode,
+
+    // Playlist Editor
+    pub playlist_editor_state: ListState,
+    pub playlist_editor_index: usize,
+    pub playlist_editor_description: String,
+
+    // Export popup
+    pub export_source: ExportSource,
+    pub export_source_name: String,
+    pub export_playlist_link: String,
+    pub export_format_state: ListState,
+    pub export_format_index: usize,
+
+    // Import review, the matches found for the lines of the imported file
+    pub import_matches: Vec<ImportMatch>,
+    pub import_review_state: TableState,
+    pub import_playlist_name: String,
+    // Playlist created by an import whose tracks could not be added yet, a retry fills it
+    pub import_playlist_link: String,
+
+    // Duplicates view, the groups found in the opened playlist or Liked Songs
+    pub duplicate_groups: Vec<DuplicateGroup>,
+    pub duplicates_state: TableState,
+    pub duplicates_index: usize,
+    pub duplicates_in_liked_songs: bool,
+    pub duplicates_source_name: String,
+
+    // Last polled playback position and when it was received
+    pub progress_synced_ms: f64,
+    pub progress_synced_at: Instant,
+
+    // Wakes the player thread so changes show up without waiting for the next poll
+    pub player_poll_wake: Option<Sender<()>>,
+    // Problem reported by the player thread, shown in the player bar
+    pub player_status_message: String,
+
+    // Short message shown over the screen for a few seconds, such as a change that was rolled back
+    pub toast_message: String,
+    pub toast_shown_at: Instant,
 }
 
 impl App {
@@ -275,7 +461,8 @@ impl App {
     pub fn run(
         &mut self,
         terminal: &mut tui::Tui,
-        rx1: Receiver<()>,
+        rx1: Receiver<Option<String>>,
+        liked_rx: Receiver<Vec<(String, bool)>>,
         keys: &mut Key,
         theme: &mut Themes,
         settings: &mut Settings,
@@ -303,13 +490,40 @@ impl App {
                 last_tick = now;
 
                 // Check if a message has been received from the player info update thread
-                if rx1.try_recv().is_ok() {
+                let mut player_updated = false;
+                while let Ok(message) = rx1.try_recv() {
+                    match message {
+                        Some(message) => self.player_status_message = message,
+                        None => {
+                            player_updated = true;
+                            self.player_status_message.clear();
+                        }
+                    }
+                }
+                if player_updated {
                     process_currently_playing(self, settings);
+                    load_lyrics(self, settings);
+                    process_queue(self);
+                }
+                // Likes and unlikes made here are newer than what the player thread checked,
+                // everything else follows the latest check so changes from other devices show up
+                while let Ok(liked) = liked_rx.try_recv() {
+                    for (id, liked) in liked {
+                        if !self.liked_changed_ids.contains(&id) {
+                            self.liked_track_ids.insert(id, liked);
+                        }
+                    }
                 }
 
+                interpolate_progress(self);
+
                 // Draw the UI
-                terminal
-                    .draw(|frame| render_frame(frame, self.selected_menu, self, keys, theme))?;
+                terminal.draw(|frame| {
+                    render_frame(frame, self.selected_menu, self, keys, theme, settings)
+                })?;
+
+                // Kitty and sixel images are written straight to the terminal after the frame
+                draw_album_art_graphics(self, terminal, settings)?;
             }
         }
 
@@ -318,6 +532,21 @@ impl App {
 
     pub fn exit(&mut self) {
         self.exit = true;
+        // Dropping the sender lets the player thread stop without waiting for its next poll
+        self.player_poll_wake = None;
+    }
+
+    /// Shows `message` in a toast for a few seconds
+    pub fn show_toast(&mut self, message: String) {
+        self.toast_message = message;
+        self.toast_shown_at = Instant::now();
+    }
+
+    /// Asks the player thread to poll right away, used after actions that change playback
+    pub fn request_player_refresh(&self) {
+        if let Some(wake) = &self.player_poll_wake {
+            let _ = wake.send(());
+        }
     }
 }
 
@@ -345,16 +574,21 @@ impl Default for App {
             track_links_search_results: Vec::new(),
             playlist_names_search_results: Vec::new(),
             playlist_links_search_results: Vec::new(),
+            show_names_search_results: Vec::new(),
+            show_links_search_results: Vec::new(),
+            show_descriptions_search_results: Vec::new(),
             artist_names_search_results: Vec::new(),
             artist_links_search_results: Vec::new(),
             album_state_in_search_result: ListState::default(),
             track_state_in_search_result: ListState::default(),
             playlist_state_in_search_result: ListState::default(),
+            show_state_in_search_result: ListState::default(),
             artist_state_in_search_result: ListState::default(),
             search_state: ListState::default(),
             selected_album_in_search_result: false,
             selected_track_in_search_result: false,
             selected_playlist_in_search_result: false,
+            selected_show_in_search_result: false,
             selected_artist_in_search_result: false,
             selected_search: false,
             search_results_rendered: false,
@@ -363,9 +597,18 @@ impl Default for App {
             user_playlist_links: Vec::new(),
             user_playlist_track_names: Vec::new(),
             user_playlist_track_duration: Vec::new(),
+            user_playlist_track_positions: Vec::new(),
+            track_selection_links: Vec::new(),
+            track_selection_rows: Vec::new(),
+            track_selection_anchor: 0,
             user_playlist_artist_names: Vec::new(),
             user_playlist_track_links: Vec::new(),
             user_playlist_album_names: Vec::new(),
+            user_playlist_owned: Vec::new(),
+            user_playlist_public: Vec::new(),
+            user_playlist_collaborative: Vec::new(),
+            user_id: String::new(),
+            user_playlist_snapshot_ids: Vec::new(),
             selected_playlist_uri: String::new(),
             current_user_playlist: String::new(),
             user_playlist_display: false,
@@ -427,9 +670,12 @@ impl Default for App {
             current_playing_name: String::new(),
             current_playing_id: String::new(),
             current_playing_album: String::new(),
+            current_playing_album_uri: String::new(),
+            current_playing_artist_uri: String::new(),
             is_playing: false,
             progress_bar_ratio: 0.0,
             currently_playing_media_type: String::new(),
+            current_playing_image_url: String::new(),
 
             new_release_artist: Vec::new(),
             new_release_name: Vec::new(),
@@ -452,11 +698,46 @@ impl Default for App {
             album_index: 0,
             track_index: 0,
             playlist_index: 0,
+            show_index: 0,
             artist_index: 0,
             user_playlist_index: 0,
             liked_songs_index: 0,
             user_album_index: 0,
             podcast_index: 0,
+            podcast_descriptions: Vec::new(),
+            podcast_current_show_selected: false,
+            enter_for_playback_in_podcast: false,
+
+            podcast_episode_names: Vec::new(),
+            podcast_episode_dates: Vec::new(),
+            podcast_episode_durations: Vec::new(),
+            podcast_episode_resume_points: Vec::new(),
+            podcast_episode_fully_played: Vec::new(),
+            podcast_episode_links: Vec::new(),
+            podcast_episode_index: 0,
+            podcast_episode_state: TableState::default(),
+            podcast_episode_display: false,
+            podcast_episode_selected: false,
+            podcast_episode_saved: Vec::new(),
+            enter_for_playback_in_saved_episodes: false,
+
+            audiobook_names: Vec::new(),
+            audiobook_ids: Vec::new(),
+            audiobook_authors: Vec::new(),
+            audiobook_narrators: Vec::new(),
+            audiobook_descriptions: Vec::new(),
+            audiobook_selected: false,
+            audiobook_display: false,
+            audiobook_state: TableState::default(),
+            audiobook_index: 0,
+            audiobook_current_selected: false,
+            enter_for_playback_in_audiobook: false,
+
+            selected_show_link: String::new(),
+            selected_show_name: String::new(),
+            selected_show_description: String::new(),
+            selected_show_saved: false,
+            show_link_to_save: String::new(),
             recently_played_index: 0,
             user_artist_index: 0,
             new_release_index: 0,
@@ -530,6 +811,8 @@ impl Default for App {
 
             enter_for_playback_in_user_playlist: false,
             enter_for_playback_in_liked_song: false,
+            liked_track_ids: HashMap::new(),
+            liked_changed_ids: HashSet::new(),
             enter_for_playback_in_user_album: false,
             enter_for_playback_in_recently_played: false,
             enter_for_playback_in_saved_artist: false,
@@ -546,12 +829,99 @@ impl Default for App {
 
             track_added_to_playlist_name: String::new(),
             playlist_index_for_track_addition: 0,
-            track_added_to_playlist_link: String::new(),
+            playlist_insert_position: InsertPosition::End,
+            add_whole_source_to_playlist: false,
+            duplicate_warning_playlist_link: String::new(),
+            track_added_to_playlist_links: Vec::new(),
             playlist_link_for_track_addition: String::new(),
             add_track_to_playlist_state: ListState::default(),
 
             playlist_link_to_follow: String::new(),
             have_playlist: true,
+
+            user_playlist_image_urls: Vec::new(),
+            user_album_image_urls: Vec::new(),
+            new_release_image_urls: Vec::new(),
+            album_art_images: Vec::new(),
+            album_art_placements: Vec::new(),
+            album_art_drawn: Vec::new(),
+
+            help_origin_menu: Menu::Default,
+            help_filter: String::new(),
+            help_state: TableState::default(),
+            help_index: 0,
+
+            theme_names: Vec::new(),
+            theme_picker_state: ListState::default(),
+            theme_picker_index: 0,
+            theme_before_preview: String::new(),
+
+            lyrics_display: false,
+            lyrics_lines: Vec::new(),
+            lyrics_synced: false,
+            lyrics_loaded_for: String::new(),
+
+            selected_link_for_queue: String::new(),
+            queue_names: Vec::new(),
+            queue_artist_names: Vec::new(),
+            queue_album_names: Vec::new(),
+            queue_durations: Vec::new(),
+            queue_links: Vec::new(),
+            queue_has_current: false,
+            queue_state: TableState::default(),
+            queue_index: 0,
+
+            playback_context_uri: String::new(),
+            playback_context_track_links: Vec::new(),
+            playback_start_position_ms: 0,
+
+            device_names: Vec::new(),
+            device_types: Vec::new(),
+            device_volumes: Vec::new(),
+            device_ids: Vec::new(),
+            device_active: Vec::new(),
+            device_picker_state: TableState::default(),
+            device_picker_index: 0,
+            selected_device_id: String::new(),
+
+            seek_input: String::new(),
+            seek_return_mode: InputMode::Normal,
+
+            volume_input: String::new(),
+            volume_return_mode: InputMode::Normal,
+            volume_before_mute: None,
+
+            text_input: String::new(),
+            text_input_title: String::new(),
+            text_input_action: TextInputAction::CreatePlaylist,
+            text_input_return_mode: InputMode::Normal,
+
+            playlist_editor_state: ListState::default(),
+            playlist_editor_index: 0,
+            playlist_editor_description: String::new(),
+            export_source: ExportSource::Playlist,
+            export_source_name: String::new(),
+            export_playlist_link: String::new(),
+            export_format_state: ListState::default(),
+            export_format_index: 0,
+            import_matches: Vec::new(),
+            import_review_state: TableState::default(),
+            import_playlist_name: String::new(),
+            import_playlist_link: String::new(),
+            duplicate_groups: Vec::new(),
+            duplicates_state: TableState::default(),
+            duplicates_index: 0,
+            duplicates_in_liked_songs: false,
+            duplicates_source_name: String::new(),
+
+            progress_synced_ms: 0.0,
+            progress_synced_at: Instant::now(),
+
+            player_poll_wake: None,
+            player_status_message: String::new(),
+
+            toast_message: String::new(),
+            toast_shown_at: Instant::now(),
         }
     }
 }
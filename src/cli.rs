@@ -0,0 +1,247 @@
+// Commands that run without the terminal UI, e.g. `spoify import playlist.csv --name "Imported"`
+// or `spoify snapshot` and `spoify restore <file>`
+
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::app::App;
+use crate::import::matching::ImportMatch;
+use crate::import::parse::read_import_file;
+use crate::import::playlist::create_imported_playlist;
+use crate::snapshot::archive::{default_snapshot_path, read_snapshot, write_snapshot};
+use crate::snapshot::plan::{describe_plan, plan_restore};
+use crate::spotify::import::match_tracks::match_import_entries;
+use crate::spotify::snapshot::restore::restore_snapshot;
+use crate::spotify::snapshot::take_snapshot::take_snapshot;
+use crate::spotify::user_playlist::user_playlist::get_playlists;
+
+const USAGE: &str = "Usage:
+    spoify import <file.txt|file.csv|file.m3u> [--name <playlist name>]
+    spoify snapshot [<file.json>]
+    spoify restore <file.json> [--dry-run]";
+
+pub enum Command {
+    Import { path: PathBuf, name: Option<String> },
+    Snapshot { path: Option<PathBuf> },
+    Restore { path: PathBuf, dry_run: bool },
+}
+
+/// Reads the command from the arguments, `None` starts the terminal UI
+pub fn parse_command(args: &[String]) -> Option<Result<Command, &'static str>> {
+    match args.first()?.as_str() {
+        "import" => {
+            let Some(path) = args.get(1).filter(|path| !path.starts_with("--")) else {
+                return Some(Err(USAGE));
+            };
+            let name = match args.iter().position(|arg| arg == "--name") {
+                Some(index) => match args.get(index + 1) {
+                    Some(name) => Some(name.clone()),
+                    None => return Some(Err(USAGE)),
+                },
+                None => None,
+            };
+            Some(Ok(Command::Import {
+                path: PathBuf::from(path),
+                name,
+            }))
+        }
+        "snapshot" => Some(Ok(Command::Snapshot {
+            path: args.get(1).map(PathBuf::from),
+        })),
+        "restore" => {
+            let Some(path) = args.get(1).filter(|path| !path.starts_with("--")) else {
+                return Some(Err(USAGE));
+            };
+            Some(Ok(Command::Restore {
+                path: PathBuf::from(path),
+                dry_run: args.iter().any(|arg| arg == "--dry-run"),
+            }))
+        }
+        "help" | "--help" | "-h" => Some(Err(USAGE)),
+        _ => None,
+    }
+}
+
+pub fn run_command(app: &mut App, command: Command) -> io::Result<()> {
+    match command {
+        Command::Import { path, name } => import_command(app, &path, name),
+        Command::Snapshot { path } => snapshot_command(app, path),
+        Command::Restore { path, dry_run } => restore_command(app, &path, dry_run),
+    }
+}
+
+fn snapshot_command(app: &mut App, path: Option<PathBuf>) -> io::Result<()> {
+    println!("Reading the library...");
+    let snapshot = match take_snapshot(app) {
+        Ok(snapshot) => snapshot,
+        Err(e) => {
+            eprintln!("Error reading the library: {}", e);
+            return Ok(());
+        }
+    };
+
+    let path = path.unwrap_or_else(default_snapshot_path);
+    write_snapshot(&path, &snapshot)?;
+    println!(
+        "Saved {} liked songs, {} albums, {} artists, {} shows, {} followed and {} own playlists to {}",
+        snapshot.liked_songs.len(),
+        snapshot.saved_albums.len(),
+        snapshot.followed_artists.len(),
+        snapshot.saved_shows.len(),
+        snapshot.followed_playlists.len(),
+        snapshot.playlists.len(),
+        path.display()
+    );
+
+    Ok(())
+}
+
+// Always shows what would change before touching the account
+fn restore_command(app: &mut App, path: &Path, dry_run: bool) -> io::Result<()> {
+    let snapshot = read_snapshot(path)?;
+    println!(
+        "Comparing the snapshot from {} with the library...",
+        snapshot.created_at
+    );
+    let current = match take_snapshot(app) {
+        Ok(current) => current,
+        Err(e) => {
+            eprintln!("Error reading the library: {}", e);
+            return Ok(());
+        }
+    };
+    if current.user_id != snapshot.user_id {
+        println!(
+            "The snapshot was taken from {}, restoring into {}",
+            snapshot.user_id, current.user_id
+        );
+    }
+
+    let plan = plan_restore(&snapshot, &current);
+    if plan.is_empty() {
+        println!("The library already matches the snapshot");
+        return Ok(());
+    }
+    for line in describe_plan(&plan) {
+        println!("{}", line);
+    }
+    if dry_run || !confirm("Apply these changes?", false)? {
+        return Ok(());
+    }
+
+    match restore_snapshot(app, &plan, &current.user_id) {
+        Ok(()) => {
+            println!("Restored the snapshot");
+            // Keep the cached sidebar in step for the next start
+            get_playlists(app);
+        }
+        Err(e) => eprintln!("Error restoring the snapshot: {}", e),
+    }
+
+    Ok(())
+}
+
+fn import_command(app: &mut App, path: &Path, name: Option<String>) -> io::Result<()> {
+    let entries = read_import_file(path)?;
+    if entries.is_empty() {
+        println!("No tracks found in {}", path.display());
+        return Ok(());
+    }
+
+    println!("Matching {} tracks...", entries.len());
+    let mut matches = match match_import_entries(app, entries) {
+        Ok(matches) => matches,
+        Err(e) => {
+            eprintln!("Error matching the tracks: {}", e);
+            return Ok(());
+        }
+    };
+    print_review(&matches);
+
+    let uncertain = matches
+        .iter()
+        .filter(|import_match| !import_match.accepted && !import_match.track_uri.is_empty())
+        .count();
+    if uncertain > 0
+        && confirm(
+            &format!("Also add the {} uncertain matches?", uncertain),
+            false,
+        )?
+    {
+        for import_match in matches.iter_mut() {
+            import_match.accepted |= !import_match.track_uri.is_empty();
+        }
+    }
+
+    let accepted = matches
+        .iter()
+        .filter(|import_match| import_match.accepted)
+        .count();
+    if accepted == 0 {
+        println!("Nothing to import");
+        return Ok(());
+    }
+    let name = name.unwrap_or_else(|| default_playlist_name(path));
+    if !confirm(
+        &format!("Create the playlist \"{}\" with {} tracks?", name, accepted),
+        true,
+    )? {
+        return Ok(());
+    }
+
+    // Loads the account id the playlist is created in
+    get_playlists(app);
+    match create_imported_playlist(app, &name, &matches) {
+        Ok(added) => {
+            println!("Created \"{}\" with {} tracks", name, added);
+            // Keep the cached sidebar in step for the next start
+            get_playlists(app);
+        }
+        Err(e) => eprintln!("Error creating the playlist: {}", e),
+    }
+
+    Ok(())
+}
+
+/// The file name without its extension
+pub fn default_playlist_name(path: &Path) -> String {
+    path.file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("Imported")
+        .to_string()
+}
+
+fn print_review(matches: &[ImportMatch]) {
+    for (index, import_match) in matches.iter().enumerate() {
+        let (mark, confidence, found) = if import_match.track_uri.is_empty() {
+            (' ', "  -".to_string(), "no match")
+        } else {
+            (
+                if import_match.accepted { 'x' } else { '?' },
+                format!("{:>3}%", import_match.confidence),
+                import_match.track_name.as_str(),
+            )
+        };
+        println!(
+            "{:>4}. [{}] {:>4}  {}  ->  {}",
+            index + 1,
+            mark,
+            confidence,
+            import_match.entry.source,
+            found
+        );
+    }
+}
+
+fn confirm(question: &str, default: bool) -> io::Result<bool> {
+    print!("{} {} ", question, if default { "[Y/n]" } else { "[y/N]" });
+    io::stdout().flush()?;
+
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer)?;
+    Ok(match answer.trim().to_lowercase().as_str() {
+        "y" | "yes" => true,
+        "n" | "no" => false,
+        _ => default,
+    })
+}
@@ -0,0 +1,225 @@
+// Finds repeated tracks in a track table, by Spotify id and by "same song, different release"
+
+use std::collections::HashMap;
+
+use crate::import::matching::{normalize_artist, normalize_title};
+use crate::spotify::library_section::saved_tracks::track_id;
+
+/// Releases of the same song can differ this much in length
+const SAME_SONG_DURATION_MS: i64 = 3_000;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DuplicateKind {
+    /// The same Spotify track more than once
+    SameTrack,
+    /// Different tracks with the same title, artist and length, e.g. the single and the album version
+    SameSong,
+}
+
+/// Rows of the table holding the same song, and which of them to keep
+#[derive(Clone, Debug)]
+pub struct DuplicateGroup {
+    pub kind: DuplicateKind,
+    pub rows: Vec<usize>,
+    pub keep: Vec<bool>,
+}
+
+impl DuplicateGroup {
+    // The first copy is kept until the user picks another one
+    fn new(kind: DuplicateKind, rows: Vec<usize>) -> Self {
+        let keep = (0..rows.len()).map(|index| index == 0).collect();
+        DuplicateGroup { kind, rows, keep }
+    }
+
+    /// Rows of the copies that are not kept
+    pub fn removed_rows(&self) -> impl Iterator<Item = usize> + '_ {
+        self.rows
+            .iter()
+            .zip(self.keep.iter())
+            .filter(|(_, keep)| !**keep)
+            .map(|(row, _)| *row)
+    }
+}
+
+/// Groups the rows of a track table that hold the same track or the same song, in table order.
+/// Every row is in one group at most, so keeping one copy per group always keeps the song.
+pub fn find_duplicates(
+    links: &[String],
+    names: &[String],
+    artists: &[String],
+    durations: &[i64],
+) -> Vec<DuplicateGroup> {
+    // Rows of every track id, ids in the order they first appear
+    let mut ids: Vec<String> = Vec::new();
+    let mut rows_by_id: HashMap<String, Vec<usize>> = HashMap::new();
+    for (row, link) in links.iter().enumerate() {
+        // Episodes are left alone
+        let Some(id) = track_id(link) else {
+            continue;
+        };
+        rows_by_id
+            .entry(id.clone())
+            .or_insert_with(|| {
+                ids.push(id);
+                Vec::new()
+            })
+            .push(row);
+    }
+
+    // The first row of every track id stands for it, compared by normalised title and artist, then by length
+    let mut songs: Vec<(String, String)> = Vec::new();
+    let mut ids_by_song: HashMap<(String, String), Vec<&String>> = HashMap::new();
+    for id in &ids {
+        let row = rows_by_id[id][0];
+        let title = normalize_title(names.get(row).map(String::as_str).unwrap_or_default());
+        if title.is_empty() {
+            continue;
+        }
+        let artist = normalize_artist(artists.get(row).map(String::as_str).unwrap_or_default());
+        ids_by_song
+            .entry((title.clone(), artist.clone()))
+            .or_insert_with(|| {
+                songs.push((title, artist));
+                Vec::new()
+            })
+            .push(id);
+    }
+    let duration = |id: &String| {
+        durations
+            .get(rows_by_id[id][0])
+            .copied()
+            .unwrap_or_default()
+    };
+    let mut same_songs = Vec::new();
+    for song in &songs {
+        let mut rest = ids_by_song[song].clone();
+        while let Some(first) = rest.first().copied() {
+            let (same, other): (Vec<&String>, Vec<&String>) = rest
+                .into_iter()
+                .partition(|id| (duration(id) - duration(first)).abs() <= SAME_SONG_DURATION_MS);
+            if same.len() > 1 {
+                same_songs.push(same);
+            }
+            rest = other;
+        }
+    }
+
+    // Repeats of a track that has other releases are listed with them instead of on their own
+    let mut groups = Vec::new();
+    for id in &ids {
+        let in_same_song = same_songs.iter().any(|same| same.contains(&id));
+        if rows_by_id[id].len() > 1 && !in_same_song {
+            groups.push(DuplicateGroup::new(
+                DuplicateKind::SameTrack,
+                rows_by_id[id].clone(),
+            ));
+        }
+    }
+    for same in same_songs {
+        let mut rows: Vec<usize> = same
+            .iter()
+            .flat_map(|id| rows_by_id[*id].iter().copied())
+            .collect();
+        rows.sort_unstable();
+        groups.push(DuplicateGroup::new(DuplicateKind::SameSong, rows));
+    }
+
+    groups
+}
+
+/// Every copy listed in the duplicates view, as (group, copy within the group)
+pub fn duplicate_entries(groups: &[DuplicateGroup]) -> Vec<(usize, usize)> {
+    groups
+        .iter()
+        .enumerate()
+        .flat_map(|(group, duplicates)| (0..duplicates.rows.len()).map(move |copy| (group, copy)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // (track id, title, artist, duration in seconds) for each row
+    fn find(rows: &[(&str, &str, &str, i64)]) -> Vec<(DuplicateKind, Vec<usize>)> {
+        let links: Vec<String> = rows
+            .iter()
+            .map(|(id, ..)| format!("spotify:track:{}", id))
+            .collect();
+        let names: Vec<String> = rows.iter().map(|row| row.1.to_string()).collect();
+        let artists: Vec<String> = rows.iter().map(|row| row.2.to_string()).collect();
+        let durations: Vec<i64> = rows.iter().map(|row| row.3 * 1000).collect();
+
+        find_duplicates(&links, &names, &artists, &durations)
+            .into_iter()
+            .map(|group| (group.kind, group.rows))
+            .collect()
+    }
+
+    #[test]
+    fn same_track_twice() {
+        let groups = find(&[
+            ("track1", "Song", "Artist", 200),
+            ("track2", "Other", "Artist", 180),
+            ("track1", "Song", "Artist", 200),
+        ]);
+
+        assert_eq!(groups, vec![(DuplicateKind::SameTrack, vec![0, 2])]);
+    }
+
+    #[test]
+    fn same_song_needs_a_close_length() {
+        let groups = find(&[
+            ("single", "Song", "Artist", 200),
+            ("album", "Song - Remastered", "Artist", 202),
+            ("live", "Song (Live)", "Artist", 260),
+        ]);
+
+        assert_eq!(groups, vec![(DuplicateKind::SameSong, vec![0, 1])]);
+    }
+
+    #[test]
+    fn repeats_of_a_song_with_other_releases_share_one_group() {
+        let groups = find(&[
+            ("single", "Song", "Artist", 200),
+            ("album", "Song (Remastered)", "Artist", 201),
+            ("single", "Song", "Artist", 200),
+            ("repeat", "Other", "Artist", 150),
+            ("repeat", "Other", "Artist", 150),
+        ]);
+
+        assert_eq!(
+            groups,
+            vec![
+                (DuplicateKind::SameTrack, vec![3, 4]),
+                (DuplicateKind::SameSong, vec![0, 1, 2]),
+            ]
+        );
+    }
+
+    #[test]
+    fn groups_never_share_rows() {
+        let groups = find(&[
+            ("a", "Song", "Artist", 200),
+            ("b", "Song", "Artist", 200),
+            ("a", "Song", "Artist", 200),
+            ("b", "Song", "Artist", 200),
+            ("c", "Song", "Someone Else", 200),
+            ("c", "Song", "Someone Else", 200),
+        ]);
+
+        let mut rows: Vec<usize> = groups.iter().flat_map(|(_, rows)| rows.clone()).collect();
+        let listed = rows.len();
+        rows.sort_unstable();
+        rows.dedup();
+        assert_eq!(rows.len(), listed);
+        assert_eq!(rows, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn first_copy_is_kept() {
+        let group = DuplicateGroup::new(DuplicateKind::SameTrack, vec![4, 7, 9]);
+
+        assert_eq!(group.removed_rows().collect::<Vec<_>>(), vec![7, 9]);
+    }
+}
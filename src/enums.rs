@@ -6,6 +6,8 @@ pub enum Library {
     Albums,
     Artists,
     Podcasts,
+    Episodes,
+    Audiobooks,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Menu {
@@ -19,6 +21,13 @@ pub enum Menu {
     Error,
     Player,
     AddTrackToPlaylist,
+    ThemePicker,
+    Queue,
+    DevicePicker,
+    PlaylistEditor,
+    Export,
+    ImportReview,
+    Duplicates,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum SearchMenu {
@@ -27,12 +36,55 @@ pub enum SearchMenu {
     SearchedArtist,
     SearchedPlaylist,
     SearchedTrack,
+    SearchedShow,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum InputMode {
     Normal,
     Editing,
     SearchResults,
+    HelpSearch,
+    SeekInput,
+    VolumeInput,
+    TextInput,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TextInputAction {
+    CreatePlaylist,
+    RenamePlaylist,
+    EditPlaylistDescription,
+    MovePlaylistTrack,
+    InsertPosition,
+    ImportFile,
+    ImportPlaylistName,
+}
+/// Where tracks added to a playlist go
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum InsertPosition {
+    End,
+    Top,
+    /// Zero based position in the playlist
+    At(u32),
+}
+/// What the export popup writes out
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ExportSource {
+    Playlist,
+    LikedSongs,
+    SavedAlbums,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ExportFormat {
+    M3u,
+    Csv,
+    Json,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ImageProtocol {
+    Kitty,
+    Sixel,
+    HalfBlocks,
+    Off,
 }
 
 impl From<Menu> for usize {
@@ -48,6 +100,13 @@ impl From<Menu> for usize {
             Menu::Error => 7,
             Menu::Player => 8,
             Menu::AddTrackToPlaylist => 9,
+            Menu::ThemePicker => 10,
+            Menu::Queue => 11,
+            Menu::DevicePicker => 12,
+            Menu::PlaylistEditor => 13,
+            Menu::Export => 14,
+            Menu::ImportReview => 15,
+            Menu::Duplicates => 16,
         }
     }
 }
@@ -60,6 +119,8 @@ impl From<Library> for usize {
             Library::Albums => 3,
             Library::Artists => 4,
             Library::Podcasts => 5,
+            Library::Episodes => 6,
+            Library::Audiobooks => 7,
         }
     }
 }
@@ -71,6 +132,7 @@ impl From<SearchMenu> for usize {
             SearchMenu::SearchedArtist => 2,
             SearchMenu::SearchedPlaylist => 3,
             SearchMenu::SearchedTrack => 4,
+            SearchMenu::SearchedShow => 5,
         }
     }
 }
@@ -0,0 +1,216 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use rspotify::model::FullTrack;
+use rspotify::prelude::Id;
+use serde::Serialize;
+
+use crate::enums::ExportFormat;
+use crate::structs::Settings;
+
+/// Formats in the order the export popup lists them
+pub const EXPORT_FORMATS: [ExportFormat; 3] =
+    [ExportFormat::M3u, ExportFormat::Csv, ExportFormat::Json];
+
+/// One track of an exported list
+#[derive(Serialize)]
+pub struct ExportTrack {
+    pub title: String,
+    pub artists: Vec<String>,
+    pub album: String,
+    pub duration_ms: i64,
+    pub isrc: String,
+    pub uri: String,
+}
+
+impl ExportTrack {
+    pub fn from_track(track: &FullTrack) -> Self {
+        ExportTrack {
+            title: track.name.clone(),
+            artists: track
+                .artists
+                .iter()
+                .map(|artist| artist.name.clone())
+                .collect(),
+            album: track.album.name.clone(),
+            duration_ms: track.duration.num_milliseconds(),
+            isrc: track.external_ids.get("isrc").cloned().unwrap_or_default(),
+            uri: track.id.as_ref().map(|id| id.uri()).unwrap_or_default(),
+        }
+    }
+}
+
+pub fn format_name(format: ExportFormat) -> &'static str {
+    match format {
+        ExportFormat::M3u => "M3U playlist",
+        ExportFormat::Csv => "CSV",
+        ExportFormat::Json => "JSON",
+    }
+}
+
+fn extension(format: ExportFormat) -> &'static str {
+    match format {
+        ExportFormat::M3u => "m3u",
+        ExportFormat::Csv => "csv",
+        ExportFormat::Json => "json",
+    }
+}
+
+/// Returns the folder exports are written to, `spoify/exports` unless set in settings.yml
+pub fn export_folder(settings: &Settings) -> PathBuf {
+    if !settings.export_folder.is_empty() {
+        return PathBuf::from(&settings.export_folder);
+    }
+
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push(".."); // Move up to the root of the Git repository
+    path.push("spoify");
+    path.push("exports");
+    path
+}
+
+/// Writes `tracks` to `<folder>/<name>.<extension>` and returns the path of the file,
+/// earlier exports are kept by numbering the new file like `<name> (2).<extension>`
+pub fn write_export(
+    folder: &Path,
+    name: &str,
+    tracks: &[ExportTrack],
+    format: ExportFormat,
+) -> io::Result<PathBuf> {
+    // Keep names usable as file names on every platform
+    let file_name: String = name
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c => c,
+        })
+        .collect();
+    let file_name = match file_name.trim() {
+        "" => "export",
+        trimmed => trimmed,
+    };
+
+    let contents = match format {
+        ExportFormat::M3u => m3u(tracks),
+        ExportFormat::Csv => csv(tracks),
+        ExportFormat::Json => serde_json::to_string_pretty(tracks)?,
+    };
+
+    fs::create_dir_all(folder)?;
+    let mut copy = 1;
+    loop {
+        let path = match copy {
+            1 => folder.join(format!("{}.{}", file_name, extension(format))),
+            n => folder.join(format!("{} ({}).{}", file_name, n, extension(format))),
+        };
+        // Only a file that did not exist yet is opened, so nothing gets overwritten
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(mut file) => {
+                file.write_all(contents.as_bytes())?;
+                return Ok(path);
+            }
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => copy += 1,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+fn m3u(tracks: &[ExportTrack]) -> String {
+    let mut contents = String::from("#EXTM3U\n");
+    for track in tracks {
+        contents.push_str(&format!(
+            "#EXTINF:{},{} - {}\n{}\n",
+            track.duration_ms / 1000,
+            track.artists.join(", "),
+            track.title,
+            track.uri
+        ));
+    }
+    contents
+}
+
+fn csv(tracks: &[ExportTrack]) -> String {
+    let mut contents = String::from("title,artists,album,duration_ms,isrc,uri\n");
+    for track in tracks {
+        let fields = [
+            track.title.clone(),
+            track.artists.join("; "),
+            track.album.clone(),
+            track.duration_ms.to_string(),
+            track.isrc.clone(),
+            track.uri.clone(),
+        ];
+        let row: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+        contents.push_str(&row.join(","));
+        contents.push('\n');
+    }
+    contents
+}
+
+// Fields with commas, quotes or line breaks are quoted, with quotes doubled
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn track(title: &str, artists: &[&str]) -> ExportTrack {
+        ExportTrack {
+            title: title.to_string(),
+            artists: artists.iter().map(|artist| artist.to_string()).collect(),
+            album: "Album".to_string(),
+            duration_ms: 215_400,
+            isrc: "USRC17607839".to_string(),
+            uri: "spotify:track:4uLU6hMCjMI75M1A2tKUQC".to_string(),
+        }
+    }
+
+    #[test]
+    fn csv_field_quotes_only_when_needed() {
+        assert_eq!(csv_field("Plain"), "Plain");
+        assert_eq!(csv_field("One, Two"), "\"One, Two\"");
+        assert_eq!(csv_field("Say \"Hi\""), "\"Say \"\"Hi\"\"\"");
+        assert_eq!(csv_field("Two\nLines"), "\"Two\nLines\"");
+    }
+
+    #[test]
+    fn csv_has_a_header_and_one_row_per_track() {
+        let contents = csv(&[track("Hello, Goodbye", &["A", "B"])]);
+
+        assert_eq!(
+            contents,
+            "title,artists,album,duration_ms,isrc,uri\n\
+             \"Hello, Goodbye\",A; B,Album,215400,USRC17607839,spotify:track:4uLU6hMCjMI75M1A2tKUQC\n"
+        );
+    }
+
+    #[test]
+    fn exporting_twice_keeps_the_first_file() {
+        let folder = std::env::temp_dir().join(format!("spoify-export-{}", std::process::id()));
+        let first = write_export(&folder, "Mix", &[track("One", &["A"])], ExportFormat::M3u);
+        let second = write_export(&folder, "Mix", &[track("Two", &["A"])], ExportFormat::M3u);
+        let first_contents = fs::read_to_string(first.as_ref().unwrap()).unwrap();
+        fs::remove_dir_all(&folder).unwrap();
+
+        assert_eq!(first.unwrap(), folder.join("Mix.m3u"));
+        assert_eq!(second.unwrap(), folder.join("Mix (2).m3u"));
+        assert!(first_contents.contains("A - One"));
+    }
+
+    #[test]
+    fn m3u_describes_each_track_before_its_uri() {
+        let contents = m3u(&[track("Song", &["A", "B"])]);
+
+        assert_eq!(
+            contents,
+            "#EXTM3U\n#EXTINF:215,A, B - Song\nspotify:track:4uLU6hMCjMI75M1A2tKUQC\n"
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use super::util::{down_key_for_table, up_key_for_table};
+use crate::{
+    app::App,
+    enums::Menu,
+    spotify::player::{
+        devices::{devices, process_devices},
+        transfer_playback::transfer_playback,
+    },
+};
+
+pub fn go_to_device_picker_event(app: &mut App) {
+    if app.selected_menu == Menu::DevicePicker {
+        app.selected_menu = Menu::Default;
+        return;
+    }
+
+    if let Err(e) = devices(app) {
+        println!("{}", e);
+    }
+    process_devices(app);
+    if app.device_names.is_empty() {
+        app.error_text = "No devices found, open Spotify on a device first".to_string();
+        app.selected_menu = Menu::Error;
+        return;
+    }
+
+    // Start the cursor on the device that is playing
+    app.device_picker_index = app
+        .device_active
+        .iter()
+        .position(|active| *active)
+        .unwrap_or(0);
+    app.device_picker_state
+        .select(Some(app.device_picker_index));
+    app.selected_menu = Menu::DevicePicker;
+}
+
+pub fn device_picker_down_event(app: &mut App) {
+    if app.selected_menu == Menu::DevicePicker {
+        (app.device_picker_state, app.device_picker_index) =
+            down_key_for_table(app.device_names.clone(), app.device_picker_state.clone());
+        app.device_picker_index %= app.device_names.len();
+    }
+}
+
+pub fn device_picker_up_event(app: &mut App) {
+    if app.selected_menu == Menu::DevicePicker {
+        (app.device_picker_state, app.device_picker_index) =
+            up_key_for_table(app.device_names.clone(), app.device_picker_state.clone());
+    }
+}
+
+/// Moves playback to the selected device and starts playing there
+pub fn device_picker_enter_event(app: &mut App) {
+    if app.selected_menu == Menu::DevicePicker {
+        transfer_to_selected_device(app, true);
+    }
+}
+
+/// Moves playback to the selected device but keeps it paused
+pub fn device_picker_tab_event(app: &mut App) {
+    if app.selected_menu == Menu::DevicePicker {
+        transfer_to_selected_device(app, false);
+    }
+}
+
+fn transfer_to_selected_device(app: &mut App, play: bool) {
+    app.selected_device_id = app.device_ids[app.device_picker_index].clone();
+    app.selected_menu = Menu::Default;
+    if let Err(e) = transfer_playback(app, play) {
+        println!("{}", e);
+    }
+    app.request_player_refresh();
+}
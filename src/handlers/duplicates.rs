@@ -0,0 +1,149 @@
+use super::{
+    playlist_control::edit_tracks::{editable_playlist_index, remove_playlist_rows},
+    util::{down_key_for_table, up_key_for_table},
+};
+use crate::{
+    app::App,
+    duplicates::{duplicate_entries, find_duplicates},
+    enums::{Library, Menu},
+    spotify::library_section::saved_tracks::unlike_tracks,
+};
+
+/// Scans the opened playlist or Liked Songs for duplicates and lists them
+pub fn find_duplicates_event(app: &mut App) {
+    let groups = match app.selected_menu {
+        Menu::Playlists => {
+            // Only playlists the user may change can have their duplicates removed
+            if editable_playlist_index(app).is_none() {
+                return;
+            }
+            app.duplicates_in_liked_songs = false;
+            app.duplicates_source_name = app.current_user_playlist.clone();
+            find_duplicates(
+                &app.user_playlist_track_links,
+                &app.user_playlist_track_names,
+                &app.user_playlist_artist_names,
+                &app.user_playlist_track_duration,
+            )
+        }
+        Menu::Library if app.selected_library == Library::LikedSongs => {
+            app.duplicates_in_liked_songs = true;
+            app.duplicates_source_name = "Liked Songs".to_string();
+            find_duplicates(
+                &app.liked_song_links,
+                &app.liked_song_names,
+                &app.liked_song_artist_names,
+                &app.liked_song_duration,
+            )
+        }
+        _ => return,
+    };
+
+    if groups.is_empty() {
+        app.show_toast(format!(
+            "No duplicates in \"{}\"",
+            app.duplicates_source_name
+        ));
+        return;
+    }
+    app.duplicate_groups = groups;
+    app.duplicates_index = 0;
+    app.duplicates_state.select(Some(0));
+    app.selected_menu = Menu::Duplicates;
+}
+
+pub fn duplicates_down_event(app: &mut App) {
+    if app.selected_menu == Menu::Duplicates {
+        let entries = vec![String::new(); duplicate_entries(&app.duplicate_groups).len()];
+        (app.duplicates_state, app.duplicates_index) =
+            down_key_for_table(entries.clone(), app.duplicates_state.clone());
+        app.duplicates_index %= entries.len();
+    }
+}
+
+pub fn duplicates_up_event(app: &mut App) {
+    if app.selected_menu == Menu::Duplicates {
+        let entries = vec![String::new(); duplicate_entries(&app.duplicate_groups).len()];
+        (app.duplicates_state, app.duplicates_index) =
+            up_key_for_table(entries, app.duplicates_state.clone());
+    }
+}
+
+/// Switches the highlighted copy between kept and removed, one copy of each song always stays
+pub fn duplicates_enter_event(app: &mut App) {
+    if app.selected_menu != Menu::Duplicates {
+        return;
+    }
+    let Some((group, copy)) = duplicate_entries(&app.duplicate_groups)
+        .get(app.duplicates_index)
+        .copied()
+    else {
+        return;
+    };
+
+    let keep = &mut app.duplicate_groups[group].keep;
+    if keep[copy] && keep.iter().filter(|keep| **keep).count() == 1 {
+        app.show_toast("Keep at least one copy, pick the copy to keep first".to_string());
+        return;
+    }
+    keep[copy] = !keep[copy];
+}
+
+/// Removes every copy that is not kept
+pub fn remove_duplicates_event(app: &mut App) {
+    if app.selected_menu != Menu::Duplicates {
+        return;
+    }
+    let mut rows: Vec<usize> = app
+        .duplicate_groups
+        .iter()
+        .flat_map(|group| group.removed_rows())
+        .collect();
+    rows.sort_unstable();
+    rows.dedup();
+    if rows.is_empty() {
+        app.show_toast("Every copy is kept, nothing to remove".to_string());
+        return;
+    }
+
+    app.selected_menu = duplicates_return_menu(app);
+    app.duplicate_groups.clear();
+    let removed = if app.duplicates_in_liked_songs {
+        unlike_rows(app, &rows)
+    } else if remove_playlist_rows(app, &rows) {
+        rows.len()
+    } else {
+        0
+    };
+    if removed > 0 {
+        app.show_toast(format!(
+            "Removed {} duplicates from \"{}\"",
+            removed, app.duplicates_source_name
+        ));
+    }
+}
+
+/// The menu the duplicates view was opened from
+pub fn duplicates_return_menu(app: &App) -> Menu {
+    if app.duplicates_in_liked_songs {
+        Menu::Library
+    } else {
+        Menu::Playlists
+    }
+}
+
+// Unliking drops the row from Liked Songs, so the links are collected first
+fn unlike_rows(app: &mut App, rows: &[usize]) -> usize {
+    let links: Vec<String> = rows
+        .iter()
+        .filter_map(|row| app.liked_song_links.get(*row).cloned())
+        .collect();
+
+    match unlike_tracks(app, &links) {
+        Ok(removed) => removed,
+        Err(e) => {
+            println!("{}", e);
+            0
+        }
+    }
+}
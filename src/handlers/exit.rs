@@ -1,3 +1,5 @@
+use super::duplicates::duplicates_return_menu;
+use super::export::export_return_menu;
 use crate::{
     app::App,
     enums::{Library, Menu},
@@ -39,9 +41,21 @@ pub fn exit_event(app: &mut App) {
         } else {
             app.selected_menu = Menu::Default;
         }
+    } else if app.selected_menu == Menu::PlaylistEditor {
+        app.selected_menu = Menu::Playlists;
+    } else if app.selected_menu == Menu::Export {
+        app.selected_menu = export_return_menu(app);
+    } else if app.selected_menu == Menu::Duplicates {
+        app.duplicate_groups.clear();
+        app.selected_menu = duplicates_return_menu(app);
+    } else if app.selected_menu == Menu::ImportReview {
+        app.import_matches.clear();
+        app.selected_menu = Menu::Playlists;
     } else if app.selected_menu == Menu::Playlists
         || app.selected_menu == Menu::NewRelease
         || app.selected_menu == Menu::AddTrackToPlaylist
+        || app.selected_menu == Menu::Queue
+        || app.selected_menu == Menu::DevicePicker
     {
         app.selected_menu = Menu::Default;
     } else {
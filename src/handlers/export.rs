@@ -0,0 +1,126 @@
+use std::io;
+
+use rspotify::model::FullTrack;
+use rspotify::ClientError;
+
+use super::util::{down_key_for_list, up_key_for_list};
+use crate::{
+    app::App,
+    enums::{ExportSource, Library, Menu},
+    export::formats::{export_folder, write_export, ExportTrack, EXPORT_FORMATS},
+    spotify::{
+        export::export_tracks::{
+            cached_liked_tracks, playlist_full_tracks, saved_album_full_tracks,
+        },
+        library_section::liked_songs::liked_tracks,
+    },
+    structs::Settings,
+};
+
+/// Opens the export popup for the selected playlist, Liked Songs or the saved albums
+pub fn export_event(app: &mut App) {
+    let source = match app.selected_menu {
+        Menu::Playlists => {
+            let Some(index) = app.user_playlist_state.selected() else {
+                return;
+            };
+            app.export_playlist_link = app.user_playlist_links[index].clone();
+            app.export_source_name = app.user_playlist_names[index].clone();
+            ExportSource::Playlist
+        }
+        Menu::Library if app.selected_library == Library::LikedSongs => {
+            app.export_source_name = "Liked Songs".to_string();
+            ExportSource::LikedSongs
+        }
+        Menu::Library if app.selected_library == Library::Albums => {
+            app.export_source_name = "Saved Albums".to_string();
+            ExportSource::SavedAlbums
+        }
+        _ => return,
+    };
+
+    app.export_source = source;
+    app.export_format_index = 0;
+    app.export_format_state.select(Some(0));
+    app.selected_menu = Menu::Export;
+}
+
+pub fn export_down_event(app: &mut App) {
+    if app.selected_menu == Menu::Export {
+        let formats = vec![String::new(); EXPORT_FORMATS.len()];
+        (app.export_format_state, app.export_format_index) =
+            down_key_for_list(formats, app.export_format_state.clone());
+        app.export_format_index %= EXPORT_FORMATS.len();
+    }
+}
+
+pub fn export_up_event(app: &mut App) {
+    if app.selected_menu == Menu::Export {
+        let formats = vec![String::new(); EXPORT_FORMATS.len()];
+        (app.export_format_state, app.export_format_index) =
+            up_key_for_list(formats, app.export_format_state.clone());
+    }
+}
+
+/// Writes the export in the highlighted format and shows where the file went
+pub fn export_enter_event(app: &mut App, settings: &Settings) {
+    if app.selected_menu != Menu::Export {
+        return;
+    }
+    let format = EXPORT_FORMATS[app.export_format_index % EXPORT_FORMATS.len()];
+    app.selected_menu = export_return_menu(app);
+
+    let tracks = match export_tracks(app) {
+        Ok(tracks) => tracks,
+        Err(e) => {
+            app.show_toast(format!(
+                "Could not export \"{}\": {}",
+                app.export_source_name, e
+            ));
+            return;
+        }
+    };
+    let tracks: Vec<ExportTrack> = tracks.iter().map(ExportTrack::from_track).collect();
+
+    match write_export(
+        &export_folder(settings),
+        &app.export_source_name,
+        &tracks,
+        format,
+    ) {
+        Ok(path) => app.show_toast(format!(
+            "Exported {} tracks to {}",
+            tracks.len(),
+            path.display()
+        )),
+        Err(e) => app.show_toast(format!("Could not write the export: {}", e)),
+    }
+}
+
+/// The menu the export popup was opened from
+pub fn export_return_menu(app: &App) -> Menu {
+    match app.export_source {
+        ExportSource::Playlist => Menu::Playlists,
+        ExportSource::LikedSongs | ExportSource::SavedAlbums => Menu::Library,
+    }
+}
+
+fn export_tracks(app: &mut App) -> Result<Vec<FullTrack>, ClientError> {
+    match app.export_source {
+        ExportSource::Playlist => {
+            let playlist_link = app.export_playlist_link.clone();
+            playlist_full_tracks(app, &playlist_link)
+        }
+        ExportSource::LikedSongs => {
+            // Refresh the cache first so songs liked during this session are included
+            liked_tracks(app)?;
+            cached_liked_tracks().ok_or_else(|| {
+                ClientError::Io(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    "Liked Songs could not be read from the cache",
+                ))
+            })
+        }
+        ExportSource::SavedAlbums => saved_album_full_tracks(app),
+    }
+}
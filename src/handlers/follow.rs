@@ -0,0 +1,133 @@
+use crate::{
+    app::App,
+    enums::{Library, Menu, SearchMenu},
+    spotify::library_section::{
+        followed_artists::{check_artist_followed, follow_artist, unfollow_artist},
+        saved_albums::{check_album_saved, remove_album, save_album},
+    },
+};
+
+enum Followable {
+    Album(String),
+    Artist(String),
+}
+
+/// Saves the selected album or follows the selected artist
+pub fn follow_event(app: &mut App) {
+    let result = match followable_target(app) {
+        Some(Followable::Album(link)) => save_album(app, &link),
+        Some(Followable::Artist(link)) => follow_artist(app, &link),
+        None => return,
+    };
+    if let Err(e) = result {
+        println!("{}", e);
+    }
+}
+
+/// Removes the selected album or unfollows the selected artist
+pub fn unfollow_event(app: &mut App) {
+    let result = match followable_target(app) {
+        Some(Followable::Album(link)) => remove_album(app, &link),
+        Some(Followable::Artist(link)) => unfollow_artist(app, &link),
+        None => return,
+    };
+    if let Err(e) = result {
+        println!("{}", e);
+        return;
+    }
+
+    // The track pane of a removed album or artist has nothing to show anymore
+    if app.selected_menu == Menu::Library {
+        if app.selected_library == Library::Albums && app.user_album_track_display {
+            app.user_album_track_display = false;
+            app.user_album_track_selected = false;
+            app.user_album_display = true;
+            app.user_album_current_album_selected = !app.user_album_links.is_empty();
+        } else if app.selected_library == Library::Artists && app.user_artist_track_display {
+            app.user_artist_track_display = false;
+            app.user_artist_track_selected = false;
+            app.user_artist_display = true;
+            app.user_artist_current_artist_selected = !app.user_artist_links.is_empty();
+        }
+    }
+}
+
+/// Saves the album of the playing track, or removes it when it is already saved
+pub fn save_playing_album_event(app: &mut App) {
+    if app.currently_playing_media_type != "track" || app.current_playing_album_uri.is_empty() {
+        return;
+    }
+    let link = app.current_playing_album_uri.clone();
+
+    let result = match check_album_saved(app, &link) {
+        Ok(true) => remove_album(app, &link),
+        Ok(false) => save_album(app, &link),
+        Err(e) => Err(e),
+    };
+    if let Err(e) = result {
+        println!("{}", e);
+    }
+}
+
+/// Follows the artist of the playing track, or unfollows them when they are already followed
+pub fn follow_playing_artist_event(app: &mut App) {
+    if app.currently_playing_media_type != "track" || app.current_playing_artist_uri.is_empty() {
+        return;
+    }
+    let link = app.current_playing_artist_uri.clone();
+
+    let result = match check_artist_followed(app, &link) {
+        Ok(true) => unfollow_artist(app, &link),
+        Ok(false) => follow_artist(app, &link),
+        Err(e) => Err(e),
+    };
+    if let Err(e) = result {
+        println!("{}", e);
+    }
+}
+
+fn followable_target(app: &App) -> Option<Followable> {
+    match app.selected_menu {
+        Menu::Search => {
+            if app.search_menu == SearchMenu::SearchedAlbum
+                || (app.search_menu == SearchMenu::Default && app.selected_album_in_search_result)
+            {
+                app.album_links_search_results
+                    .get(app.album_index)
+                    .cloned()
+                    .map(Followable::Album)
+            } else if app.search_menu == SearchMenu::SearchedArtist
+                || (app.search_menu == SearchMenu::Default && app.selected_artist_in_search_result)
+            {
+                app.artist_links_search_results
+                    .get(app.artist_index)
+                    .cloned()
+                    .map(Followable::Artist)
+            } else {
+                None
+            }
+        }
+        Menu::NewRelease if !app.current_new_release_album_link.is_empty() => Some(
+            Followable::Album(app.current_new_release_album_link.clone()),
+        ),
+        Menu::Library
+            if app.selected_library == Library::Albums
+                && (app.user_album_display || app.user_album_track_display) =>
+        {
+            app.user_album_links
+                .get(app.user_album_index)
+                .cloned()
+                .map(Followable::Album)
+        }
+        Menu::Library
+            if app.selected_library == Library::Artists
+                && (app.user_artist_display || app.user_artist_track_display) =>
+        {
+            app.user_artist_links
+                .get(app.user_artist_index)
+                .cloned()
+                .map(Followable::Artist)
+        }
+        _ => None,
+    }
+}
@@ -1,9 +1,76 @@
-use crate::{app::App, enums::Menu};
+use super::util::{down_key_for_table, up_key_for_table};
+use crate::{
+    app::App,
+    enums::{InputMode, Menu},
+    settings::keybindings::help_entries,
+    structs::Key,
+};
+use crossterm::event::{KeyCode, KeyEvent};
 
 pub fn go_to_help_event(app: &mut App) {
     if app.selected_menu == Menu::Help {
         app.selected_menu = Menu::Default;
     } else {
+        // Remember where help was opened from so that context is listed first
+        app.help_origin_menu = app.selected_menu;
+        app.help_filter.clear();
+        app.help_index = 0;
+        app.help_state.select(Some(0));
         app.selected_menu = Menu::Help;
     }
 }
+
+pub fn help_down_event(app: &mut App, key: &mut Key) {
+    if app.selected_menu == Menu::Help {
+        let tasks: Vec<String> = help_tasks(app, key);
+        if !tasks.is_empty() {
+            (app.help_state, app.help_index) = down_key_for_table(tasks, app.help_state.clone());
+        }
+    }
+}
+
+pub fn help_up_event(app: &mut App, key: &mut Key) {
+    if app.selected_menu == Menu::Help {
+        let tasks: Vec<String> = help_tasks(app, key);
+        if !tasks.is_empty() {
+            (app.help_state, app.help_index) = up_key_for_table(tasks, app.help_state.clone());
+        }
+    }
+}
+
+/// Starts typing a filter for the help table
+pub fn help_search_event(app: &mut App) {
+    if app.selected_menu == Menu::Help {
+        app.input_mode = InputMode::HelpSearch;
+    }
+}
+
+/// Handles key presses while the help filter is being typed
+pub fn help_search_input(app: &mut App, key_event: KeyEvent) {
+    match key_event.code {
+        KeyCode::Char(c) if !c.is_control() => {
+            app.help_filter.push(c);
+        }
+        KeyCode::Backspace => {
+            app.help_filter.pop();
+        }
+        // Keep the filter and go back to scrolling through the results
+        KeyCode::Enter => {
+            app.input_mode = InputMode::Normal;
+        }
+        KeyCode::Esc => {
+            app.help_filter.clear();
+            app.input_mode = InputMode::Normal;
+        }
+        _ => {}
+    }
+    app.help_state.select(Some(0));
+    app.help_index = 0;
+}
+
+fn help_tasks(app: &mut App, key: &mut Key) -> Vec<String> {
+    help_entries(key, app.help_origin_menu, &app.help_filter)
+        .into_iter()
+        .map(|(_, task, _)| task)
+        .collect()
+}
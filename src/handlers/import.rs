@@ -0,0 +1,138 @@
+use std::path::PathBuf;
+
+use super::{
+    playlist_control::edit_playlist::refresh_playlists,
+    text_input::open_text_input,
+    util::{down_key_for_table, up_key_for_table},
+};
+use crate::{
+    app::App,
+    cli::default_playlist_name,
+    enums::{Menu, TextInputAction},
+    import::{parse::read_import_file, playlist::create_imported_playlist},
+    spotify::import::match_tracks::match_import_entries,
+};
+
+/// Asks for the file to import a playlist from
+pub fn import_event(app: &mut App) {
+    if app.selected_menu == Menu::Playlists {
+        open_text_input(
+            app,
+            TextInputAction::ImportFile,
+            "File to import (.txt, .csv or .m3u)",
+            "",
+        );
+    }
+}
+
+/// Reads the file, looks every line up and opens the review of the matches
+pub fn import_file_submit(app: &mut App, path: &str) {
+    if path.is_empty() {
+        return;
+    }
+    let path = expand_home(path);
+
+    let entries = match read_import_file(&path) {
+        Ok(entries) if entries.is_empty() => {
+            app.show_toast(format!("No tracks found in {}", path.display()));
+            return;
+        }
+        Ok(entries) => entries,
+        Err(e) => {
+            app.show_toast(format!("Could not read {}: {}", path.display(), e));
+            return;
+        }
+    };
+    match match_import_entries(app, entries) {
+        Ok(matches) => app.import_matches = matches,
+        Err(e) => {
+            app.show_toast(format!("Could not match the tracks: {}", e));
+            return;
+        }
+    }
+
+    app.import_playlist_name = default_playlist_name(&path);
+    app.import_playlist_link.clear();
+    app.import_review_state.select(Some(0));
+    app.selected_menu = Menu::ImportReview;
+}
+
+pub fn import_review_down_event(app: &mut App) {
+    if app.selected_menu == Menu::ImportReview && !app.import_matches.is_empty() {
+        let rows = vec![String::new(); app.import_matches.len()];
+        (app.import_review_state, _) = down_key_for_table(rows, app.import_review_state.clone());
+    }
+}
+
+pub fn import_review_up_event(app: &mut App) {
+    if app.selected_menu == Menu::ImportReview && !app.import_matches.is_empty() {
+        let rows = vec![String::new(); app.import_matches.len()];
+        (app.import_review_state, _) = up_key_for_table(rows, app.import_review_state.clone());
+    }
+}
+
+/// Accepts or rejects the highlighted match, lines without a match stay out
+pub fn import_review_enter_event(app: &mut App) {
+    if app.selected_menu != Menu::ImportReview {
+        return;
+    }
+    let Some(index) = app.import_review_state.selected() else {
+        return;
+    };
+    if let Some(import_match) = app.import_matches.get_mut(index) {
+        if !import_match.track_uri.is_empty() {
+            import_match.accepted = !import_match.accepted;
+        }
+    }
+}
+
+/// Asks for the name of the playlist the accepted matches go into
+pub fn import_create_event(app: &mut App) {
+    if app.selected_menu != Menu::ImportReview {
+        return;
+    }
+    if !app
+        .import_matches
+        .iter()
+        .any(|import_match| import_match.accepted)
+    {
+        app.show_toast("No matches are accepted, press Enter to accept one".to_string());
+        return;
+    }
+    let name = app.import_playlist_name.clone();
+    open_text_input(
+        app,
+        TextInputAction::ImportPlaylistName,
+        "Name of the imported playlist",
+        &name,
+    );
+}
+
+pub fn import_playlist_name_submit(app: &mut App, name: &str) {
+    if name.is_empty() {
+        return;
+    }
+    let matches = std::mem::take(&mut app.import_matches);
+    match create_imported_playlist(app, name, &matches) {
+        Ok(added) => {
+            // New playlists are listed first
+            refresh_playlists(app, None);
+            app.user_playlist_state.select(Some(0));
+            app.show_toast(format!("Created \"{}\" with {} tracks", name, added));
+            app.selected_menu = Menu::Playlists;
+        }
+        Err(e) => {
+            println!("{}", e);
+            // Keep the review so the import can be tried again, into the playlist created already
+            app.import_matches = matches;
+        }
+    }
+}
+
+// Lets "~/music/list.m3u" be typed like in a shell
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var("HOME")) {
+        (Some(rest), Ok(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
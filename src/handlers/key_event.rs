@@ -1,36 +1,77 @@
 use super::change_keybindings::change_keybindings;
+use super::device_picker::{
+    device_picker_down_event, device_picker_enter_event, device_picker_tab_event,
+    device_picker_up_event, go_to_device_picker_event,
+};
+use super::duplicates::{
+    duplicates_down_event, duplicates_enter_event, duplicates_up_event, find_duplicates_event,
+    remove_duplicates_event,
+};
 use super::error_screen::go_to_error_event;
 use super::exit::exit_event;
-use super::help::go_to_help_event;
+use super::export::{export_down_event, export_enter_event, export_event, export_up_event};
+use super::follow::{
+    follow_event, follow_playing_artist_event, save_playing_album_event, unfollow_event,
+};
+use super::help::{
+    go_to_help_event, help_down_event, help_search_event, help_search_input, help_up_event,
+};
+use super::import::{
+    import_create_event, import_event, import_review_down_event, import_review_enter_event,
+    import_review_up_event,
+};
 use super::library::{
     go_to_library_event, library_down_event, library_enter_event, library_tab_event,
     library_up_event,
 };
+use super::like::like_track_event;
 use super::new_release::{
     go_to_new_release_event, new_release_down_event, new_release_enter_event,
     new_release_tab_event, new_release_up_event,
 };
 use super::open_configure_folder::open_config_folder;
 use super::player::{
-    fullscreen_player_event, next_track_event, play_pause_event, previous_track_event,
-    repeat_event, shuffle_event, volume_decreament_event, volume_increment_event,
+    fullscreen_player_event, lyrics_event, next_track_event, play_only_selected_track_event,
+    play_pause_event, previous_track_event, repeat_event, shuffle_event, volume_decreament_event,
+    volume_increment_event,
 };
 use super::playlist_control::add_track_to_playlist::{
     add_track_to_playlist_down_event, add_track_to_playlist_enter_event,
-    add_track_to_playlist_event, add_track_to_playlist_up_event,
+    add_track_to_playlist_event, add_track_to_playlist_tab_event, add_track_to_playlist_up_event,
+};
+use super::playlist_control::edit_playlist::{
+    create_playlist_event, edit_playlist_event, playlist_editor_down_event,
+    playlist_editor_enter_event, playlist_editor_up_event,
+};
+use super::playlist_control::edit_tracks::{
+    move_playlist_track_down_event, move_playlist_track_to_event, move_playlist_track_up_event,
+    remove_playlist_track_event,
 };
 use super::playlist_control::{
     follow_playlist::follow_playlist_event, unfollow_playlist::unfollow_playlist_event,
 };
+use super::podcast::{follow_show_event, save_episode_event, unfollow_show_event};
+use super::queue::{add_to_queue_event, go_to_queue_event, queue_down_event, queue_up_event};
 use super::refresh::refresh_event;
 use super::search::{
     go_to_search_event, search_down_event, search_enter_event, search_tab_event, search_up_event,
 };
+use super::seek::{
+    restart_track_event, seek_backward_event, seek_forward_event, seek_input, seek_input_event,
+    seek_to_percent_event,
+};
+use super::selection::{select_all_event, select_range_event, toggle_selection_event};
+use super::text_input::text_input;
+use super::theme_picker::{
+    go_to_theme_picker_event, revert_theme_preview, theme_picker_cancel_event,
+    theme_picker_down_event, theme_picker_enter_event, theme_picker_up_event,
+};
 use super::user_playlist::{
     go_to_user_playlists_event, user_playlist_down_event, user_playlist_enter_event,
     user_playlist_tab_event, user_playlist_up_event,
 };
 use super::util::{default_nav, delete_char, move_cursor_left, move_cursor_right, reset_cursor};
+use super::volume::{mute_event, volume_input, volume_input_event};
 use crate::app::App;
 use crate::enums::{InputMode, Menu};
 use crate::spotify::search::search::process_search;
@@ -61,9 +102,60 @@ pub fn handle_key_event(
     let change_keybind: char = key.change_keybind;
     let refresh_key: char = key.refresh_key;
     let open_config_fold_key: char = key.open_config_fold_key;
+    let theme_picker_key: char = key.theme_picker_key;
+    let lyrics_key: char = key.lyrics_key;
+    let queue_key: char = key.queue_key;
+    let device_picker_key: char = key.device_picker_key;
+    let seek_forward_key: char = key.seek_forward_key;
+    let seek_backward_key: char = key.seek_backward_key;
+    let seek_to_position_key: char = key.seek_to_position_key;
+    let restart_track_key: char = key.restart_track_key;
+    let set_volume_key: char = key.set_volume_key;
+    let mute_key: char = key.mute_key;
+    let save_key: char = key.save_key;
+    let follow_artist_key: char = key.follow_artist_key;
+    let save_album_key: char = key.save_album_key;
+    let create_playlist_key: char = key.create_playlist_key;
+    let edit_playlist_key: char = key.edit_playlist_key;
+    let move_track_key: char = key.move_track_key;
+    let toggle_selection_key: char = key.toggle_selection_key;
+    let select_range_key: char = key.select_range_key;
+    let export_key: char = key.export_key;
+    let import_key: char = key.import_key;
+    let find_duplicates_key: char = key.find_duplicates_key;
 
     if key_event.kind == KeyEventKind::Press {
+        // While typing a help filter every key goes to the filter
+        if app.input_mode == InputMode::HelpSearch {
+            help_search_input(app, key_event);
+            return;
+        }
+
+        // While typing a position every key goes to the seek input
+        if app.input_mode == InputMode::SeekInput {
+            seek_input(app, key_event);
+            return;
+        }
+
+        // While typing a volume every key goes to the volume input
+        if app.input_mode == InputMode::VolumeInput {
+            volume_input(app, key_event, settings);
+            return;
+        }
+
+        // While the text input dialog is open every key goes to it
+        if app.input_mode == InputMode::TextInput {
+            text_input(app, key_event);
+            return;
+        }
+
+        let in_theme_picker = app.selected_menu == Menu::ThemePicker;
         match key_event.code {
+            // Start filtering the help screen
+            KeyCode::Char('/') if app.selected_menu == Menu::Help => {
+                help_search_event(app);
+            }
+
             // Toggle shuffle mode when Ctrl+S is pressed
             KeyCode::Char('s') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
                 shuffle_event(app);
@@ -84,14 +176,40 @@ pub fn handle_key_event(
                 add_track_to_playlist_event(app);
             }
 
-            // Follow Playlist
+            // Add the selected track to the queue
+            KeyCode::Char('q') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                add_to_queue_event(app);
+            }
+
+            // Play the selected track without the rest of its list
+            KeyCode::Char('o') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                play_only_selected_track_event(app);
+            }
+
+            // Select every row of the focused track table
+            KeyCode::Char('a') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                select_all_event(app);
+            }
+
+            // Follow Playlist, save a show or album, or follow an artist
             KeyCode::Char('f') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
                 follow_playlist_event(app);
+                follow_show_event(app);
+                follow_event(app);
             }
 
-            //Unfollow/Delete Playlist
+            //Unfollow/Delete Playlist, remove a show or album, or unfollow an artist
             KeyCode::Char('d') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
                 unfollow_playlist_event(app);
+                unfollow_show_event(app);
+                unfollow_event(app);
+            }
+
+            // Leave the theme picker without saving the previewed theme
+            code if code == KeyCode::Char(exit_application_key)
+                && app.selected_menu == Menu::ThemePicker =>
+            {
+                theme_picker_cancel_event(app, theme, settings);
             }
 
             // Exit the application when 'q' is pressed in Normal mode
@@ -141,6 +259,30 @@ pub fn handle_key_event(
                 fullscreen_player_event(app);
             }
 
+            // Show or hide the lyrics pane
+            code if code == KeyCode::Char(lyrics_key) && app.input_mode != InputMode::Editing => {
+                lyrics_event(app);
+            }
+
+            // Show or hide the queue
+            code if code == KeyCode::Char(queue_key) && app.input_mode != InputMode::Editing => {
+                go_to_queue_event(app);
+            }
+
+            // Show the devices playback can move to
+            code if code == KeyCode::Char(device_picker_key)
+                && app.input_mode != InputMode::Editing =>
+            {
+                go_to_device_picker_event(app);
+            }
+
+            // Open the theme picker
+            code if code == KeyCode::Char(theme_picker_key)
+                && app.input_mode != InputMode::Editing =>
+            {
+                go_to_theme_picker_event(app, settings);
+            }
+
             // Go to New Release Menu
             code if code == KeyCode::Char(new_release_key)
                 && app.input_mode != InputMode::Editing =>
@@ -160,6 +302,104 @@ pub fn handle_key_event(
             {
                 volume_increment_event(app, settings);
             }
+            code if code == KeyCode::Char(set_volume_key)
+                && app.input_mode != InputMode::Editing =>
+            {
+                volume_input_event(app);
+            }
+            code if code == KeyCode::Char(mute_key) && app.input_mode != InputMode::Editing => {
+                mute_event(app, settings);
+            }
+
+            // Save or remove the selected item from the library
+            code if code == KeyCode::Char(save_key) && app.input_mode != InputMode::Editing => {
+                like_track_event(app);
+                save_episode_event(app);
+            }
+
+            // Create a playlist or edit the selected one
+            code if code == KeyCode::Char(create_playlist_key)
+                && app.input_mode != InputMode::Editing =>
+            {
+                create_playlist_event(app);
+                import_create_event(app);
+            }
+            code if code == KeyCode::Char(edit_playlist_key)
+                && app.input_mode != InputMode::Editing =>
+            {
+                edit_playlist_event(app);
+            }
+
+            // Export the selected playlist, Liked Songs or the saved albums to a file
+            code if code == KeyCode::Char(export_key) && app.input_mode != InputMode::Editing => {
+                export_event(app);
+            }
+
+            // Import a playlist from a text, CSV or M3U file
+            code if code == KeyCode::Char(import_key) && app.input_mode != InputMode::Editing => {
+                import_event(app);
+            }
+
+            // Find and remove duplicates in the opened playlist or Liked Songs
+            code if code == KeyCode::Char(find_duplicates_key)
+                && app.input_mode != InputMode::Editing =>
+            {
+                find_duplicates_event(app);
+            }
+
+            // Select rows of the focused track table for bulk actions
+            code if code == KeyCode::Char(toggle_selection_key)
+                && app.input_mode != InputMode::Editing =>
+            {
+                toggle_selection_event(app);
+            }
+            code if code == KeyCode::Char(select_range_key)
+                && app.input_mode != InputMode::Editing =>
+            {
+                select_range_event(app);
+            }
+
+            // Remove or move tracks in the opened playlist
+            KeyCode::Delete if app.input_mode != InputMode::Editing => {
+                if app.selected_menu == Menu::Duplicates {
+                    remove_duplicates_event(app);
+                } else {
+                    remove_playlist_track_event(app);
+                }
+            }
+            code if code == KeyCode::Char(move_track_key)
+                && app.input_mode != InputMode::Editing =>
+            {
+                move_playlist_track_to_event(app);
+            }
+            KeyCode::Up
+                if key_event.modifiers.contains(KeyModifiers::SHIFT)
+                    && app.selected_menu == Menu::Playlists
+                    && app.user_playlist_tracks_selected =>
+            {
+                move_playlist_track_up_event(app);
+            }
+            KeyCode::Down
+                if key_event.modifiers.contains(KeyModifiers::SHIFT)
+                    && app.selected_menu == Menu::Playlists
+                    && app.user_playlist_tracks_selected =>
+            {
+                move_playlist_track_down_event(app);
+            }
+
+            // Follow or unfollow the artist of the playing track
+            code if code == KeyCode::Char(follow_artist_key)
+                && app.input_mode != InputMode::Editing =>
+            {
+                follow_playing_artist_event(app);
+            }
+
+            // Save or remove the album of the playing track
+            code if code == KeyCode::Char(save_album_key)
+                && app.input_mode != InputMode::Editing =>
+            {
+                save_playing_album_event(app);
+            }
 
             // Keys for next and previous track
             code if code == KeyCode::Char(next_track_key)
@@ -173,6 +413,28 @@ pub fn handle_key_event(
                 previous_track_event(app);
             }
 
+            // Keys for seeking
+            code if code == KeyCode::Char(seek_forward_key)
+                && app.input_mode != InputMode::Editing =>
+            {
+                seek_forward_event(app, settings);
+            }
+            code if code == KeyCode::Char(seek_backward_key)
+                && app.input_mode != InputMode::Editing =>
+            {
+                seek_backward_event(app, settings);
+            }
+            code if code == KeyCode::Char(seek_to_position_key)
+                && app.input_mode != InputMode::Editing =>
+            {
+                seek_input_event(app);
+            }
+            code if code == KeyCode::Char(restart_track_key)
+                && app.input_mode != InputMode::Editing =>
+            {
+                restart_track_event(app);
+            }
+
             // Key for Error Screen
             code if code == KeyCode::Char(error_key) && app.input_mode != InputMode::Editing => {
                 go_to_error_event(app);
@@ -191,10 +453,18 @@ pub fn handle_key_event(
                 user_playlist_down_event(app);
                 search_down_event(app);
                 add_track_to_playlist_down_event(app);
+                theme_picker_down_event(app, theme, settings);
+                queue_down_event(app);
+                device_picker_down_event(app);
+                playlist_editor_down_event(app);
+                export_down_event(app);
+                import_review_down_event(app);
+                duplicates_down_event(app);
+                help_down_event(app, key);
 
                 if app.can_navigate_menu {
                     let next_index: usize = app.library_state.selected().unwrap_or(0) + 1;
-                    app.library_state.select(Some(next_index % 6)); //wrapping around the last option
+                    app.library_state.select(Some(next_index % 8)); //wrapping around the last option
                     default_nav(app);
                 }
             }
@@ -206,10 +476,18 @@ pub fn handle_key_event(
                 user_playlist_up_event(app);
                 search_up_event(app);
                 add_track_to_playlist_up_event(app);
+                theme_picker_up_event(app, theme, settings);
+                queue_up_event(app);
+                device_picker_up_event(app);
+                playlist_editor_up_event(app);
+                export_up_event(app);
+                import_review_up_event(app);
+                duplicates_up_event(app);
+                help_up_event(app, key);
 
                 if app.can_navigate_menu {
                     let prev_index = if app.library_state.selected().unwrap_or(0) == 0 {
-                        5 //wrapping to the last option when user presses up at the first option
+                        7 //wrapping to the last option when user presses up at the first option
                     } else {
                         app.library_state.selected().unwrap_or(0) - 1
                     };
@@ -225,6 +503,12 @@ pub fn handle_key_event(
                 library_enter_event(app);
                 search_enter_event(app);
                 add_track_to_playlist_enter_event(app);
+                theme_picker_enter_event(app, settings);
+                device_picker_enter_event(app);
+                playlist_editor_enter_event(app);
+                export_enter_event(app, settings);
+                import_review_enter_event(app);
+                duplicates_enter_event(app);
             }
 
             // Tab keybinding for all the menus
@@ -233,6 +517,13 @@ pub fn handle_key_event(
                 new_release_tab_event(app);
                 library_tab_event(app);
                 search_tab_event(app);
+                device_picker_tab_event(app);
+                add_track_to_playlist_tab_event(app);
+            }
+
+            // Number keys jump to a tenth of the track
+            KeyCode::Char(c) if c.is_ascii_digit() && app.input_mode != InputMode::Editing => {
+                seek_to_percent_event(app, c.to_digit(10).unwrap_or(0));
             }
 
             // Pause/Play using Spacebar
@@ -240,6 +531,10 @@ pub fn handle_key_event(
                 play_pause_event(app);
             }
 
+            KeyCode::Esc if app.selected_menu == Menu::ThemePicker => {
+                theme_picker_cancel_event(app, theme, settings);
+            }
+
             // Just exit from Search Menu
             KeyCode::Esc if app.input_mode != InputMode::Editing => {
                 app.selected_menu = Menu::Default;
@@ -256,6 +551,11 @@ pub fn handle_key_event(
 
             _ => {}
         }
+
+        // Leaving the picker any other way than Enter, like a menu key, drops the previewed theme
+        if in_theme_picker && app.selected_menu != Menu::ThemePicker {
+            revert_theme_preview(app, theme, settings);
+        }
     }
 }
 
@@ -294,6 +594,10 @@ pub fn search_input(app: &mut App, key_event: KeyEvent) -> io::Result<()> {
             },
             InputMode::Normal => {}
             InputMode::SearchResults => {}
+            InputMode::HelpSearch => {}
+            InputMode::SeekInput => {}
+            InputMode::VolumeInput => {}
+            InputMode::TextInput => {}
         }
     }
     Ok(())
@@ -1,20 +1,29 @@
-use super::util::{default, down_key_for_table, up_key_for_table};
+use super::like::check_liked_tracks;
+use super::podcast::{open_audiobook, open_show, play_selected_episode};
+use super::util::{
+    default, down_key_for_table, liked_songs_context_uri, play_track_in_context, playback_window,
+    up_key_for_table,
+};
 use crate::{
     app::App,
     enums::{Library, Menu},
     spotify::{
+        album_art::fetch_album_art::fetch_album_art,
         library_section::{
+            audiobooks::{process_audiobooks, user_audiobooks},
             liked_songs::{liked_tracks, process_liked_tracks},
             made_fy::{made_fy, process_made_fy},
             made_fy_tracks::{fetch_made_fy_tracks, process_made_fy_tracks},
             podcast::{process_podcasts, user_podcast},
             recently_played::{process_recently_played, recently_played},
+            saved_episodes::{process_saved_episodes, saved_episodes},
+            saved_tracks::mark_liked_songs,
             user_album_tracks::{process_user_album_tracks, user_album_tracks},
             user_albums::{process_user_albums, user_albums},
             user_artist_tracks::{process_user_artist_tracks, user_artist_tracks},
             user_artists::{process_user_artists, user_artists},
         },
-        player::start_playback::start_playback,
+        player::util::context_uri,
     },
 };
 
@@ -68,6 +77,30 @@ pub fn library_down_event(app: &mut App) {
                 (app.podcast_state, app.podcast_index) =
                     down_key_for_table(app.podcast_names.clone(), app.podcast_state.clone());
             }
+            if app.podcast_episode_selected {
+                (app.podcast_episode_state, app.podcast_episode_index) = down_key_for_table(
+                    app.podcast_episode_names.clone(),
+                    app.podcast_episode_state.clone(),
+                );
+            }
+        } else if app.library_state.selected() == Some(6) {
+            if app.podcast_episode_selected {
+                (app.podcast_episode_state, app.podcast_episode_index) = down_key_for_table(
+                    app.podcast_episode_names.clone(),
+                    app.podcast_episode_state.clone(),
+                );
+            }
+        } else if app.library_state.selected() == Some(7) {
+            if app.audiobook_selected {
+                (app.audiobook_state, app.audiobook_index) =
+                    down_key_for_table(app.audiobook_names.clone(), app.audiobook_state.clone());
+            }
+            if app.podcast_episode_selected {
+                (app.podcast_episode_state, app.podcast_episode_index) = down_key_for_table(
+                    app.podcast_episode_names.clone(),
+                    app.podcast_episode_state.clone(),
+                );
+            }
         } else if app.library_state.selected() == Some(4) {
             if app.user_artist_selected {
                 (app.user_artist_state, app.user_artist_index) = down_key_for_table(
@@ -129,6 +162,30 @@ pub fn library_up_event(app: &mut App) {
                 (app.podcast_state, app.podcast_index) =
                     up_key_for_table(app.podcast_names.clone(), app.podcast_state.clone());
             }
+            if app.podcast_episode_selected {
+                (app.podcast_episode_state, app.podcast_episode_index) = up_key_for_table(
+                    app.podcast_episode_names.clone(),
+                    app.podcast_episode_state.clone(),
+                );
+            }
+        } else if app.library_state.selected() == Some(6) {
+            if app.podcast_episode_selected {
+                (app.podcast_episode_state, app.podcast_episode_index) = up_key_for_table(
+                    app.podcast_episode_names.clone(),
+                    app.podcast_episode_state.clone(),
+                );
+            }
+        } else if app.library_state.selected() == Some(7) {
+            if app.audiobook_selected {
+                (app.audiobook_state, app.audiobook_index) =
+                    up_key_for_table(app.audiobook_names.clone(), app.audiobook_state.clone());
+            }
+            if app.podcast_episode_selected {
+                (app.podcast_episode_state, app.podcast_episode_index) = up_key_for_table(
+                    app.podcast_episode_names.clone(),
+                    app.podcast_episode_state.clone(),
+                );
+            }
         } else if app.library_state.selected() == Some(4) {
             if app.user_artist_selected {
                 (app.user_artist_state, app.user_artist_index) =
@@ -156,6 +213,7 @@ pub fn library_enter_event(app: &mut App) {
                     println!("{}", e);
                 }
                 process_made_fy_tracks(app);
+                check_liked_tracks(app, &app.made_fy_track_links.clone());
                 app.made_fy_track_display = true;
                 app.made_fy_display = false;
                 app.made_fy_track_selected = true;
@@ -166,9 +224,11 @@ pub fn library_enter_event(app: &mut App) {
             } else if app.enter_for_playback_in_made_fy {
                 app.selected_link_for_playback =
                     app.made_fy_track_links[app.made_fy_track_index].clone();
-                if let Err(e) = start_playback(app) {
-                    println!("{}", e);
-                }
+                play_track_in_context(
+                    app,
+                    context_uri("playlist", &app.made_fy_playlist_links[app.made_fy_index]),
+                    Vec::new(),
+                );
             } else {
                 if let Err(e) = made_fy(app) {
                     println!("{}", e);
@@ -182,14 +242,19 @@ pub fn library_enter_event(app: &mut App) {
             if app.enter_for_playback_in_liked_song {
                 app.selected_link_for_playback =
                     app.liked_song_links[app.liked_songs_index].clone();
-                if let Err(e) = start_playback(app) {
-                    println!("{}", e);
+                match liked_songs_context_uri(app) {
+                    Some(context_uri) => play_track_in_context(app, context_uri, Vec::new()),
+                    None => {
+                        let window = playback_window(&app.liked_song_links, app.liked_songs_index);
+                        play_track_in_context(app, String::new(), window);
+                    }
                 }
             } else {
                 if let Err(e) = liked_tracks(app) {
                     println!("{}", e);
                 }
                 process_liked_tracks(app);
+                mark_liked_songs(app);
                 app.liked_song_display = true;
                 app.enter_for_playback_in_liked_song = true;
             }
@@ -200,6 +265,10 @@ pub fn library_enter_event(app: &mut App) {
                     println!("{}", e);
                 }
                 process_user_album_tracks(app);
+                check_liked_tracks(app, &app.user_album_track_links.clone());
+                if let Some(image_url) = app.user_album_image_urls.get(app.user_album_index) {
+                    let _ = fetch_album_art(image_url);
+                }
                 app.user_album_track_display = true;
                 app.user_album_display = false;
                 app.user_album_track_selected = true;
@@ -209,9 +278,11 @@ pub fn library_enter_event(app: &mut App) {
             } else if app.enter_for_playback_in_user_album {
                 app.selected_link_for_playback =
                     app.user_album_track_links[app.user_album_track_index].clone();
-                if let Err(e) = start_playback(app) {
-                    println!("{}", e);
-                }
+                play_track_in_context(
+                    app,
+                    context_uri("album", &app.user_album_links[app.user_album_index]),
+                    Vec::new(),
+                );
             } else {
                 if let Err(e) = user_albums(app) {
                     println!("{}", e);
@@ -226,24 +297,93 @@ pub fn library_enter_event(app: &mut App) {
             if app.enter_for_playback_in_recently_played {
                 app.selected_link_for_playback =
                     app.recently_played_links[app.recently_played_index].clone();
-                if let Err(e) = start_playback(app) {
-                    println!("{}", e);
-                }
+                let window = playback_window(&app.recently_played_links, app.recently_played_index);
+                play_track_in_context(app, String::new(), window);
             } else {
                 if let Err(e) = recently_played(app) {
                     println!("{}", e);
                 }
                 process_recently_played(app);
+                check_liked_tracks(app, &app.recently_played_links.clone());
                 app.recently_played_display = true;
                 app.enter_for_playback_in_recently_played = true;
             }
         } else if app.library_state.selected() == Some(5) {
             app.selected_library = Library::Podcasts;
-            if let Err(e) = user_podcast(app) {
-                println!("{}", e);
+            if app.podcast_current_show_selected {
+                if app.podcast_links.is_empty() {
+                    return;
+                }
+                app.selected_show_link = app.podcast_links[app.podcast_index].clone();
+                app.selected_show_name = app.podcast_names[app.podcast_index].clone();
+                app.selected_show_description = app
+                    .podcast_descriptions
+                    .get(app.podcast_index)
+                    .cloned()
+                    .unwrap_or_default();
+                if open_show(app) {
+                    app.podcast_display = false;
+                    app.podcast_current_show_selected = false;
+                    app.podcast_selected = false;
+                }
+            } else if app.enter_for_playback_in_podcast {
+                play_selected_episode(
+                    app,
+                    context_uri("show", &app.selected_show_link),
+                    Vec::new(),
+                );
+            } else {
+                if let Err(e) = user_podcast(app) {
+                    println!("{}", e);
+                }
+                process_podcasts(app);
+                app.podcast_display = true;
+                app.podcast_current_show_selected = true;
+                app.enter_for_playback_in_podcast = true;
+            }
+        } else if app.library_state.selected() == Some(6) {
+            app.selected_library = Library::Episodes;
+            if app.enter_for_playback_in_saved_episodes {
+                // Saved episodes come from different shows, so they play as a list
+                play_selected_episode(app, String::new(), app.podcast_episode_links.clone());
+            } else {
+                if let Err(e) = saved_episodes(app) {
+                    println!("{}", e);
+                    return;
+                }
+                process_saved_episodes(app);
+                app.selected_show_name.clear();
+                app.podcast_episode_display = true;
+                app.enter_for_playback_in_saved_episodes = true;
+            }
+        } else if app.library_state.selected() == Some(7) {
+            app.selected_library = Library::Audiobooks;
+            if app.audiobook_current_selected {
+                if app.audiobook_ids.is_empty() {
+                    return;
+                }
+                if open_audiobook(app) {
+                    app.audiobook_display = false;
+                    app.audiobook_current_selected = false;
+                    app.audiobook_selected = false;
+                }
+            } else if app.enter_for_playback_in_audiobook {
+                // Audiobooks play like shows, so the rest of the chapters follow
+                play_selected_episode(
+                    app,
+                    context_uri("show", &app.selected_show_link),
+                    Vec::new(),
+                );
+            } else {
+                if let Err(e) = user_audiobooks(app) {
+                    println!("{}", e);
+                    return;
+                }
+                process_audiobooks(app);
+                app.audiobook_display = true;
+                app.audiobook_current_selected = true;
+                app.enter_for_playback_in_audiobook = true;
             }
-            process_podcasts(app);
-            app.podcast_display = true;
         } else if app.library_state.selected() == Some(4) {
             app.selected_library = Library::Artists;
             if app.user_artist_current_artist_selected {
@@ -251,6 +391,7 @@ pub fn library_enter_event(app: &mut App) {
                     println!("{}", e);
                 }
                 process_user_artist_tracks(app);
+                check_liked_tracks(app, &app.user_artist_track_links.clone());
                 app.user_artist_track_display = true;
                 app.user_artist_display = false;
                 app.user_artist_track_selected = true;
@@ -260,9 +401,9 @@ pub fn library_enter_event(app: &mut App) {
             } else if app.enter_for_playback_in_saved_artist {
                 app.selected_link_for_playback =
                     app.user_artist_track_links[app.user_artist_track_index].clone();
-                if let Err(e) = start_playback(app) {
-                    println!("{}", e);
-                }
+                let window =
+                    playback_window(&app.user_artist_track_links, app.user_artist_track_index);
+                play_track_in_context(app, String::new(), window);
             } else {
                 if let Err(e) = user_artists(app) {
                     println!("{}", e);
@@ -294,6 +435,14 @@ pub fn library_tab_event(app: &mut App) {
         } else if app.library_state.selected() == Some(5) && app.podcast_display {
             app.podcast_state.select(Some(0));
             app.podcast_selected = !app.podcast_selected;
+        } else if app.library_state.selected() == Some(6) && app.podcast_episode_display {
+            app.podcast_episode_index = 0;
+            app.podcast_episode_state.select(Some(0));
+            app.podcast_episode_selected = !app.podcast_episode_selected;
+        } else if app.library_state.selected() == Some(7) && app.audiobook_display {
+            app.audiobook_index = 0;
+            app.audiobook_state.select(Some(0));
+            app.audiobook_selected = !app.audiobook_selected;
         } else if app.library_state.selected() == Some(4) && app.user_artist_display {
             app.user_artist_state.select(Some(0));
             app.user_artist_selected = !app.user_artist_selected;
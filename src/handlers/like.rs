@@ -0,0 +1,81 @@
+use super::selection::{clear_selection, selected_links};
+use super::util::selected_track_link;
+use crate::{
+    app::App,
+    enums::Menu,
+    spotify::library_section::saved_tracks::{
+        check_saved_tracks, like_track, like_tracks, track_id, unlike_track, unlike_tracks,
+    },
+};
+
+/// Likes the selected track, or the playing track when no track is selected, and unlikes it when it is already liked
+pub fn like_track_event(app: &mut App) {
+    if app.selected_menu != Menu::Queue && !selected_links(app).is_empty() {
+        like_selection(app);
+        return;
+    }
+
+    let link = if app.selected_menu == Menu::Queue {
+        app.queue_links.get(app.queue_index).cloned()
+    } else {
+        selected_track_link(app)
+    };
+    let link = match link {
+        Some(link) => link,
+        None if app.currently_playing_media_type == "track" => app.current_playing_id.clone(),
+        None => return,
+    };
+
+    // Episodes are saved to Your Episodes instead
+    let Some(id) = track_id(&link) else {
+        return;
+    };
+    if !app.liked_track_ids.contains_key(&id) {
+        check_liked_tracks(app, std::slice::from_ref(&link));
+    }
+
+    let result = if app.liked_track_ids.get(&id).copied().unwrap_or(false) {
+        unlike_track(app, &link)
+    } else {
+        like_track(app, &link)
+    };
+    if let Err(e) = result {
+        println!("{}", e);
+    }
+}
+
+/// Likes every selected track, or unlikes them all when every one of them is already liked
+fn like_selection(app: &mut App) {
+    let links: Vec<String> = selected_links(app)
+        .into_iter()
+        .filter(|link| track_id(link).is_some())
+        .collect();
+    check_liked_tracks(app, &links);
+
+    let is_liked = |app: &App, link: &str| {
+        track_id(link)
+            .and_then(|id| app.liked_track_ids.get(&id).copied())
+            .unwrap_or(false)
+    };
+    let result = if links.iter().all(|link| is_liked(app, link)) {
+        unlike_tracks(app, &links)
+    } else {
+        let unliked: Vec<String> = links
+            .iter()
+            .filter(|link| !is_liked(app, link))
+            .cloned()
+            .collect();
+        like_tracks(app, &unliked)
+    };
+    if let Err(e) = result {
+        println!("{}", e);
+    }
+    clear_selection(app);
+}
+
+/// Looks up which of the listed tracks are liked so their tables can show it
+pub fn check_liked_tracks(app: &mut App, track_links: &[String]) {
+    if let Err(e) = check_saved_tracks(app, track_links) {
+        println!("{}", e);
+    }
+}
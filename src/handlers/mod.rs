@@ -1,14 +1,27 @@
 pub mod change_keybindings;
+pub mod device_picker;
+pub mod duplicates;
 pub mod error_screen;
 pub mod exit;
+pub mod export;
+pub mod follow;
 pub mod help;
+pub mod import;
 pub mod key_event;
 pub mod library;
+pub mod like;
 pub mod new_release;
 pub mod open_configure_folder;
 pub mod player;
 pub mod playlist_control;
+pub mod podcast;
+pub mod queue;
 pub mod refresh;
 pub mod search;
+pub mod seek;
+pub mod selection;
+pub mod text_input;
+pub mod theme_picker;
 pub mod user_playlist;
 pub mod util;
+pub mod volume;
@@ -1,12 +1,14 @@
-use super::util::{default, down_key_for_table, up_key_for_table};
+use super::like::check_liked_tracks;
+use super::util::{default, down_key_for_table, play_track_in_context, up_key_for_table};
 use crate::{
     app::App,
     enums::Menu,
     spotify::{
+        album_art::fetch_album_art::fetch_album_art,
         new_release_section::new_releases_tracks::{
             new_releases_tracks, process_new_releases_tracks,
         },
-        player::start_playback::start_playback,
+        player::util::context_uri,
     },
 };
 
@@ -67,14 +69,24 @@ pub fn new_release_enter_event(app: &mut App) {
         if app.enter_for_playback_in_new_release {
             app.selected_link_for_playback =
                 app.new_release_spotify_urls[app.new_release_index].clone();
-            if let Err(e) = start_playback(app) {
-                println!("{}", e);
-            }
+            play_track_in_context(
+                app,
+                context_uri("album", &app.current_new_release_album_link),
+                Vec::new(),
+            );
         } else {
             if let Err(e) = new_releases_tracks(app) {
                 println!("{}", e);
             }
             process_new_releases_tracks(app);
+            check_liked_tracks(app, &app.new_release_spotify_urls.clone());
+            if let Some(image_url) = app
+                .new_release_state
+                .selected()
+                .and_then(|index| app.new_release_image_urls.get(index))
+            {
+                let _ = fetch_album_art(image_url);
+            }
             app.new_release_display = true;
             app.searched_album_selected = false;
             app.searched_artist_selected = false;
@@ -1,10 +1,19 @@
+use super::selection::{clear_selection, selected_links};
+use super::util::{play_track_in_context, playback_window, selected_track_link};
 use crate::{
     app::App,
     enums::Menu,
     spotify::player::{
-        next_track::next_track, pause_playback::pause, play_playback::play,
-        previous_track::previous_track, repeat::cycle_repeat, shuffle::toogle_shuffle,
-        volume_decrease::volume_decreament, volume_increase::volume_increment,
+        next_track::next_track,
+        pause_playback::pause,
+        play_playback::play,
+        player::{interpolate_progress, sync_progress},
+        previous_track::previous_track,
+        repeat::cycle_repeat,
+        shuffle::toogle_shuffle,
+        start_playback::start_playback,
+        volume_decrease::volume_decreament,
+        volume_increase::volume_increment,
     },
     structs::Settings,
 };
@@ -17,10 +26,15 @@ pub fn fullscreen_player_event(app: &mut App) {
     }
 }
 
+pub fn lyrics_event(app: &mut App) {
+    app.lyrics_display = !app.lyrics_display;
+}
+
 pub fn repeat_event(app: &mut App) {
     if let Err(e) = cycle_repeat(app) {
         println!("{}", e);
     }
+    app.request_player_refresh();
 }
 
 pub fn shuffle_event(app: &mut App) {
@@ -28,40 +42,72 @@ pub fn shuffle_event(app: &mut App) {
     if let Err(e) = toogle_shuffle(app) {
         println!("{}", e);
     }
+    app.request_player_refresh();
 }
 
 pub fn volume_decreament_event(app: &mut App, settings: &mut Settings) {
     if let Err(e) = volume_decreament(app, settings) {
         println!("{}", e);
     }
+    app.request_player_refresh();
 }
 
 pub fn volume_increment_event(app: &mut App, settings: &mut Settings) {
     if let Err(e) = volume_increment(app, settings) {
         println!("{}", e);
     }
+    app.request_player_refresh();
 }
 
 pub fn next_track_event(app: &mut App) {
     if let Err(e) = next_track(app) {
         println!("{}", e);
     }
+    app.request_player_refresh();
 }
 
 pub fn previous_track_event(app: &mut App) {
     if let Err(e) = previous_track(app) {
         println!("{}", e);
     }
+    app.request_player_refresh();
 }
 
 pub fn play_pause_event(app: &mut App) {
+    // Start or stop the local progress right away instead of waiting for the next poll
     if app.playback_status == "Paused" {
         if let Err(e) = play(app) {
             println!("{}", e);
+        } else {
+            sync_progress(app);
+            app.is_playing = true;
         }
     } else if app.playback_status == "Playing" {
         if let Err(e) = pause(app) {
             println!("{}", e);
+        } else {
+            interpolate_progress(app);
+            app.is_playing = false;
+        }
+    }
+    app.request_player_refresh();
+}
+
+/// Plays just the selected track without the rest of its album or playlist, or only the selected rows as a list
+pub fn play_only_selected_track_event(app: &mut App) {
+    let links = selected_links(app);
+    if let Some(first_link) = links.first() {
+        app.selected_link_for_playback = first_link.clone();
+        play_track_in_context(app, String::new(), playback_window(&links, 0));
+        clear_selection(app);
+        return;
+    }
+
+    if let Some(link) = selected_track_link(app) {
+        app.selected_link_for_playback = link;
+        if let Err(e) = start_playback(app) {
+            println!("{}", e);
         }
+        app.request_player_refresh();
     }
 }
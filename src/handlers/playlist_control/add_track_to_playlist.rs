@@ -1,75 +1,197 @@
+use std::collections::HashSet;
+
+use rspotify::ClientError;
+
 use crate::app::App;
-use crate::enums::{Library, Menu, SearchMenu};
-use crate::handlers::util::{down_key_for_list, up_key_for_list};
+use crate::enums::{InsertPosition, Library, Menu, SearchMenu, TextInputAction};
+use crate::handlers::selection::{clear_selection, selected_links};
+use crate::handlers::text_input::open_text_input;
+use crate::handlers::util::{down_key_for_list, selected_track_link, up_key_for_list};
+use crate::spotify::library_section::saved_tracks::track_id;
 use crate::spotify::playlist_control::add_track_to_playlist::add_track_to_playlist;
+use crate::spotify::playlist_control::playlist_tracks::playlist_track_ids;
+use crate::spotify::playlist_control::source_tracks::{album_track_ids, artist_top_track_ids};
+
+/// A whole album, playlist or artist whose tracks are added at once, with its link and name
+enum TrackSource {
+    Album(String, String),
+    Playlist(String, String),
+    Artist(String, String),
+}
 
+/// Opens the playlist picker for the selected rows, the selected track, or every track of the selected album, playlist or artist
 pub fn add_track_to_playlist_event(app: &mut App) {
-    if app.selected_menu == Menu::Library {
-        if app.selected_library == Library::RecentlyPlayed {
-            app.track_added_to_playlist_link =
-                app.recently_played_links[app.recently_played_index].clone();
-            app.selected_menu = Menu::AddTrackToPlaylist;
-        } else if app.selected_library == Library::LikedSongs {
-            app.track_added_to_playlist_link = app.liked_song_links[app.liked_songs_index].clone();
-            app.selected_menu = Menu::AddTrackToPlaylist;
-        } else if app.selected_library == Library::MadeFY {
-            if app.made_fy_track_selected {
-                app.track_added_to_playlist_link =
-                    app.made_fy_track_links[app.made_fy_track_index].clone();
-                app.selected_menu = Menu::AddTrackToPlaylist;
-            }
-        } else if app.selected_library == Library::Albums {
-            if app.user_album_track_selected {
-                app.track_added_to_playlist_link =
-                    app.user_album_track_links[app.user_album_track_index].clone();
-                app.selected_menu = Menu::AddTrackToPlaylist;
+    let links = selected_links(app);
+    let (links, name, whole_source) = if !links.is_empty() {
+        let name = format!("{} selected tracks", links.len());
+        (links, name, false)
+    } else if let Some(link) = selected_track_link(app) {
+        (vec![link], "the selected track".to_string(), false)
+    } else {
+        let Some(source) = track_source(app) else {
+            return;
+        };
+        let (result, name) = match source {
+            TrackSource::Album(link, name) => (
+                album_track_ids(app, &link),
+                format!("the album \"{}\"", name),
+            ),
+            TrackSource::Playlist(link, name) => (
+                playlist_track_ids(app, &link),
+                format!("the playlist \"{}\"", name),
+            ),
+            TrackSource::Artist(link, name) => (
+                artist_top_track_ids(app, &link),
+                format!("the top tracks of {}", name),
+            ),
+        };
+        match result {
+            Ok(track_ids) if !track_ids.is_empty() => (track_ids, name, true),
+            Ok(_) => return,
+            Err(e) => {
+                println!("{}", e);
+                return;
             }
-        } else if app.selected_library == Library::Artists && app.user_artist_track_selected {
-            app.track_added_to_playlist_link =
-                app.user_artist_track_links[app.user_artist_track_index].clone();
-            app.selected_menu = Menu::AddTrackToPlaylist;
         }
-    } else if app.selected_menu == Menu::NewRelease {
-        if app.enter_for_playback_in_new_release {
-            app.track_added_to_playlist_link =
-                app.new_release_spotify_urls[app.new_release_index].clone();
-            app.selected_menu = Menu::AddTrackToPlaylist;
+    };
+
+    app.track_added_to_playlist_links = links;
+    app.track_added_to_playlist_name = name;
+    app.add_whole_source_to_playlist = whole_source;
+    app.playlist_insert_position = InsertPosition::End;
+    app.duplicate_warning_playlist_link.clear();
+    app.selected_menu = Menu::AddTrackToPlaylist;
+}
+
+pub fn add_track_to_playlist_enter_event(app: &mut App) {
+    if app.selected_menu != Menu::AddTrackToPlaylist {
+        return;
+    }
+    app.playlist_link_for_track_addition =
+        app.user_playlist_links[app.playlist_index_for_track_addition].clone();
+    let playlist_link = app.playlist_link_for_track_addition.clone();
+    let playlist_name = app.user_playlist_names[app.playlist_index_for_track_addition].clone();
+
+    // Whole albums, playlists and artists only add what the playlist does not have yet
+    let skipped = if app.add_whole_source_to_playlist {
+        match skip_tracks_in_playlist(app, &playlist_link) {
+            Ok(skipped) => skipped,
+            Err(e) => {
+                println!("{}", e);
+                return;
+            }
         }
-    } else if app.selected_menu == Menu::Playlists {
-        if app.enter_for_playback_in_user_playlist {
-            app.track_added_to_playlist_link =
-                app.user_playlist_track_links[app.user_playlist_index].clone();
-            app.selected_menu = Menu::AddTrackToPlaylist;
+    } else if warn_about_duplicates(app, &playlist_link, &playlist_name) {
+        return;
+    } else {
+        0
+    };
+
+    app.duplicate_warning_playlist_link.clear();
+    app.selected_menu = Menu::Default;
+    if app.track_added_to_playlist_links.is_empty() {
+        app.show_toast(format!("Every track is already in \"{}\"", playlist_name));
+        return;
+    }
+
+    match add_track_to_playlist(app) {
+        Ok(()) => {
+            clear_selection(app);
+            if app.add_whole_source_to_playlist {
+                let mut message = format!(
+                    "Added {} tracks to \"{}\"",
+                    app.track_added_to_playlist_links.len(),
+                    playlist_name
+                );
+                if skipped > 0 {
+                    message.push_str(&format!(", skipped {} already there", skipped));
+                }
+                app.show_toast(message);
+            }
         }
-    } else if app.selected_menu == Menu::Search {
-        if app.is_in_track {
-            app.track_added_to_playlist_link =
-                app.track_links_search_results[app.track_index].clone();
-            app.selected_menu = Menu::AddTrackToPlaylist;
-        } else if app.search_menu == SearchMenu::SearchedAlbum {
-            app.track_added_to_playlist_link =
-                app.selected_album_tracks_links[app.searched_album_index].clone();
-            app.selected_menu = Menu::AddTrackToPlaylist;
-        } else if app.search_menu == SearchMenu::SearchedArtist {
-            app.track_added_to_playlist_link =
-                app.selected_artist_tracks_links[app.searched_artist_index].clone();
-            app.selected_menu = Menu::AddTrackToPlaylist;
-        } else if app.search_menu == SearchMenu::SearchedPlaylist {
-            app.track_added_to_playlist_link =
-                app.selected_playlist_tracks_links[app.searched_playlist_index].clone();
-            app.selected_menu = Menu::AddTrackToPlaylist;
+        Err(e) => println!("{}", e),
+    }
+}
+
+/// Drops the tracks the playlist already has, and repeats within the source, returning how many were dropped
+fn skip_tracks_in_playlist(app: &mut App, playlist_link: &str) -> Result<usize, ClientError> {
+    let mut known_ids: HashSet<String> = playlist_track_ids(app, playlist_link)?
+        .into_iter()
+        .collect();
+    let count = app.track_added_to_playlist_links.len();
+    app.track_added_to_playlist_links
+        .retain(|link| track_id(link).is_some_and(|id| known_ids.insert(id)));
+
+    Ok(count - app.track_added_to_playlist_links.len())
+}
+
+/// Warns once when tracks are already in the playlist, pressing Enter again adds them anyway
+///
+/// Returns whether the warning was shown.
+fn warn_about_duplicates(app: &mut App, playlist_link: &str, playlist_name: &str) -> bool {
+    if app.duplicate_warning_playlist_link == playlist_link {
+        return false;
+    }
+    let track_ids = match playlist_track_ids(app, playlist_link) {
+        Ok(track_ids) => track_ids,
+        Err(e) => {
+            println!("{}", e);
+            return false;
         }
+    };
+    let duplicates = app
+        .track_added_to_playlist_links
+        .iter()
+        .filter(|link| track_id(link).is_some_and(|id| track_ids.contains(&id)))
+        .count();
+    if duplicates == 0 {
+        return false;
     }
+
+    app.duplicate_warning_playlist_link = playlist_link.to_string();
+    let message = if app.track_added_to_playlist_links.len() == 1 {
+        format!("Already in \"{}\"", playlist_name)
+    } else {
+        format!(
+            "{} of the {} tracks are already in \"{}\"",
+            duplicates,
+            app.track_added_to_playlist_links.len(),
+            playlist_name
+        )
+    };
+    app.show_toast(format!("{}, press Enter again to add anyway", message));
+    true
 }
 
-pub fn add_track_to_playlist_enter_event(app: &mut App) {
+/// Cycles where the track goes: the end of the playlist, the top, or a typed position
+pub fn add_track_to_playlist_tab_event(app: &mut App) {
     if app.selected_menu == Menu::AddTrackToPlaylist {
-        app.playlist_link_for_track_addition =
-            app.user_playlist_links[app.playlist_index_for_track_addition].clone();
-        if let Err(e) = add_track_to_playlist(app) {
-            println!("{}", e);
+        match app.playlist_insert_position {
+            InsertPosition::End => app.playlist_insert_position = InsertPosition::Top,
+            InsertPosition::Top => {
+                // Cancelling the input goes back to adding at the end
+                app.playlist_insert_position = InsertPosition::End;
+                open_text_input(
+                    app,
+                    TextInputAction::InsertPosition,
+                    "Position in the playlist (1 is the top)",
+                    "",
+                );
+            }
+            InsertPosition::At(_) => app.playlist_insert_position = InsertPosition::End,
+        }
+    }
+}
+
+pub fn insert_position_submit(app: &mut App, position: &str) {
+    match position.parse::<u32>() {
+        Ok(position) if position >= 1 => {
+            app.playlist_insert_position = InsertPosition::At(position - 1);
         }
-        app.selected_menu = Menu::Default;
+        _ => app.show_toast(format!(
+            "\"{}\" is not a position, use a number from 1",
+            position
+        )),
     }
 }
 
@@ -96,3 +218,65 @@ pub fn add_track_to_playlist_down_event(app: &mut App) {
         );
     }
 }
+
+fn track_source(app: &App) -> Option<TrackSource> {
+    let source = |links: &[String], names: &[String], index: usize| {
+        Some((links.get(index)?.clone(), names.get(index)?.clone()))
+    };
+    match app.selected_menu {
+        Menu::Search if app.search_menu == SearchMenu::Default => {
+            if app.selected_album_in_search_result {
+                let (link, name) = source(
+                    &app.album_links_search_results,
+                    &app.album_names_search_results,
+                    app.album_index,
+                )?;
+                Some(TrackSource::Album(link, name))
+            } else if app.selected_playlist_in_search_result {
+                let (link, name) = source(
+                    &app.playlist_links_search_results,
+                    &app.playlist_names_search_results,
+                    app.playlist_index,
+                )?;
+                Some(TrackSource::Playlist(link, name))
+            } else if app.selected_artist_in_search_result {
+                let (link, name) = source(
+                    &app.artist_links_search_results,
+                    &app.artist_names_search_results,
+                    app.artist_index,
+                )?;
+                Some(TrackSource::Artist(link, name))
+            } else {
+                None
+            }
+        }
+        Menu::NewRelease if !app.current_new_release_album_link.is_empty() => {
+            Some(TrackSource::Album(
+                app.current_new_release_album_link.clone(),
+                app.current_new_release.clone(),
+            ))
+        }
+        Menu::Library if app.selected_library == Library::Albums && app.user_album_display => {
+            let (link, name) = source(
+                &app.user_album_links,
+                &app.user_album_names,
+                app.user_album_index,
+            )?;
+            Some(TrackSource::Album(link, name))
+        }
+        Menu::Library if app.selected_library == Library::Artists && app.user_artist_display => {
+            let (link, name) = source(
+                &app.user_artist_links,
+                &app.user_artist_names,
+                app.user_artist_index,
+            )?;
+            Some(TrackSource::Artist(link, name))
+        }
+        Menu::Playlists => {
+            let index = app.user_playlist_state.selected()?;
+            let (link, name) = source(&app.user_playlist_links, &app.user_playlist_names, index)?;
+            Some(TrackSource::Playlist(link, name))
+        }
+        _ => None,
+    }
+}
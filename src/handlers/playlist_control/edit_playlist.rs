@@ -0,0 +1,199 @@
+use crate::{
+    app::App,
+    enums::{Menu, TextInputAction},
+    handlers::{
+        text_input::open_text_input,
+        util::{down_key_for_list, up_key_for_list},
+    },
+    spotify::{
+        player::util::context_uri,
+        playlist_control::playlist_details::{
+            change_playlist_details, create_playlist, playlist_description, PlaylistChanges,
+        },
+        user_playlist::user_playlist::{get_playlists, process_user_playlists},
+    },
+};
+
+/// Rename, description, public and collaborative
+pub const PLAYLIST_EDITOR_OPTIONS: usize = 4;
+
+/// Asks for the name of a new playlist
+pub fn create_playlist_event(app: &mut App) {
+    if app.selected_menu == Menu::Playlists {
+        open_text_input(
+            app,
+            TextInputAction::CreatePlaylist,
+            "Name of the new playlist",
+            "",
+        );
+    }
+}
+
+/// Opens the editor for the selected playlist, only playlists the user owns can be edited
+pub fn edit_playlist_event(app: &mut App) {
+    if app.selected_menu != Menu::Playlists {
+        return;
+    }
+    let Some(index) = app.user_playlist_state.selected() else {
+        return;
+    };
+    if !app.user_playlist_owned.get(index).copied().unwrap_or(false) {
+        app.error_text = format!(
+            "\"{}\" belongs to someone else, only your own playlists can be edited",
+            app.user_playlist_names[index]
+        );
+        app.selected_menu = Menu::Error;
+        return;
+    }
+
+    let playlist_link = app.user_playlist_links[index].clone();
+    app.playlist_editor_description = match playlist_description(app, &playlist_link) {
+        Ok(description) => description,
+        Err(e) => {
+            println!("{}", e);
+            String::new()
+        }
+    };
+    app.playlist_editor_index = 0;
+    app.playlist_editor_state.select(Some(0));
+    app.selected_menu = Menu::PlaylistEditor;
+}
+
+pub fn playlist_editor_down_event(app: &mut App) {
+    if app.selected_menu == Menu::PlaylistEditor {
+        let options = vec![String::new(); PLAYLIST_EDITOR_OPTIONS];
+        (app.playlist_editor_state, app.playlist_editor_index) =
+            down_key_for_list(options, app.playlist_editor_state.clone());
+        app.playlist_editor_index %= PLAYLIST_EDITOR_OPTIONS;
+    }
+}
+
+pub fn playlist_editor_up_event(app: &mut App) {
+    if app.selected_menu == Menu::PlaylistEditor {
+        let options = vec![String::new(); PLAYLIST_EDITOR_OPTIONS];
+        (app.playlist_editor_state, app.playlist_editor_index) =
+            up_key_for_list(options, app.playlist_editor_state.clone());
+    }
+}
+
+pub fn playlist_editor_enter_event(app: &mut App) {
+    if app.selected_menu != Menu::PlaylistEditor {
+        return;
+    }
+    let Some(index) = app.user_playlist_state.selected() else {
+        return;
+    };
+
+    match app.playlist_editor_index {
+        0 => {
+            let name = app.user_playlist_names[index].clone();
+            open_text_input(app, TextInputAction::RenamePlaylist, "New name", &name);
+        }
+        1 => {
+            let description = app.playlist_editor_description.clone();
+            open_text_input(
+                app,
+                TextInputAction::EditPlaylistDescription,
+                "Description (leave empty to clear it)",
+                &description,
+            );
+        }
+        2 => {
+            // Spotify only allows collaboration on private playlists
+            let public = !app.user_playlist_public[index];
+            let changes = PlaylistChanges {
+                public: Some(public),
+                collaborative: if public { Some(false) } else { None },
+                ..Default::default()
+            };
+            apply_changes(app, changes);
+        }
+        _ => {
+            let collaborative = !app.user_playlist_collaborative[index];
+            let changes = PlaylistChanges {
+                collaborative: Some(collaborative),
+                public: if collaborative { Some(false) } else { None },
+                ..Default::default()
+            };
+            apply_changes(app, changes);
+        }
+    }
+}
+
+pub fn create_playlist_submit(app: &mut App, name: &str) {
+    if name.is_empty() {
+        return;
+    }
+    let uri = match create_playlist(app, name) {
+        Ok(uri) if !uri.is_empty() => uri,
+        Ok(_) => return,
+        Err(e) => {
+            println!("{}", e);
+            return;
+        }
+    };
+
+    // Open the new playlist wherever it ended up in the list
+    let Some(index) = refresh_playlists(app, Some(&uri)) else {
+        return;
+    };
+    app.user_playlist_index = index;
+    app.selected_playlist_uri = app.user_playlist_links[index].clone();
+    app.have_playlist = true;
+    app.user_playlist_display = false;
+    app.selected_menu = Menu::Playlists;
+}
+
+pub fn rename_playlist_submit(app: &mut App, name: &str) {
+    if name.is_empty() {
+        return;
+    }
+    apply_changes(
+        app,
+        PlaylistChanges {
+            name: Some(name),
+            ..Default::default()
+        },
+    );
+}
+
+pub fn description_submit(app: &mut App, description: &str) {
+    apply_changes(
+        app,
+        PlaylistChanges {
+            description: Some(description),
+            ..Default::default()
+        },
+    );
+    if app.selected_menu == Menu::PlaylistEditor {
+        app.playlist_editor_description = description.to_string();
+    }
+}
+
+fn apply_changes(app: &mut App, changes: PlaylistChanges) {
+    let Some(index) = app.user_playlist_state.selected() else {
+        return;
+    };
+    let playlist_link = app.user_playlist_links[index].clone();
+    if let Err(e) = change_playlist_details(app, &playlist_link, changes) {
+        println!("{}", e);
+        return;
+    }
+    refresh_playlists(app, Some(&playlist_link));
+}
+
+/// Reloads the playlist sidebar, keeping `selected_link` selected when it is given and returning its row
+pub fn refresh_playlists(app: &mut App, selected_link: Option<&str>) -> Option<usize> {
+    get_playlists(app);
+    process_user_playlists(app);
+
+    // Links may be urls or uris, so they are compared by uri
+    let uri = context_uri("playlist", selected_link?);
+    let index = app
+        .user_playlist_links
+        .iter()
+        .position(|playlist_link| context_uri("playlist", playlist_link) == uri)?;
+    app.user_playlist_state.select(Some(index));
+    app.current_user_playlist = app.user_playlist_names[index].clone();
+    Some(index)
+}
@@ -0,0 +1,254 @@
+use crate::{
+    app::App,
+    enums::{Menu, TextInputAction},
+    handlers::{
+        selection::{clear_selection, selected_rows},
+        text_input::open_text_input,
+    },
+    spotify::playlist_control::playlist_tracks::{remove_playlist_tracks, reorder_playlist_track},
+};
+
+/// Copy of the track table taken before a change, put back when Spotify rejects the change
+struct PlaylistTracksBackup {
+    names: Vec<String>,
+    artist_names: Vec<String>,
+    album_names: Vec<String>,
+    durations: Vec<i64>,
+    links: Vec<String>,
+    positions: Vec<u32>,
+    index: usize,
+}
+
+impl PlaylistTracksBackup {
+    fn take(app: &App) -> Self {
+        PlaylistTracksBackup {
+            names: app.user_playlist_track_names.clone(),
+            artist_names: app.user_playlist_artist_names.clone(),
+            album_names: app.user_playlist_album_names.clone(),
+            durations: app.user_playlist_track_duration.clone(),
+            links: app.user_playlist_track_links.clone(),
+            positions: app.user_playlist_track_positions.clone(),
+            index: app.user_playlist_index,
+        }
+    }
+
+    fn restore(self, app: &mut App) {
+        app.user_playlist_track_names = self.names;
+        app.user_playlist_artist_names = self.artist_names;
+        app.user_playlist_album_names = self.album_names;
+        app.user_playlist_track_duration = self.durations;
+        app.user_playlist_track_links = self.links;
+        app.user_playlist_track_positions = self.positions;
+        select_track_row(app, self.index);
+    }
+}
+
+/// Removes the selected rows, or the selected track when no rows are selected, from the opened playlist
+pub fn remove_playlist_track_event(app: &mut App) {
+    let rows = selected_rows(app);
+    if rows.is_empty() {
+        let row = app.user_playlist_index;
+        remove_playlist_rows(app, &[row]);
+    } else if remove_playlist_rows(app, &rows) {
+        clear_selection(app);
+    }
+}
+
+/// Removes `rows` from the opened playlist right away, and puts them back if Spotify rejects it
+///
+/// Returns whether the rows were removed.
+pub fn remove_playlist_rows(app: &mut App, rows: &[usize]) -> bool {
+    let Some(playlist) = editable_playlist_index(app) else {
+        return false;
+    };
+    let removed: Vec<(String, u32)> = rows
+        .iter()
+        .filter_map(|row| {
+            Some((
+                app.user_playlist_track_links.get(*row)?.clone(),
+                *app.user_playlist_track_positions.get(*row)?,
+            ))
+        })
+        .collect();
+    if removed.is_empty() {
+        return false;
+    }
+
+    let backup = PlaylistTracksBackup::take(app);
+    let mut rows = rows.to_vec();
+    rows.sort_unstable();
+    for row in rows.iter().rev() {
+        app.user_playlist_track_names.remove(*row);
+        app.user_playlist_artist_names.remove(*row);
+        app.user_playlist_album_names.remove(*row);
+        app.user_playlist_track_duration.remove(*row);
+        app.user_playlist_track_links.remove(*row);
+        app.user_playlist_track_positions.remove(*row);
+    }
+    // Everything after a removed item moves up by one in the playlist
+    for position in app.user_playlist_track_positions.iter_mut() {
+        *position -= removed
+            .iter()
+            .filter(|(_, removed)| removed < position)
+            .count() as u32;
+    }
+    select_track_row(app, app.user_playlist_index);
+
+    let playlist_link = app.user_playlist_links[playlist].clone();
+    let snapshot_id = app.user_playlist_snapshot_ids[playlist].clone();
+    match remove_playlist_tracks(app, &playlist_link, &removed, &snapshot_id) {
+        Ok(snapshot_id) => {
+            app.user_playlist_snapshot_ids[playlist] = snapshot_id;
+            true
+        }
+        Err(e) => {
+            backup.restore(app);
+            app.show_toast(format!("Could not remove from the playlist: {}", e));
+            false
+        }
+    }
+}
+
+pub fn move_playlist_track_up_event(app: &mut App) {
+    let row = app.user_playlist_index;
+    if row > 0 {
+        move_playlist_track(app, row, row - 1);
+    }
+}
+
+pub fn move_playlist_track_down_event(app: &mut App) {
+    let row = app.user_playlist_index;
+    if row + 1 < app.user_playlist_track_links.len() {
+        move_playlist_track(app, row, row + 1);
+    }
+}
+
+/// Asks for the position the selected track should move to
+pub fn move_playlist_track_to_event(app: &mut App) {
+    if editable_playlist_index(app).is_some() && !app.user_playlist_track_links.is_empty() {
+        let title = format!(
+            "Move to position (1-{})",
+            app.user_playlist_track_links.len()
+        );
+        open_text_input(app, TextInputAction::MovePlaylistTrack, &title, "");
+    }
+}
+
+pub fn move_playlist_track_to_submit(app: &mut App, position: &str) {
+    let length = app.user_playlist_track_links.len();
+    match position.parse::<usize>() {
+        Ok(position) if (1..=length).contains(&position) => {
+            move_playlist_track(app, app.user_playlist_index, position - 1);
+        }
+        _ => app.show_toast(format!(
+            "\"{}\" is not a position, use a number from 1 to {}",
+            position, length
+        )),
+    }
+}
+
+/// Moves the track on row `from` to row `to` right away, and moves it back if Spotify rejects it
+fn move_playlist_track(app: &mut App, from: usize, to: usize) {
+    let Some(playlist) = editable_playlist_index(app) else {
+        return;
+    };
+    if from == to || from >= app.user_playlist_track_positions.len() {
+        return;
+    }
+
+    // Spotify counts positions in the whole playlist, which can include items without a row
+    let range_start = app.user_playlist_track_positions[from];
+    let insert_before = if to < from {
+        app.user_playlist_track_positions[to]
+    } else {
+        app.user_playlist_track_positions[to] + 1
+    };
+
+    let backup = PlaylistTracksBackup::take(app);
+    for position in app.user_playlist_track_positions.iter_mut() {
+        *position = moved_position(*position, range_start, insert_before);
+    }
+    let mut order: Vec<usize> = (0..app.user_playlist_track_positions.len()).collect();
+    order.sort_by_key(|row| app.user_playlist_track_positions[*row]);
+    app.user_playlist_track_names = reorder(&app.user_playlist_track_names, &order);
+    app.user_playlist_artist_names = reorder(&app.user_playlist_artist_names, &order);
+    app.user_playlist_album_names = reorder(&app.user_playlist_album_names, &order);
+    app.user_playlist_track_duration = reorder(&app.user_playlist_track_duration, &order);
+    app.user_playlist_track_links = reorder(&app.user_playlist_track_links, &order);
+    app.user_playlist_track_positions = reorder(&app.user_playlist_track_positions, &order);
+    select_track_row(app, to);
+
+    let playlist_link = app.user_playlist_links[playlist].clone();
+    let snapshot_id = app.user_playlist_snapshot_ids[playlist].clone();
+    match reorder_playlist_track(
+        app,
+        &playlist_link,
+        range_start,
+        insert_before,
+        &snapshot_id,
+    ) {
+        Ok(snapshot_id) => app.user_playlist_snapshot_ids[playlist] = snapshot_id,
+        Err(e) => {
+            backup.restore(app);
+            app.show_toast(format!("Could not move the track: {}", e));
+        }
+    }
+}
+
+/// Returns the index of the opened playlist when its track table is focused and the user may change it
+pub fn editable_playlist_index(app: &mut App) -> Option<usize> {
+    if app.selected_menu != Menu::Playlists
+        || !app.user_playlist_display
+        || !app.user_playlist_tracks_selected
+    {
+        return None;
+    }
+    let index = app
+        .user_playlist_links
+        .iter()
+        .position(|link| *link == app.selected_playlist_uri)?;
+
+    if app.user_playlist_owned[index] || app.user_playlist_collaborative[index] {
+        Some(index)
+    } else {
+        app.show_toast(format!(
+            "\"{}\" belongs to someone else and cannot be changed",
+            app.user_playlist_names[index]
+        ));
+        None
+    }
+}
+
+// Where an item ends up after the item at `range_start` is moved before `insert_before`
+fn moved_position(position: u32, range_start: u32, insert_before: u32) -> u32 {
+    if position == range_start {
+        if insert_before > range_start {
+            insert_before - 1
+        } else {
+            insert_before
+        }
+    } else if range_start < position && position < insert_before {
+        position - 1
+    } else if insert_before <= position && position < range_start {
+        position + 1
+    } else {
+        position
+    }
+}
+
+fn reorder<T: Clone>(values: &[T], order: &[usize]) -> Vec<T> {
+    order
+        .iter()
+        .filter_map(|row| values.get(*row).cloned())
+        .collect()
+}
+
+fn select_track_row(app: &mut App, row: usize) {
+    let length = app.user_playlist_track_links.len();
+    app.user_playlist_index = row.min(length.saturating_sub(1));
+    app.user_playlist_tracks_state.select(if length == 0 {
+        None
+    } else {
+        Some(app.user_playlist_index)
+    });
+}
@@ -1,3 +1,5 @@
 pub mod add_track_to_playlist;
+pub mod edit_playlist;
+pub mod edit_tracks;
 pub mod follow_playlist;
 pub mod unfollow_playlist;
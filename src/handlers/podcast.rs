@@ -0,0 +1,179 @@
+use super::util::play_track_in_context;
+use crate::{
+    app::App,
+    enums::{Library, Menu, SearchMenu},
+    spotify::library_section::{
+        audiobooks::{audiobook_chapters, process_audiobook_chapters},
+        podcast::{process_podcasts, user_podcast},
+        podcast_episodes::{podcast_episodes, process_podcast_episodes},
+        saved_episodes::{check_saved_episodes, remove_episode, save_episode},
+        saved_shows::{check_selected_show_saved, remove_show, save_show},
+    },
+};
+
+/// Lists the episodes of `app.selected_show_link` along with which ones are saved,
+/// returns false when they could not be fetched and the show was not opened
+pub fn open_show(app: &mut App) -> bool {
+    match podcast_episodes(app) {
+        Ok(true) => {}
+        Ok(false) => return false,
+        Err(e) => {
+            println!("{}", e);
+            return false;
+        }
+    }
+    process_podcast_episodes(app);
+    if let Err(e) = check_saved_episodes(app) {
+        println!("{}", e);
+    }
+    if let Err(e) = check_selected_show_saved(app) {
+        println!("{}", e);
+    }
+
+    app.podcast_episode_display = true;
+    app.podcast_episode_selected = true;
+    app.podcast_episode_index = 0;
+    app.podcast_episode_state.select(Some(0));
+    true
+}
+
+/// Lists the chapters of the selected saved audiobook on the episode page,
+/// returns false when they could not be fetched and the audiobook was not opened
+pub fn open_audiobook(app: &mut App) -> bool {
+    let index = app.audiobook_index;
+    app.selected_show_link = app.audiobook_ids[index].clone();
+    app.selected_show_name = app.audiobook_names[index].clone();
+    let mut credits = format!("By {}", app.audiobook_authors[index]);
+    if !app.audiobook_narrators[index].is_empty() {
+        credits.push_str(&format!(", narrated by {}", app.audiobook_narrators[index]));
+    }
+    app.selected_show_description = format!("{}. {}", credits, app.audiobook_descriptions[index]);
+    app.selected_show_saved = true;
+
+    if let Err(e) = audiobook_chapters(app) {
+        println!("{}", e);
+        return false;
+    }
+    process_audiobook_chapters(app);
+
+    app.podcast_episode_display = true;
+    app.podcast_episode_selected = true;
+    app.podcast_episode_index = 0;
+    app.podcast_episode_state.select(Some(0));
+    true
+}
+
+/// Plays the selected episode from where the user stopped, finished episodes start over
+pub fn play_selected_episode(app: &mut App, context_uri: String, episode_links: Vec<String>) {
+    let index = app.podcast_episode_index;
+    let Some(link) = app.podcast_episode_links.get(index).cloned() else {
+        return;
+    };
+
+    app.selected_link_for_playback = link;
+    if !app.podcast_episode_fully_played[index] {
+        app.playback_start_position_ms = app.podcast_episode_resume_points[index];
+    }
+    play_track_in_context(app, context_uri, episode_links);
+}
+
+/// Saves the show selected in search results or the one whose page is open
+pub fn follow_show_event(app: &mut App) {
+    let show_page_open = show_page_open(app);
+    if show_page_open {
+        app.show_link_to_save = app.selected_show_link.clone();
+    } else if app.selected_menu == Menu::Search && app.selected_show_in_search_result {
+        match app.show_links_search_results.get(app.show_index) {
+            Some(link) => app.show_link_to_save = link.clone(),
+            None => return,
+        }
+    } else {
+        return;
+    }
+
+    match save_show(app) {
+        Ok(_) if show_page_open => app.selected_show_saved = true,
+        Ok(_) => {}
+        Err(e) => println!("{}", e),
+    }
+}
+
+/// Removes the show selected in search results, in the saved podcasts or the one whose page is open
+pub fn unfollow_show_event(app: &mut App) {
+    let show_page_open = show_page_open(app);
+    let in_saved_podcasts = app.selected_menu == Menu::Library
+        && app.selected_library == Library::Podcasts
+        && app.podcast_display
+        && app.podcast_selected;
+
+    if show_page_open {
+        app.show_link_to_save = app.selected_show_link.clone();
+    } else if in_saved_podcasts {
+        match app.podcast_links.get(app.podcast_index) {
+            Some(link) => app.show_link_to_save = link.clone(),
+            None => return,
+        }
+    } else if app.selected_menu == Menu::Search && app.selected_show_in_search_result {
+        match app.show_links_search_results.get(app.show_index) {
+            Some(link) => app.show_link_to_save = link.clone(),
+            None => return,
+        }
+    } else {
+        return;
+    }
+
+    if let Err(e) = remove_show(app) {
+        println!("{}", e);
+        return;
+    }
+
+    if show_page_open {
+        app.selected_show_saved = false;
+    }
+    if in_saved_podcasts {
+        // Refresh the list so the removed show disappears
+        if let Err(e) = user_podcast(app) {
+            println!("{}", e);
+        }
+        process_podcasts(app);
+        app.podcast_index = app
+            .podcast_index
+            .min(app.podcast_names.len().saturating_sub(1));
+        app.podcast_state.select(Some(app.podcast_index));
+    }
+}
+
+/// Adds the selected episode to Your Episodes, or removes it when it is already there
+pub fn save_episode_event(app: &mut App) {
+    let episode_table_focused = app.podcast_episode_display
+        && app.podcast_episode_selected
+        && ((app.selected_menu == Menu::Library
+            && matches!(app.selected_library, Library::Podcasts | Library::Episodes))
+            || (app.selected_menu == Menu::Search && app.search_menu == SearchMenu::SearchedShow));
+    if !episode_table_focused {
+        return;
+    }
+
+    let index = app.podcast_episode_index;
+    let Some(link) = app.podcast_episode_links.get(index).cloned() else {
+        return;
+    };
+    let saved = app.podcast_episode_saved[index];
+
+    let result = if saved {
+        remove_episode(app, &link)
+    } else {
+        save_episode(app, &link)
+    };
+    match result {
+        Ok(_) => app.podcast_episode_saved[index] = !saved,
+        Err(e) => println!("{}", e),
+    }
+}
+
+fn show_page_open(app: &App) -> bool {
+    (app.selected_menu == Menu::Search && app.search_menu == SearchMenu::SearchedShow)
+        || (app.selected_menu == Menu::Library
+            && app.selected_library == Library::Podcasts
+            && app.podcast_episode_display)
+}
@@ -0,0 +1,72 @@
+use super::like::check_liked_tracks;
+use super::selection::{clear_selection, selected_links};
+use super::util::{down_key_for_table, selected_track_link, up_key_for_table};
+use crate::{
+    app::App,
+    enums::Menu,
+    spotify::player::{
+        add_to_queue::add_to_queue,
+        queue::{process_queue, user_queue},
+    },
+};
+
+pub fn go_to_queue_event(app: &mut App) {
+    if app.selected_menu == Menu::Queue {
+        app.selected_menu = Menu::Default;
+        return;
+    }
+
+    refresh_queue(app);
+    app.queue_index = 0;
+    app.queue_state.select(Some(0));
+    app.selected_menu = Menu::Queue;
+}
+
+pub fn queue_down_event(app: &mut App) {
+    if app.selected_menu == Menu::Queue && !app.queue_names.is_empty() {
+        (app.queue_state, app.queue_index) =
+            down_key_for_table(app.queue_names.clone(), app.queue_state.clone());
+        app.queue_index %= app.queue_names.len();
+    }
+}
+
+pub fn queue_up_event(app: &mut App) {
+    if app.selected_menu == Menu::Queue && !app.queue_names.is_empty() {
+        (app.queue_state, app.queue_index) =
+            up_key_for_table(app.queue_names.clone(), app.queue_state.clone());
+    }
+}
+
+/// Adds the track selected in the current menu to the end of the queue, or every track of the selection in order
+pub fn add_to_queue_event(app: &mut App) {
+    let links = selected_links(app);
+    if !links.is_empty() {
+        for link in links {
+            app.selected_link_for_queue = link;
+            if let Err(e) = add_to_queue(app) {
+                println!("{}", e);
+                break;
+            }
+        }
+        clear_selection(app);
+        refresh_queue(app);
+        return;
+    }
+
+    if let Some(link) = selected_track_link(app) {
+        app.selected_link_for_queue = link;
+        if let Err(e) = add_to_queue(app) {
+            println!("{}", e);
+            return;
+        }
+        refresh_queue(app);
+    }
+}
+
+fn refresh_queue(app: &mut App) {
+    if let Err(e) = user_queue(app) {
+        println!("{}", e);
+    }
+    process_queue(app);
+    check_liked_tracks(app, &app.queue_links.clone());
+}
@@ -1,12 +1,14 @@
+use super::like::check_liked_tracks;
+use super::podcast::{open_show, play_selected_episode};
 use super::util::{
-    default, default_search, down_key_for_list, down_key_for_table, up_key_for_list,
-    up_key_for_table,
+    default, default_search, down_key_for_list, down_key_for_table, play_track_in_context,
+    playback_window, up_key_for_list, up_key_for_table,
 };
 use crate::{
     app::App,
     enums::{InputMode, Menu, SearchMenu},
     spotify::{
-        player::start_playback::start_playback,
+        player::util::context_uri,
         search::{
             search_albums::{process_selected_album_tracks, search_selected_album_tracks},
             search_artists::{process_selected_artist_tracks, search_selected_artist_tracks},
@@ -48,6 +50,12 @@ pub fn search_down_event(app: &mut App) {
                     app.playlist_state_in_search_result.clone(),
                 );
             }
+            if app.selected_show_in_search_result {
+                (app.show_state_in_search_result, app.show_index) = down_key_for_list(
+                    app.show_names_search_results.clone(),
+                    app.show_state_in_search_result.clone(),
+                );
+            }
         }
         if app.search_menu == SearchMenu::SearchedAlbum && app.searched_album_selected {
             (app.searched_album_state, app.searched_album_index) = down_key_for_table(
@@ -67,6 +75,12 @@ pub fn search_down_event(app: &mut App) {
                 app.searched_playlist_state.clone(),
             );
         }
+        if app.search_menu == SearchMenu::SearchedShow && app.podcast_episode_selected {
+            (app.podcast_episode_state, app.podcast_episode_index) = down_key_for_table(
+                app.podcast_episode_names.clone(),
+                app.podcast_episode_state.clone(),
+            );
+        }
     }
 }
 
@@ -97,6 +111,12 @@ pub fn search_up_event(app: &mut App) {
                     app.playlist_state_in_search_result.clone(),
                 );
             }
+            if app.selected_show_in_search_result {
+                (app.show_state_in_search_result, app.show_index) = up_key_for_list(
+                    app.show_names_search_results.clone(),
+                    app.show_state_in_search_result.clone(),
+                );
+            }
         }
         if app.search_menu == SearchMenu::SearchedAlbum && app.searched_album_selected {
             (app.searched_album_state, app.searched_album_index) = up_key_for_table(
@@ -116,6 +136,12 @@ pub fn search_up_event(app: &mut App) {
                 app.searched_playlist_state.clone(),
             );
         }
+        if app.search_menu == SearchMenu::SearchedShow && app.podcast_episode_selected {
+            (app.podcast_episode_state, app.podcast_episode_index) = up_key_for_table(
+                app.podcast_episode_names.clone(),
+                app.podcast_episode_state.clone(),
+            );
+        }
     }
 }
 
@@ -125,23 +151,25 @@ pub fn search_enter_event(app: &mut App) {
             app.is_only_id = true;
             app.selected_link_for_playback =
                 app.track_links_search_results[app.track_index].clone();
-            if let Err(e) = start_playback(app) {
-                println!("{}", e);
-            }
+            let window = playback_window(&app.track_links_search_results, app.track_index);
+            play_track_in_context(app, String::new(), window);
         }
 
         if app.search_menu == SearchMenu::SearchedAlbum {
             app.is_only_id = false;
             app.selected_link_for_playback =
                 app.selected_album_tracks_links[app.searched_album_index].clone();
-            if let Err(e) = start_playback(app) {
-                println!("{}", e);
-            }
+            play_track_in_context(
+                app,
+                context_uri("album", &app.album_links_search_results[app.album_index]),
+                Vec::new(),
+            );
         } else if app.selected_album_in_search_result {
             if let Err(e) = search_selected_album_tracks(app) {
                 println!("{}", e);
             }
             process_selected_album_tracks(app);
+            check_liked_tracks(app, &app.selected_album_tracks_links.clone());
             default_search(app);
             app.search_menu = SearchMenu::SearchedAlbum;
             app.searched_album_selected = true;
@@ -151,14 +179,15 @@ pub fn search_enter_event(app: &mut App) {
             app.is_only_id = false;
             app.selected_link_for_playback =
                 app.selected_artist_tracks_links[app.searched_artist_index].clone();
-            if let Err(e) = start_playback(app) {
-                println!("{}", e);
-            }
+            let window =
+                playback_window(&app.selected_artist_tracks_links, app.searched_artist_index);
+            play_track_in_context(app, String::new(), window);
         } else if app.selected_artist_in_search_result {
             if let Err(e) = search_selected_artist_tracks(app) {
                 println!("{}", e);
             }
             process_selected_artist_tracks(app);
+            check_liked_tracks(app, &app.selected_artist_tracks_links.clone());
             default_search(app);
             app.search_menu = SearchMenu::SearchedArtist;
             app.searched_artist_selected = true;
@@ -168,18 +197,44 @@ pub fn search_enter_event(app: &mut App) {
             app.is_only_id = false;
             app.selected_link_for_playback =
                 app.selected_playlist_tracks_links[app.searched_playlist_index].clone();
-            if let Err(e) = start_playback(app) {
-                println!("{}", e);
-            }
+            play_track_in_context(
+                app,
+                context_uri(
+                    "playlist",
+                    &app.playlist_links_search_results[app.playlist_index],
+                ),
+                Vec::new(),
+            );
         } else if app.selected_playlist_in_search_result {
             if let Err(e) = search_selected_playlist_tracks(app) {
                 println!("{}", e);
             }
             process_selected_playlist_tracks(app);
+            check_liked_tracks(app, &app.selected_playlist_tracks_links.clone());
             default_search(app);
             app.search_menu = SearchMenu::SearchedPlaylist;
             app.searched_playlist_selected = true;
         }
+
+        if app.search_menu == SearchMenu::SearchedShow {
+            play_selected_episode(
+                app,
+                context_uri("show", &app.selected_show_link),
+                Vec::new(),
+            );
+        } else if app.selected_show_in_search_result {
+            if app.show_links_search_results.is_empty() {
+                return;
+            }
+            app.selected_show_link = app.show_links_search_results[app.show_index].clone();
+            app.selected_show_name = app.show_names_search_results[app.show_index].clone();
+            app.selected_show_description =
+                app.show_descriptions_search_results[app.show_index].clone();
+            default_search(app);
+            if open_show(app) {
+                app.search_menu = SearchMenu::SearchedShow;
+            }
+        }
     }
 }
 
@@ -190,6 +245,7 @@ pub fn search_tab_event(app: &mut App) {
         app.artist_state_in_search_result.select(None);
         app.album_state_in_search_result.select(None);
         app.playlist_state_in_search_result.select(None);
+        app.show_state_in_search_result.select(None);
 
         if app.search_state.selected() == Some(0) {
             app.track_state_in_search_result.select(Some(0));
@@ -197,6 +253,7 @@ pub fn search_tab_event(app: &mut App) {
             app.selected_artist_in_search_result = false;
             app.selected_album_in_search_result = false;
             app.selected_playlist_in_search_result = false;
+            app.selected_show_in_search_result = false;
             app.is_in_track = true;
         } else if app.search_state.selected() == Some(1) {
             app.artist_state_in_search_result.select(Some(0));
@@ -204,6 +261,7 @@ pub fn search_tab_event(app: &mut App) {
             app.selected_track_in_search_result = false;
             app.selected_album_in_search_result = false;
             app.selected_playlist_in_search_result = false;
+            app.selected_show_in_search_result = false;
             app.is_in_track = false;
         } else if app.search_state.selected() == Some(2) {
             app.album_state_in_search_result.select(Some(0));
@@ -211,6 +269,7 @@ pub fn search_tab_event(app: &mut App) {
             app.selected_track_in_search_result = false;
             app.selected_artist_in_search_result = false;
             app.selected_playlist_in_search_result = false;
+            app.selected_show_in_search_result = false;
             app.is_in_track = false;
         } else if app.search_state.selected() == Some(3) {
             app.playlist_state_in_search_result.select(Some(0));
@@ -218,9 +277,19 @@ pub fn search_tab_event(app: &mut App) {
             app.selected_track_in_search_result = false;
             app.selected_artist_in_search_result = false;
             app.selected_album_in_search_result = false;
+            app.selected_show_in_search_result = false;
+            app.is_in_track = false;
+        } else if app.search_state.selected() == Some(4) {
+            app.show_state_in_search_result.select(Some(0));
+            app.show_index = 0;
+            app.selected_show_in_search_result = !app.selected_show_in_search_result;
+            app.selected_track_in_search_result = false;
+            app.selected_artist_in_search_result = false;
+            app.selected_album_in_search_result = false;
+            app.selected_playlist_in_search_result = false;
             app.is_in_track = false;
         }
-        let length = 4;
+        let length = 5;
         let next_index = app.search_state.selected().unwrap_or(0) + 1;
         app.search_state.select(Some(next_index % length));
     }
@@ -0,0 +1,103 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::{
+    app::App,
+    enums::{InputMode, Menu},
+    spotify::player::{player::sync_progress, seek::seek},
+    structs::Settings,
+};
+
+/// Skips forward by the seek step, using the podcast step while an episode plays
+pub fn seek_forward_event(app: &mut App, settings: &mut Settings) {
+    let step_ms = seek_step_seconds(app, settings) as f64 * 1000.0;
+    seek_to(app, app.currrent_timestamp + step_ms);
+}
+
+pub fn seek_backward_event(app: &mut App, settings: &mut Settings) {
+    let step_ms = seek_step_seconds(app, settings) as f64 * 1000.0;
+    seek_to(app, app.currrent_timestamp - step_ms);
+}
+
+/// Jumps to `digit` tenths of the track, so 0 restarts it and 5 goes to the middle
+pub fn seek_to_percent_event(app: &mut App, digit: u32) {
+    seek_to(app, app.ending_timestamp * digit as f64 / 10.0);
+}
+
+pub fn restart_track_event(app: &mut App) {
+    seek_to(app, 0.0);
+}
+
+/// Starts typing an absolute position like `1:23`
+pub fn seek_input_event(app: &mut App) {
+    app.seek_input.clear();
+    // Typing a position from the search results must not close them
+    app.seek_return_mode = app.input_mode;
+    app.input_mode = InputMode::SeekInput;
+}
+
+pub fn seek_input(app: &mut App, key_event: KeyEvent) {
+    match key_event.code {
+        KeyCode::Char(c) if c.is_ascii_digit() || c == ':' => {
+            app.seek_input.push(c);
+        }
+        KeyCode::Backspace => {
+            app.seek_input.pop();
+        }
+        KeyCode::Enter => {
+            app.input_mode = app.seek_return_mode;
+            match parse_position(&app.seek_input) {
+                Some(position_ms) => seek_to(app, position_ms),
+                None => {
+                    app.error_text = format!(
+                        "\"{}\" is not a position, use minutes:seconds like 1:23",
+                        app.seek_input
+                    );
+                    app.selected_menu = Menu::Error;
+                }
+            }
+        }
+        KeyCode::Esc => {
+            app.input_mode = app.seek_return_mode;
+        }
+        _ => {}
+    }
+}
+
+/// Parses `m:ss`, `h:mm:ss` or plain seconds into milliseconds
+fn parse_position(input: &str) -> Option<f64> {
+    let mut seconds = 0.0;
+    for part in input.trim().split(':') {
+        seconds = seconds * 60.0 + part.parse::<u32>().ok()? as f64;
+    }
+
+    Some(seconds * 1000.0)
+}
+
+fn seek_step_seconds(app: &App, settings: &Settings) -> u32 {
+    if app.currently_playing_media_type == "episode" {
+        settings.podcast_seek_step
+    } else {
+        settings.seek_step
+    }
+}
+
+// Moves the progress bar right away, the next poll confirms the position
+fn seek_to(app: &mut App, position_ms: f64) {
+    if app.current_playing_name.is_empty() {
+        return;
+    }
+
+    let position_ms = position_ms.clamp(0.0, app.ending_timestamp.max(0.0));
+    if let Err(e) = seek(app, position_ms as i64) {
+        app.error_text = format!("Error seeking: {}", e);
+        app.selected_menu = Menu::Error;
+        return;
+    }
+
+    app.currrent_timestamp = position_ms;
+    sync_progress(app);
+    app.request_player_refresh();
+    if app.ending_timestamp > 0.0 {
+        app.progress_bar_ratio = app.currrent_timestamp / app.ending_timestamp;
+    }
+}
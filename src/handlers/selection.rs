@@ -0,0 +1,96 @@
+use super::util::focused_track_table;
+use crate::app::App;
+
+/// Adds the selected row to the selection, or takes it out when it is already selected
+pub fn toggle_selection_event(app: &mut App) {
+    let Some((links, index)) = focused_track_table(app) else {
+        return;
+    };
+    if index >= links.len() {
+        return;
+    }
+    let links = links.to_vec();
+    start_selection(app, links);
+
+    if let Some(position) = app
+        .track_selection_rows
+        .iter()
+        .position(|row| *row == index)
+    {
+        app.track_selection_rows.remove(position);
+    } else {
+        app.track_selection_rows.push(index);
+    }
+    app.track_selection_anchor = index;
+}
+
+/// Selects every row between the last toggled row and the selected row
+pub fn select_range_event(app: &mut App) {
+    let Some((links, index)) = focused_track_table(app) else {
+        return;
+    };
+    if index >= links.len() {
+        return;
+    }
+    let links = links.to_vec();
+    start_selection(app, links);
+
+    let anchor = app.track_selection_anchor.min(index);
+    let end = app.track_selection_anchor.max(index);
+    for row in anchor..=end {
+        if !app.track_selection_rows.contains(&row) {
+            app.track_selection_rows.push(row);
+        }
+    }
+    app.track_selection_anchor = index;
+}
+
+/// Selects every row of the table, or clears the selection when every row is already selected
+pub fn select_all_event(app: &mut App) {
+    let Some((links, _)) = focused_track_table(app) else {
+        return;
+    };
+    let links = links.to_vec();
+    start_selection(app, links);
+
+    if app.track_selection_rows.len() == app.track_selection_links.len() {
+        app.track_selection_rows.clear();
+    } else {
+        app.track_selection_rows = (0..app.track_selection_links.len()).collect();
+    }
+}
+
+/// Returns the selected rows of the focused table in table order, empty when the selection was made in another table
+pub fn selected_rows(app: &App) -> Vec<usize> {
+    match focused_track_table(app) {
+        Some((links, _)) if links == app.track_selection_links.as_slice() => {
+            let mut rows = app.track_selection_rows.clone();
+            rows.sort_unstable();
+            rows
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Returns the links of the selected rows in table order
+pub fn selected_links(app: &App) -> Vec<String> {
+    selected_rows(app)
+        .into_iter()
+        .filter_map(|row| app.track_selection_links.get(row).cloned())
+        .collect()
+}
+
+pub fn clear_selection(app: &mut App) {
+    app.track_selection_links.clear();
+    app.track_selection_rows.clear();
+    app.track_selection_anchor = 0;
+}
+
+// Selecting in another table, or in a table whose rows changed, starts over
+fn start_selection(app: &mut App, links: Vec<String>) {
+    if app.track_selection_links != links {
+        app.track_selection_links = links;
+        app.track_selection_rows.clear();
+        app.track_selection_anchor = 0;
+    }
+}
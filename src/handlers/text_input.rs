@@ -0,0 +1,59 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::import::{import_file_submit, import_playlist_name_submit};
+use super::playlist_control::{
+    add_track_to_playlist::insert_position_submit,
+    edit_playlist::{create_playlist_submit, description_submit, rename_playlist_submit},
+    edit_tracks::move_playlist_track_to_submit,
+};
+use crate::{
+    app::App,
+    enums::{InputMode, TextInputAction},
+};
+
+/// Opens the text input dialog with `initial` already typed, `action` decides what happens on Enter
+pub fn open_text_input(app: &mut App, action: TextInputAction, title: &str, initial: &str) {
+    app.text_input = initial.to_string();
+    app.text_input_title = title.to_string();
+    app.text_input_action = action;
+    app.text_input_return_mode = app.input_mode;
+    app.input_mode = InputMode::TextInput;
+}
+
+pub fn text_input(app: &mut App, key_event: KeyEvent) {
+    match key_event.code {
+        // Shortcuts like Ctrl+S must not end up in the text
+        KeyCode::Char(c)
+            if !key_event
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+        {
+            app.text_input.push(c);
+        }
+        KeyCode::Backspace => {
+            app.text_input.pop();
+        }
+        KeyCode::Enter => {
+            app.input_mode = app.text_input_return_mode;
+            let text = std::mem::take(&mut app.text_input);
+            match app.text_input_action {
+                TextInputAction::CreatePlaylist => create_playlist_submit(app, text.trim()),
+                TextInputAction::RenamePlaylist => rename_playlist_submit(app, text.trim()),
+                TextInputAction::EditPlaylistDescription => description_submit(app, text.trim()),
+                TextInputAction::MovePlaylistTrack => {
+                    move_playlist_track_to_submit(app, text.trim())
+                }
+                TextInputAction::InsertPosition => insert_position_submit(app, text.trim()),
+                TextInputAction::ImportFile => import_file_submit(app, text.trim()),
+                TextInputAction::ImportPlaylistName => {
+                    import_playlist_name_submit(app, text.trim())
+                }
+            }
+        }
+        KeyCode::Esc => {
+            app.input_mode = app.text_input_return_mode;
+            app.text_input.clear();
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,79 @@
+use super::util::{down_key_for_list, up_key_for_list};
+use crate::{
+    app::App,
+    enums::Menu,
+    settings::theme::{list_themes, save_theme_to_settings, set_theme},
+    structs::{Settings, Themes},
+};
+
+pub fn go_to_theme_picker_event(app: &mut App, settings: &mut Settings) {
+    if app.selected_menu == Menu::ThemePicker {
+        return;
+    }
+
+    list_themes(app);
+    if app.theme_names.is_empty() {
+        app.error_text = "No theme files found in the configure folder".to_string();
+        app.selected_menu = Menu::Error;
+        return;
+    }
+
+    // Start the cursor on the theme that is currently in use
+    app.theme_before_preview = settings.theme_name.clone();
+    app.theme_picker_index = app
+        .theme_names
+        .iter()
+        .position(|name| *name == settings.theme_name)
+        .unwrap_or(0);
+    app.theme_picker_state.select(Some(app.theme_picker_index));
+    app.selected_menu = Menu::ThemePicker;
+}
+
+pub fn theme_picker_down_event(app: &mut App, theme: &mut Themes, settings: &mut Settings) {
+    if app.selected_menu == Menu::ThemePicker {
+        (app.theme_picker_state, app.theme_picker_index) =
+            down_key_for_list(app.theme_names.clone(), app.theme_picker_state.clone());
+        app.theme_picker_index %= app.theme_names.len();
+        preview_theme(app, theme, settings);
+    }
+}
+
+pub fn theme_picker_up_event(app: &mut App, theme: &mut Themes, settings: &mut Settings) {
+    if app.selected_menu == Menu::ThemePicker {
+        (app.theme_picker_state, app.theme_picker_index) =
+            up_key_for_list(app.theme_names.clone(), app.theme_picker_state.clone());
+        preview_theme(app, theme, settings);
+    }
+}
+
+pub fn theme_picker_enter_event(app: &mut App, settings: &mut Settings) {
+    if app.selected_menu == Menu::ThemePicker {
+        app.selected_menu = Menu::Default;
+        app.theme_before_preview = settings.theme_name.clone();
+        if let Err(e) = save_theme_to_settings(settings) {
+            app.error_text = format!("Error saving the theme to settings.yml: {}", e);
+            app.selected_menu = Menu::Error;
+        }
+    }
+}
+
+/// Closes the picker without saving and puts back the theme that was active before previewing
+pub fn theme_picker_cancel_event(app: &mut App, theme: &mut Themes, settings: &mut Settings) {
+    if app.selected_menu == Menu::ThemePicker {
+        revert_theme_preview(app, theme, settings);
+        app.selected_menu = Menu::Default;
+    }
+}
+
+/// Puts back the theme that was active before previewing, once Enter saved a theme there is nothing to revert
+pub fn revert_theme_preview(app: &mut App, theme: &mut Themes, settings: &mut Settings) {
+    if settings.theme_name != app.theme_before_preview {
+        settings.theme_name = app.theme_before_preview.clone();
+        set_theme(app, theme, settings);
+    }
+}
+
+fn preview_theme(app: &mut App, theme: &mut Themes, settings: &mut Settings) {
+    settings.theme_name = app.theme_names[app.theme_picker_index].clone();
+    set_theme(app, theme, settings);
+}
@@ -1,9 +1,11 @@
-use super::util::{default, down_key_for_table, up_key_for_table};
+use super::like::check_liked_tracks;
+use super::util::{default, down_key_for_table, play_track_in_context, up_key_for_table};
 use crate::{
     app::App,
     enums::Menu,
     spotify::{
-        player::start_playback::start_playback,
+        album_art::fetch_album_art::fetch_album_art,
+        player::util::context_uri,
         user_playlist::user_playlist_track::{fetch_playlists_tracks, process_playlist_tracks},
     },
 };
@@ -71,14 +73,24 @@ pub fn user_playlist_enter_event(app: &mut App) {
         if app.enter_for_playback_in_user_playlist {
             app.selected_link_for_playback =
                 app.user_playlist_track_links[app.user_playlist_index].clone();
-            if let Err(e) = start_playback(app) {
-                println!("{}", e);
-            }
+            play_track_in_context(
+                app,
+                context_uri("playlist", &app.selected_playlist_uri),
+                Vec::new(),
+            );
         } else {
             if let Err(e) = fetch_playlists_tracks(app) {
                 println!("{}", e);
             }
             process_playlist_tracks(app);
+            check_liked_tracks(app, &app.user_playlist_track_links.clone());
+            if let Some(image_url) = app
+                .user_playlist_state
+                .selected()
+                .and_then(|index| app.user_playlist_image_urls.get(index))
+            {
+                let _ = fetch_album_art(image_url);
+            }
             app.user_playlist_display = true;
             app.searched_album_selected = false;
             app.searched_artist_selected = false;
@@ -2,7 +2,8 @@ use ratatui::widgets::{ListState, TableState};
 
 use crate::{
     app::App,
-    enums::{InputMode, SearchMenu},
+    enums::{InputMode, Library, Menu, SearchMenu},
+    spotify::player::start_playback::start_playback_in_context,
 };
 
 // Helper functions for cursor movement and character deletion
@@ -112,6 +113,14 @@ pub fn default(app: &mut App) {
     app.enter_for_playback_in_saved_artist = false;
     app.enter_for_playback_in_user_playlist = false;
     app.enter_for_playback_in_new_release = false;
+    app.podcast_current_show_selected = false;
+    app.podcast_episode_display = false;
+    app.podcast_episode_selected = false;
+    app.enter_for_playback_in_podcast = false;
+    app.enter_for_playback_in_saved_episodes = false;
+    app.audiobook_display = false;
+    app.audiobook_current_selected = false;
+    app.enter_for_playback_in_audiobook = false;
     app.is_only_id = false;
     app.selected_link_for_playback.clear();
     app.is_in_track = false;
@@ -141,6 +150,14 @@ pub fn default_nav(app: &mut App) {
     app.enter_for_playback_in_saved_artist = false;
     app.enter_for_playback_in_user_playlist = false;
     app.enter_for_playback_in_new_release = false;
+    app.podcast_current_show_selected = false;
+    app.podcast_episode_display = false;
+    app.podcast_episode_selected = false;
+    app.enter_for_playback_in_podcast = false;
+    app.enter_for_playback_in_saved_episodes = false;
+    app.audiobook_display = false;
+    app.audiobook_current_selected = false;
+    app.enter_for_playback_in_audiobook = false;
     app.is_only_id = false;
     app.selected_link_for_playback.clear();
     app.is_in_track = false;
@@ -164,3 +181,152 @@ pub fn default_search(app: &mut App) {
     app.is_in_track = false;
     app.search_menu = SearchMenu::Default;
 }
+
+/// Returns the link of the track selected in the current menu, if a track table has focus
+pub fn selected_track_link(app: &App) -> Option<String> {
+    if app.selected_menu == Menu::Library {
+        if app.selected_library == Library::RecentlyPlayed {
+            return app
+                .recently_played_links
+                .get(app.recently_played_index)
+                .cloned();
+        } else if app.selected_library == Library::LikedSongs {
+            return app.liked_song_links.get(app.liked_songs_index).cloned();
+        } else if app.selected_library == Library::MadeFY {
+            if app.made_fy_track_selected {
+                return app
+                    .made_fy_track_links
+                    .get(app.made_fy_track_index)
+                    .cloned();
+            }
+        } else if app.selected_library == Library::Albums {
+            if app.user_album_track_selected {
+                return app
+                    .user_album_track_links
+                    .get(app.user_album_track_index)
+                    .cloned();
+            }
+        } else if matches!(
+            app.selected_library,
+            Library::Podcasts | Library::Episodes | Library::Audiobooks
+        ) {
+            if app.podcast_episode_selected {
+                return app
+                    .podcast_episode_links
+                    .get(app.podcast_episode_index)
+                    .cloned();
+            }
+        } else if app.selected_library == Library::Artists && app.user_artist_track_selected {
+            return app
+                .user_artist_track_links
+                .get(app.user_artist_track_index)
+                .cloned();
+        }
+    } else if app.selected_menu == Menu::NewRelease {
+        if app.enter_for_playback_in_new_release {
+            return app
+                .new_release_spotify_urls
+                .get(app.new_release_index)
+                .cloned();
+        }
+    } else if app.selected_menu == Menu::Playlists {
+        if app.enter_for_playback_in_user_playlist {
+            return app
+                .user_playlist_track_links
+                .get(app.user_playlist_index)
+                .cloned();
+        }
+    } else if app.selected_menu == Menu::Search {
+        if app.is_in_track {
+            return app.track_links_search_results.get(app.track_index).cloned();
+        } else if app.search_menu == SearchMenu::SearchedAlbum {
+            return app
+                .selected_album_tracks_links
+                .get(app.searched_album_index)
+                .cloned();
+        } else if app.search_menu == SearchMenu::SearchedArtist {
+            return app
+                .selected_artist_tracks_links
+                .get(app.searched_artist_index)
+                .cloned();
+        } else if app.search_menu == SearchMenu::SearchedPlaylist {
+            return app
+                .selected_playlist_tracks_links
+                .get(app.searched_playlist_index)
+                .cloned();
+        } else if app.search_menu == SearchMenu::SearchedShow {
+            return app
+                .podcast_episode_links
+                .get(app.podcast_episode_index)
+                .cloned();
+        }
+    }
+
+    None
+}
+
+/// Returns the links of the focused track table and the selected row, used for selecting several rows
+pub fn focused_track_table(app: &App) -> Option<(&[String], usize)> {
+    if app.selected_menu == Menu::Library {
+        if app.selected_library == Library::RecentlyPlayed && app.recently_played_display {
+            return Some((&app.recently_played_links, app.recently_played_index));
+        } else if app.selected_library == Library::LikedSongs && app.liked_song_display {
+            return Some((&app.liked_song_links, app.liked_songs_index));
+        } else if app.selected_library == Library::MadeFY && app.made_fy_track_selected {
+            return Some((&app.made_fy_track_links, app.made_fy_track_index));
+        } else if app.selected_library == Library::Albums && app.user_album_track_selected {
+            return Some((&app.user_album_track_links, app.user_album_track_index));
+        } else if app.selected_library == Library::Artists && app.user_artist_track_selected {
+            return Some((&app.user_artist_track_links, app.user_artist_track_index));
+        }
+    } else if app.selected_menu == Menu::NewRelease {
+        if app.enter_for_playback_in_new_release {
+            return Some((&app.new_release_spotify_urls, app.new_release_index));
+        }
+    } else if app.selected_menu == Menu::Playlists {
+        if app.enter_for_playback_in_user_playlist {
+            return Some((&app.user_playlist_track_links, app.user_playlist_index));
+        }
+    } else if app.selected_menu == Menu::Search && !app.is_in_track {
+        if app.search_menu == SearchMenu::SearchedAlbum {
+            return Some((&app.selected_album_tracks_links, app.searched_album_index));
+        } else if app.search_menu == SearchMenu::SearchedArtist {
+            return Some((&app.selected_artist_tracks_links, app.searched_artist_index));
+        } else if app.search_menu == SearchMenu::SearchedPlaylist {
+            return Some((
+                &app.selected_playlist_tracks_links,
+                app.searched_playlist_index,
+            ));
+        }
+    }
+
+    None
+}
+
+/// Tracks sent when a list without a context is played, Spotify rejects requests with too many uris
+const PLAYBACK_WINDOW_SIZE: usize = 50;
+
+/// The selected track and the ones after it, at most `PLAYBACK_WINDOW_SIZE` of them
+pub fn playback_window(track_links: &[String], selected_index: usize) -> Vec<String> {
+    track_links
+        .iter()
+        .skip(selected_index)
+        .take(PLAYBACK_WINDOW_SIZE)
+        .cloned()
+        .collect()
+}
+
+/// The uri that plays Liked Songs as a context, `None` until the user id is known
+pub fn liked_songs_context_uri(app: &App) -> Option<String> {
+    (!app.user_id.is_empty()).then(|| format!("spotify:user:{}:collection", app.user_id))
+}
+
+/// Plays the selected track inside `context_uri`, or inside `track_links` when the list has no context uri
+pub fn play_track_in_context(app: &mut App, context_uri: String, track_links: Vec<String>) {
+    app.playback_context_uri = context_uri;
+    app.playback_context_track_links = track_links;
+    if let Err(e) = start_playback_in_context(app) {
+        println!("{}", e);
+    }
+    app.request_player_refresh();
+}
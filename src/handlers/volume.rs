@@ -0,0 +1,68 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::{
+    app::App,
+    enums::{InputMode, Menu},
+    spotify::player::set_volume::{set_device_volume, set_volume},
+    structs::Settings,
+};
+
+/// Mutes the current device, or brings back the level it had before muting
+pub fn mute_event(app: &mut App, settings: &mut Settings) {
+    match app.volume_before_mute.take() {
+        Some(volume) if settings.volume_percent == 0 => {
+            if let Err(e) = set_volume(app, settings, volume) {
+                println!("{}", e);
+            }
+        }
+        _ if settings.volume_percent > 0 => {
+            let volume = settings.volume_percent;
+            match set_volume(app, settings, 0) {
+                Ok(_) => app.volume_before_mute = Some(volume),
+                Err(e) => println!("{}", e),
+            }
+        }
+        _ => {}
+    }
+    app.request_player_refresh();
+}
+
+/// Starts typing a volume level between 0 and 100
+pub fn volume_input_event(app: &mut App) {
+    app.volume_input.clear();
+    app.volume_return_mode = app.input_mode;
+    app.input_mode = InputMode::VolumeInput;
+}
+
+pub fn volume_input(app: &mut App, key_event: KeyEvent, settings: &mut Settings) {
+    match key_event.code {
+        KeyCode::Char(c) if c.is_ascii_digit() && app.volume_input.len() < 3 => {
+            app.volume_input.push(c);
+        }
+        KeyCode::Backspace => {
+            app.volume_input.pop();
+        }
+        KeyCode::Enter => {
+            app.input_mode = app.volume_return_mode;
+            match app.volume_input.parse::<u8>() {
+                Ok(volume) if volume <= 100 => {
+                    if let Err(e) = set_device_volume(app, settings, volume) {
+                        println!("{}", e);
+                    }
+                    app.request_player_refresh();
+                }
+                _ => {
+                    app.error_text = format!(
+                        "\"{}\" is not a volume, use a number from 0 to 100",
+                        app.volume_input
+                    );
+                    app.selected_menu = Menu::Error;
+                }
+            }
+        }
+        KeyCode::Esc => {
+            app.input_mode = app.volume_return_mode;
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,227 @@
+use std::collections::HashSet;
+
+use rspotify::model::FullTrack;
+use rspotify::prelude::Id;
+
+use super::parse::ImportEntry;
+
+/// Matches at or above this confidence are accepted without asking
+pub const ACCEPT_CONFIDENCE: u8 = 70;
+
+/// An import entry with the Spotify track it resolved to, `track_uri` is empty when nothing matched
+#[derive(Clone, Debug)]
+pub struct ImportMatch {
+    pub entry: ImportEntry,
+    pub track_uri: String,
+    pub track_name: String,
+    pub confidence: u8,
+    pub accepted: bool,
+}
+
+impl ImportMatch {
+    pub fn new(entry: ImportEntry, track: Option<(&FullTrack, u8)>) -> Self {
+        match track {
+            Some((track, confidence)) => {
+                let artists: Vec<&str> = track
+                    .artists
+                    .iter()
+                    .map(|artist| artist.name.as_str())
+                    .collect();
+                ImportMatch {
+                    entry,
+                    track_uri: track.id.as_ref().map(|id| id.uri()).unwrap_or_default(),
+                    track_name: format!("{} - {}", artists.join(", "), track.name),
+                    confidence,
+                    accepted: confidence >= ACCEPT_CONFIDENCE,
+                }
+            }
+            None => ImportMatch {
+                entry,
+                track_uri: String::new(),
+                track_name: String::new(),
+                confidence: 0,
+                accepted: false,
+            },
+        }
+    }
+}
+
+/// Lowercases a title and drops what differs between releases of the same song, like "(Remastered 2011)" or "- Live"
+pub fn normalize_title(title: &str) -> String {
+    let mut title = title.to_lowercase();
+    for separator in [" - ", " feat.", " ft.", " featuring "] {
+        if let Some(index) = title.find(separator) {
+            title.truncate(index);
+        }
+    }
+    let mut depth = 0;
+    let title: String = title
+        .chars()
+        .filter_map(|c| match c {
+            '(' | '[' => {
+                depth += 1;
+                None
+            }
+            ')' | ']' => {
+                depth -= 1;
+                None
+            }
+            _ if depth > 0 => None,
+            c if c.is_alphanumeric() => Some(c),
+            _ => Some(' '),
+        })
+        .collect();
+
+    title.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Lowercases artist names and keeps only their words
+pub fn normalize_artist(artist: &str) -> String {
+    let artist: String = artist
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+
+    artist.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// How sure we are that `track` is the song in `entry`, from 0 to 100
+pub fn match_confidence(entry: &ImportEntry, track: &FullTrack) -> u8 {
+    if !entry.isrc.is_empty()
+        && track
+            .external_ids
+            .get("isrc")
+            .is_some_and(|isrc| isrc.eq_ignore_ascii_case(&entry.isrc))
+    {
+        return 100;
+    }
+
+    let title = word_similarity(
+        &normalize_title(&entry.title),
+        &normalize_title(&track.name),
+    );
+    let artist = if entry.artist.is_empty() {
+        // Without an artist the title has to carry the match
+        0.5
+    } else {
+        let artists: Vec<&str> = track
+            .artists
+            .iter()
+            .map(|artist| artist.name.as_str())
+            .collect();
+        word_similarity(
+            &normalize_artist(&entry.artist),
+            &normalize_artist(&artists.join(" ")),
+        )
+        .max(word_similarity(
+            &normalize_artist(&entry.artist),
+            &normalize_artist(artists.first().copied().unwrap_or_default()),
+        ))
+    };
+
+    let mut score = title * 0.6 + artist * 0.4;
+    if let Some(duration_ms) = entry.duration_ms {
+        if (duration_ms - track.duration.num_milliseconds()).abs() > 10_000 {
+            score -= 0.2;
+        }
+    }
+
+    (score.clamp(0.0, 1.0) * 100.0).round() as u8
+}
+
+// Share of words the two texts have in common
+fn word_similarity(a: &str, b: &str) -> f64 {
+    let a: HashSet<&str> = a.split_whitespace().collect();
+    let b: HashSet<&str> = b.split_whitespace().collect();
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    a.intersection(&b).count() as f64 / a.union(&b).count() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn track(name: &str, artists: &[&str], duration_ms: i64, isrc: &str) -> FullTrack {
+        let artists: Vec<serde_json::Value> = artists
+            .iter()
+            .map(|artist| {
+                serde_json::json!({ "external_urls": {}, "href": null, "id": null, "name": artist })
+            })
+            .collect();
+        serde_json::from_value(serde_json::json!({
+            "album": {
+                "album_type": null,
+                "artists": [],
+                "external_urls": {},
+                "href": null,
+                "id": null,
+                "images": [],
+                "name": "Album",
+            },
+            "artists": artists,
+            "disc_number": 1,
+            "duration_ms": duration_ms,
+            "explicit": false,
+            "external_ids": { "isrc": isrc },
+            "external_urls": {},
+            "href": null,
+            "id": null,
+            "is_local": false,
+            "name": name,
+            "popularity": 0,
+            "preview_url": null,
+            "track_number": 1,
+        }))
+        .unwrap()
+    }
+
+    fn entry(artist: &str, title: &str) -> ImportEntry {
+        ImportEntry {
+            artist: artist.to_string(),
+            title: title.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn normalize_title_drops_versions_and_features() {
+        assert_eq!(normalize_title("Help! (Remastered 2009)"), "help");
+        assert_eq!(normalize_title("Song - Live at Wembley"), "song");
+        assert_eq!(normalize_title("Song feat. Someone"), "song");
+        assert_eq!(normalize_title("Rock & Roll [Mono]"), "rock roll");
+    }
+
+    #[test]
+    fn matching_isrc_is_certain() {
+        let mut entry = entry("Someone Else", "Other");
+        entry.isrc = "usrc17607839".to_string();
+
+        let track = track("Song", &["Artist"], 200_000, "USRC17607839");
+        assert_eq!(match_confidence(&entry, &track), 100);
+    }
+
+    #[test]
+    fn same_song_is_accepted_and_other_songs_are_not() {
+        let track = track("Song (Remastered)", &["Artist", "Guest"], 200_000, "");
+
+        assert_eq!(match_confidence(&entry("Artist", "Song"), &track), 100);
+        assert!(match_confidence(&entry("", "Song"), &track) >= ACCEPT_CONFIDENCE);
+        assert!(match_confidence(&entry("Artist", "Different Words"), &track) < ACCEPT_CONFIDENCE);
+    }
+
+    #[test]
+    fn far_off_duration_lowers_the_confidence() {
+        let track = track("Song", &["Artist"], 200_000, "");
+        let mut close = entry("Artist", "Song");
+        close.duration_ms = Some(205_000);
+        let mut far = entry("Artist", "Song");
+        far.duration_ms = Some(400_000);
+
+        assert_eq!(match_confidence(&close, &track), 100);
+        assert_eq!(match_confidence(&far, &track), 80);
+    }
+}
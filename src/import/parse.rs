@@ -0,0 +1,330 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// One track read from an import file
+#[derive(Clone, Debug, Default)]
+pub struct ImportEntry {
+    /// The entry as written in the file, shown on the review screen
+    pub source: String,
+    pub artist: String,
+    pub title: String,
+    pub isrc: String,
+    pub uri: String,
+    pub duration_ms: Option<i64>,
+}
+
+/// Reads the tracks of an M3U, CSV or "artist - title" text file, picked by the file extension
+pub fn read_import_file(path: &Path) -> io::Result<Vec<ImportEntry>> {
+    let contents = fs::read_to_string(path)?;
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+
+    Ok(match extension.as_str() {
+        "m3u" | "m3u8" => parse_m3u(&contents),
+        "csv" => parse_csv(&contents),
+        _ => parse_lines(&contents),
+    })
+}
+
+fn parse_lines(contents: &str) -> Vec<ImportEntry> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(entry_from_artist_title)
+        .collect()
+}
+
+// "#EXTINF:<seconds>,<artist> - <title>" describes the location on the next line
+fn parse_m3u(contents: &str) -> Vec<ImportEntry> {
+    let mut entries = Vec::new();
+    let mut pending: Option<ImportEntry> = None;
+
+    for line in contents.lines().map(str::trim) {
+        if let Some(info) = line.strip_prefix("#EXTINF:") {
+            let (seconds, name) = info.split_once(',').unwrap_or(("", info));
+            let mut entry = entry_from_artist_title(name.trim());
+            entry.duration_ms = seconds
+                .trim()
+                .parse::<i64>()
+                .ok()
+                .filter(|seconds| *seconds > 0)
+                .map(|seconds| seconds * 1000);
+            entries.extend(pending.replace(entry));
+        } else if !line.is_empty() && !line.starts_with('#') {
+            let mut entry = pending.take().unwrap_or_else(|| {
+                let stem = Path::new(line)
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .unwrap_or(line);
+                entry_from_artist_title(stem)
+            });
+            if line.starts_with("spotify:track:") || line.contains("open.spotify.com/track/") {
+                entry.uri = line.to_string();
+            }
+            entries.push(entry);
+        }
+    }
+    entries.extend(pending);
+
+    entries
+}
+
+// Columns are found by their header, files without a known header are read as artist,title
+fn parse_csv(contents: &str) -> Vec<ImportEntry> {
+    let mut rows = split_csv_records(contents).into_iter();
+    let Some((header_line, header)) = rows.next() else {
+        return Vec::new();
+    };
+
+    let column = |names: &[&str]| {
+        header
+            .iter()
+            .position(|field| names.contains(&field.trim().to_lowercase().as_str()))
+    };
+    let title_column = column(&["title", "name", "track", "track name"]);
+    let artist_column = column(&["artist", "artists", "artist name", "artist name(s)"]);
+    let isrc_column = column(&["isrc"]);
+    let uri_column = column(&["uri", "spotify uri", "track uri"]);
+    // The header tells the unit, plain durations are in seconds
+    let (duration_column, duration_unit_ms) = match column(&["duration_ms", "duration (ms)"]) {
+        Some(column) => (Some(column), 1),
+        None => (column(&["duration", "duration (s)", "length"]), 1000),
+    };
+
+    let (title_column, artist_column, mut entries) = match (title_column, artist_column) {
+        (None, None) => (
+            Some(1),
+            Some(0),
+            vec![entry_from_csv_row(header_line, &header, Some(1), Some(0))],
+        ),
+        columns => (columns.0, columns.1, Vec::new()),
+    };
+
+    for (line, fields) in rows {
+        let mut entry = entry_from_csv_row(line, &fields, title_column, artist_column);
+        let field = |column: Option<usize>| {
+            column
+                .and_then(|column| fields.get(column))
+                .map(|field| field.trim().to_string())
+                .unwrap_or_default()
+        };
+        entry.isrc = field(isrc_column);
+        entry.uri = field(uri_column);
+        entry.duration_ms = parse_duration(&field(duration_column), duration_unit_ms);
+        entries.push(entry);
+    }
+
+    entries
+}
+
+fn entry_from_csv_row(
+    line: &str,
+    fields: &[String],
+    title_column: Option<usize>,
+    artist_column: Option<usize>,
+) -> ImportEntry {
+    let field = |column: Option<usize>| {
+        column
+            .and_then(|column| fields.get(column))
+            .map(|field| field.trim().to_string())
+            .unwrap_or_default()
+    };
+    ImportEntry {
+        // Quoted fields may span lines, the review shows the record on one
+        source: line.replace(['\r', '\n'], " "),
+        // Exports list several artists separated by semicolons
+        artist: field(artist_column).replace(';', ","),
+        title: field(title_column),
+        ..Default::default()
+    }
+}
+
+fn entry_from_artist_title(text: &str) -> ImportEntry {
+    let (artist, title) = text.split_once(" - ").unwrap_or(("", text));
+    ImportEntry {
+        source: text.to_string(),
+        artist: artist.trim().to_string(),
+        title: title.trim().to_string(),
+        ..Default::default()
+    }
+}
+
+// Accepts m:ss, or a number of `unit_ms` milliseconds
+fn parse_duration(text: &str, unit_ms: i64) -> Option<i64> {
+    if let Some((minutes, seconds)) = text.split_once(':') {
+        let minutes = minutes.trim().parse::<i64>().ok()?;
+        let seconds = seconds.trim().parse::<i64>().ok()?;
+        return Some((minutes * 60 + seconds) * 1000);
+    }
+    text.parse::<i64>()
+        .ok()
+        .filter(|value| *value > 0)
+        .map(|value| value * unit_ms)
+}
+
+/// Splits CSV text into records and their fields, returning each record as written.
+/// Quoted fields may hold commas, doubled quotes and line breaks, blank lines are skipped.
+fn split_csv_records(contents: &str) -> Vec<(&str, Vec<String>)> {
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut start = 0;
+    let mut chars = contents.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek().map(|(_, next)| *next) == Some('"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            '\n' if !in_quotes => {
+                fields.push(std::mem::take(&mut field));
+                records.push((&contents[start..index], std::mem::take(&mut fields)));
+                start = index + 1;
+            }
+            '\r' if !in_quotes => {}
+            c => field.push(c),
+        }
+    }
+    if start < contents.len() {
+        fields.push(field);
+        records.push((&contents[start..], fields));
+    }
+
+    records
+        .into_iter()
+        .map(|(line, fields)| (line.trim_end_matches('\r'), fields))
+        .filter(|(line, _)| !line.trim().is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::ExportFormat;
+    use crate::export::formats::{write_export, ExportTrack};
+
+    #[test]
+    fn csv_records_keep_quoted_commas_quotes_and_line_breaks() {
+        let records = split_csv_records("a,\"b, c\",\"say \"\"hi\"\"\"\r\n\n\"two\nlines\",d");
+
+        assert_eq!(
+            records,
+            vec![
+                (
+                    "a,\"b, c\",\"say \"\"hi\"\"\"",
+                    vec![
+                        "a".to_string(),
+                        "b, c".to_string(),
+                        "say \"hi\"".to_string()
+                    ]
+                ),
+                (
+                    "\"two\nlines\",d",
+                    vec!["two\nlines".to_string(), "d".to_string()]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn csv_export_reads_back_the_same_tracks() {
+        let folder = std::env::temp_dir().join(format!("spoify-import-{}", std::process::id()));
+        let tracks = [ExportTrack {
+            title: "Hello, \"Goodbye\"\nReprise".to_string(),
+            artists: vec!["A".to_string(), "B".to_string()],
+            album: "Album".to_string(),
+            duration_ms: 215_400,
+            isrc: "USRC17607839".to_string(),
+            uri: "spotify:track:4uLU6hMCjMI75M1A2tKUQC".to_string(),
+        }];
+        let path = write_export(&folder, "round trip", &tracks, ExportFormat::Csv).unwrap();
+        let entries = read_import_file(&path).unwrap();
+        std::fs::remove_dir_all(&folder).unwrap();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].title, "Hello, \"Goodbye\"\nReprise");
+        assert_eq!(entries[0].artist, "A, B");
+        assert_eq!(entries[0].isrc, "USRC17607839");
+        assert_eq!(entries[0].uri, "spotify:track:4uLU6hMCjMI75M1A2tKUQC");
+        assert_eq!(entries[0].duration_ms, Some(215_400));
+    }
+
+    #[test]
+    fn csv_duration_unit_comes_from_the_header() {
+        let in_ms = parse_csv("title,duration (ms)\nSong,5000");
+        let in_seconds = parse_csv("title,duration\nSong,215\nOther,3:35");
+
+        assert_eq!(in_ms[0].duration_ms, Some(5_000));
+        assert_eq!(in_seconds[0].duration_ms, Some(215_000));
+        assert_eq!(in_seconds[1].duration_ms, Some(215_000));
+    }
+
+    #[test]
+    fn csv_without_a_known_header_is_artist_then_title() {
+        let entries = parse_csv("Artist One,First\nArtist Two,Second");
+
+        let pairs: Vec<(&str, &str)> = entries
+            .iter()
+            .map(|entry| (entry.artist.as_str(), entry.title.as_str()))
+            .collect();
+        assert_eq!(
+            pairs,
+            vec![("Artist One", "First"), ("Artist Two", "Second")]
+        );
+    }
+
+    #[test]
+    fn m3u_extinf_with_and_without_a_location() {
+        let entries = parse_m3u(
+            "#EXTM3U\n\
+             #EXTINF:215,Artist - With Uri\n\
+             spotify:track:4uLU6hMCjMI75M1A2tKUQC\n\
+             #EXTINF:-1,Artist - No Location\n\
+             #EXTINF:100,Artist - Last\n",
+        );
+
+        let summary: Vec<(&str, &str, Option<i64>, &str)> = entries
+            .iter()
+            .map(|entry| {
+                (
+                    entry.artist.as_str(),
+                    entry.title.as_str(),
+                    entry.duration_ms,
+                    entry.uri.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "Artist",
+                    "With Uri",
+                    Some(215_000),
+                    "spotify:track:4uLU6hMCjMI75M1A2tKUQC"
+                ),
+                ("Artist", "No Location", None, ""),
+                ("Artist", "Last", Some(100_000), ""),
+            ]
+        );
+    }
+
+    #[test]
+    fn m3u_location_without_extinf_uses_the_file_name() {
+        let entries = parse_m3u("/music/Artist - Song.mp3\n");
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].artist, "Artist");
+        assert_eq!(entries[0].title, "Song");
+        assert_eq!(entries[0].uri, "");
+    }
+}
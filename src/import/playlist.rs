@@ -0,0 +1,50 @@
+use std::io;
+
+use rspotify::ClientError;
+
+use super::matching::ImportMatch;
+use crate::{
+    app::App,
+    enums::InsertPosition,
+    spotify::playlist_control::{
+        add_track_to_playlist::add_track_to_playlist, playlist_details::create_playlist,
+    },
+};
+
+/// Creates a playlist called `name` from the accepted matches, returns how many tracks went in
+///
+/// When the tracks cannot be added the playlist stays in `app.import_playlist_link`,
+/// so trying again fills it instead of creating another one.
+pub fn create_imported_playlist(
+    app: &mut App,
+    name: &str,
+    matches: &[ImportMatch],
+) -> Result<usize, ClientError> {
+    let track_links: Vec<String> = matches
+        .iter()
+        .filter(|import_match| import_match.accepted && !import_match.track_uri.is_empty())
+        .map(|import_match| import_match.track_uri.clone())
+        .collect();
+
+    if app.import_playlist_link.is_empty() {
+        let playlist_link = create_playlist(app, name)?;
+        if playlist_link.is_empty() {
+            return Err(ClientError::Io(io::Error::other(
+                "The playlist could not be created",
+            )));
+        }
+        app.import_playlist_link = playlist_link;
+    }
+    if track_links.is_empty() {
+        app.import_playlist_link.clear();
+        return Ok(0);
+    }
+
+    app.playlist_link_for_track_addition = app.import_playlist_link.clone();
+    app.track_added_to_playlist_links = track_links;
+    app.playlist_insert_position = InsertPosition::End;
+    add_track_to_playlist(app)?;
+    app.import_playlist_link.clear();
+
+    Ok(app.track_added_to_playlist_links.len())
+}
@@ -0,0 +1,210 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::app::App;
+use crate::structs::{LyricLine, Settings};
+
+/// Returns the folder lyrics files are read from, `spoify/lyrics` unless set in settings.yml
+pub fn lyrics_folder(settings: &Settings) -> PathBuf {
+    if !settings.lyrics_folder.is_empty() {
+        return PathBuf::from(&settings.lyrics_folder);
+    }
+
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push(".."); // Move up to the root of the Git repository
+    path.push("spoify");
+    path.push("lyrics");
+    path
+}
+
+/// Loads the lyrics of the current track when the track changed since the last lookup
+pub fn load_lyrics(app: &mut App, settings: &Settings) {
+    let lookup_name = format!(
+        "{} - {}",
+        app.currently_playing_artist, app.current_playing_name
+    );
+    if lookup_name == app.lyrics_loaded_for {
+        return;
+    }
+
+    app.lyrics_loaded_for = lookup_name.clone();
+    app.lyrics_lines.clear();
+    app.lyrics_synced = false;
+
+    if app.current_playing_name.is_empty() {
+        return;
+    }
+
+    if let Some(path) = find_lyrics_file(&lyrics_folder(settings), &lookup_name) {
+        if let Ok(contents) = fs::read_to_string(path) {
+            (app.lyrics_lines, app.lyrics_synced) = parse_lrc(&contents);
+        }
+    }
+}
+
+/// Looks for `<artist> - <title>.lrc` (or `.txt`) ignoring case, since file names rarely match the exact capitalisation
+fn find_lyrics_file(folder: &Path, lookup_name: &str) -> Option<PathBuf> {
+    // Characters that cannot be part of a file name are replaced the way most lyrics downloaders do
+    let file_stem: String = lookup_name
+        .chars()
+        .map(|c| if "/\\:*?\"<>|".contains(c) { '_' } else { c })
+        .collect::<String>()
+        .to_lowercase();
+
+    let mut plain_text = None;
+    for entry in fs::read_dir(folder).ok()?.flatten() {
+        let path = entry.path();
+        let stem = match path.file_stem().and_then(|stem| stem.to_str()) {
+            Some(stem) => stem.to_lowercase(),
+            None => continue,
+        };
+        if stem != file_stem {
+            continue;
+        }
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("lrc") => return Some(path),
+            Some(ext) if ext.eq_ignore_ascii_case("txt") => plain_text = Some(path),
+            _ => {}
+        }
+    }
+
+    plain_text
+}
+
+/// Parses LRC contents into lines sorted by time, returning whether any line carried a timestamp
+///
+/// Lines may have several timestamps (`[00:12.00][01:40.00]chorus`), and the `[offset:ms]` tag shifts every line.
+/// Files without timestamps are kept as plain text in their original order.
+pub fn parse_lrc(contents: &str) -> (Vec<LyricLine>, bool) {
+    let mut offset_ms = 0.0;
+    let mut timed_lines = Vec::new();
+    let mut plain_lines = Vec::new();
+
+    for raw_line in contents.lines() {
+        let mut rest = raw_line.trim();
+        let mut timestamps = Vec::new();
+
+        while let Some(tag_end) = rest.strip_prefix('[').and_then(|tag| tag.find(']')) {
+            let tag = &rest[1..tag_end + 1];
+            rest = &rest[tag_end + 2..];
+
+            if let Some(timestamp) = parse_timestamp(tag) {
+                timestamps.push(timestamp);
+            } else if let Some(value) = tag.strip_prefix("offset:") {
+                offset_ms = value.trim().parse::<f64>().unwrap_or(0.0);
+            }
+        }
+
+        let text = rest.trim().to_string();
+        if timestamps.is_empty() {
+            // Metadata tags like [ar:...] leave nothing behind and are skipped
+            if !(raw_line.trim_start().starts_with('[') && text.is_empty()) {
+                plain_lines.push(LyricLine {
+                    timestamp_ms: None,
+                    text,
+                });
+            }
+        } else {
+            for timestamp in timestamps {
+                timed_lines.push((timestamp, text.clone()));
+            }
+        }
+    }
+
+    if timed_lines.is_empty() {
+        return (plain_lines, false);
+    }
+
+    // A positive offset makes the lyrics appear sooner
+    timed_lines.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let lines = timed_lines
+        .into_iter()
+        .map(|(timestamp, text)| LyricLine {
+            timestamp_ms: Some((timestamp - offset_ms).max(0.0)),
+            text,
+        })
+        .collect();
+
+    (lines, true)
+}
+
+/// Parses `mm:ss`, `mm:ss.xx` or `mm:ss:xx` into milliseconds
+fn parse_timestamp(tag: &str) -> Option<f64> {
+    let (minutes, seconds) = tag.split_once(':')?;
+    let minutes = minutes.trim().parse::<u32>().ok()?;
+    let seconds = seconds.trim().replacen(':', ".", 1).parse::<f64>().ok()?;
+
+    Some((minutes as f64 * 60.0 + seconds) * 1000.0)
+}
+
+/// Returns the index of the line being sung at `position_ms`
+pub fn current_lyric_index(lines: &[LyricLine], position_ms: f64) -> Option<usize> {
+    lines
+        .iter()
+        .rposition(|line| line.timestamp_ms.is_some_and(|time| time <= position_ms))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn times_and_texts(lines: &[LyricLine]) -> Vec<(Option<f64>, &str)> {
+        lines
+            .iter()
+            .map(|line| (line.timestamp_ms, line.text.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn line_with_several_timestamps_is_repeated_in_time_order() {
+        let (lines, synced) = parse_lrc("[00:12.00][01:40.50]Chorus\n[00:30.00]Verse");
+
+        assert!(synced);
+        assert_eq!(
+            times_and_texts(&lines),
+            vec![
+                (Some(12_000.0), "Chorus"),
+                (Some(30_000.0), "Verse"),
+                (Some(100_500.0), "Chorus"),
+            ]
+        );
+    }
+
+    #[test]
+    fn bad_timestamps_and_metadata_are_not_timed() {
+        let (lines, synced) = parse_lrc("[ar:Someone]\n[xx:yy]Broken\n[00:05.00]Fine");
+
+        assert!(synced);
+        assert_eq!(times_and_texts(&lines), vec![(Some(5_000.0), "Fine")]);
+    }
+
+    #[test]
+    fn offset_moves_lines_earlier_without_going_negative() {
+        let (lines, _) = parse_lrc("[offset:500]\n[00:00.20]Start\n[00:02.00]Next");
+
+        assert_eq!(
+            times_and_texts(&lines),
+            vec![(Some(0.0), "Start"), (Some(1_500.0), "Next")]
+        );
+    }
+
+    #[test]
+    fn plain_text_keeps_its_order() {
+        let (lines, synced) = parse_lrc("First\n\nSecond");
+
+        assert!(!synced);
+        assert_eq!(
+            times_and_texts(&lines),
+            vec![(None, "First"), (None, ""), (None, "Second")]
+        );
+    }
+
+    #[test]
+    fn timestamp_formats() {
+        assert_eq!(parse_timestamp("01:02"), Some(62_000.0));
+        assert_eq!(parse_timestamp("01:02.50"), Some(62_500.0));
+        assert_eq!(parse_timestamp("01:02:50"), Some(62_500.0));
+        assert_eq!(parse_timestamp("1:2:3:4"), None);
+        assert_eq!(parse_timestamp("ar:Someone"), None);
+    }
+}
@@ -2,6 +2,7 @@ use std::io;
 use std::sync::mpsc;
 use std::thread;
 
+use cli::{parse_command, run_command};
 use settings::creds::{read_creds, set_creds};
 use structs::{Key, Settings, Themes};
 use ui::tui;
@@ -10,9 +11,15 @@ use util::{instruction, save_creds_to_yml, startup, update_player_info};
 use crate::app::App;
 
 mod app;
+mod cli;
+mod duplicates;
 mod enums;
+mod export;
 mod handlers;
+mod import;
+mod lyrics;
 mod settings;
+mod snapshot;
 mod spotify;
 mod structs;
 mod ui;
@@ -25,6 +32,7 @@ fn main() -> io::Result<()> {
     let mut settings: Settings = Settings::default();
 
     app.file_name = "spoify-0.2.12".to_string(); //-0.2.12
+    let args: Vec<String> = std::env::args().skip(1).collect();
 
     // Set the creds from the configure files
     read_creds(&mut app);
@@ -33,6 +41,11 @@ fn main() -> io::Result<()> {
     if app.client_id == "" {
         instruction();
         save_creds_to_yml(&mut app);
+    } else if let Some(command) = parse_command(&args) {
+        match command {
+            Ok(command) => run_command(&mut app, command)?,
+            Err(usage) => println!("{}", usage),
+        }
     } else {
         // Fetch user's playlists, new releases, set keybinds and themes before the main app starts
         startup(&mut app, &mut key, &mut theme, &mut settings);
@@ -40,17 +53,33 @@ fn main() -> io::Result<()> {
         let mut terminal = tui::init()?;
 
         let (tx1, rx1) = mpsc::channel();
+        let (liked_tx, liked_rx) = mpsc::channel();
+        let (wake_tx, wake_rx) = mpsc::channel();
 
         let mut player_info_app: App = app.clone();
         let mut player_info_settings: Settings = settings.clone();
+        app.player_poll_wake = Some(wake_tx);
 
         // Spawn a new thread to update player's current playback
         let player_info_thread = thread::spawn(move || {
-            update_player_info(tx1, &mut player_info_app, &mut player_info_settings)
+            update_player_info(
+                tx1,
+                liked_tx,
+                wake_rx,
+                &mut player_info_app,
+                &mut player_info_settings,
+            )
         });
 
         // Run the main app loop
-        app.run(&mut terminal, rx1, &mut key, &mut theme, &mut settings)?;
+        app.run(
+            &mut terminal,
+            rx1,
+            liked_rx,
+            &mut key,
+            &mut theme,
+            &mut settings,
+        )?;
 
         // Wait for the spawned threads to complete
         if let Err(e) = player_info_thread.join() {
@@ -4,9 +4,9 @@ use std::collections::HashMap;
 use std::fs::File;
 use std::io::BufReader;
 use std::path::PathBuf;
-use yaml_rust::YamlLoader;
 
 use crate::app::App;
+use crate::enums::Menu;
 use crate::structs::Key;
 
 #[derive(Deserialize, Debug)]
@@ -98,29 +98,346 @@ pub fn set_keybindings(app: &mut App, key: &mut Key) {
         Some(s) => s.chars().next().unwrap_or(' '),
         None => ' ',
     };
+    key.theme_picker_key = match keybindings.get("Theme Picker") {
+        Some(s) => s.chars().next().unwrap_or(' '),
+        None => ' ',
+    };
+    key.lyrics_key = match keybindings.get("Lyrics") {
+        Some(s) => s.chars().next().unwrap_or(' '),
+        None => ' ',
+    };
+    key.queue_key = match keybindings.get("Queue") {
+        Some(s) => s.chars().next().unwrap_or(' '),
+        None => ' ',
+    };
+    key.device_picker_key = match keybindings.get("Devices") {
+        Some(s) => s.chars().next().unwrap_or(' '),
+        None => ' ',
+    };
+    key.seek_forward_key = match keybindings.get("Seek Forward") {
+        Some(s) => s.chars().next().unwrap_or(' '),
+        None => ' ',
+    };
+    key.seek_backward_key = match keybindings.get("Seek Backward") {
+        Some(s) => s.chars().next().unwrap_or(' '),
+        None => ' ',
+    };
+    key.seek_to_position_key = match keybindings.get("Seek to Position") {
+        Some(s) => s.chars().next().unwrap_or(' '),
+        None => ' ',
+    };
+    key.restart_track_key = match keybindings.get("Restart Track") {
+        Some(s) => s.chars().next().unwrap_or(' '),
+        None => ' ',
+    };
+    key.set_volume_key = match keybindings.get("Set Volume") {
+        Some(s) => s.chars().next().unwrap_or(' '),
+        None => ' ',
+    };
+    key.mute_key = match keybindings.get("Mute") {
+        Some(s) => s.chars().next().unwrap_or(' '),
+        None => ' ',
+    };
+    key.save_key = match keybindings.get("Save/Remove from Library") {
+        Some(s) => s.chars().next().unwrap_or(' '),
+        None => ' ',
+    };
+    key.follow_artist_key = match keybindings.get("Follow/Unfollow Playing Artist") {
+        Some(s) => s.chars().next().unwrap_or(' '),
+        None => ' ',
+    };
+    key.save_album_key = match keybindings.get("Save/Remove Playing Album") {
+        Some(s) => s.chars().next().unwrap_or(' '),
+        None => ' ',
+    };
+    key.create_playlist_key = match keybindings.get("Create Playlist") {
+        Some(s) => s.chars().next().unwrap_or(' '),
+        None => ' ',
+    };
+    key.edit_playlist_key = match keybindings.get("Edit Playlist") {
+        Some(s) => s.chars().next().unwrap_or(' '),
+        None => ' ',
+    };
+    key.move_track_key = match keybindings.get("Move Track To Position") {
+        Some(s) => s.chars().next().unwrap_or(' '),
+        None => ' ',
+    };
+    key.toggle_selection_key = match keybindings.get("Select/Unselect Track") {
+        Some(s) => s.chars().next().unwrap_or(' '),
+        None => ' ',
+    };
+    key.select_range_key = match keybindings.get("Select Range") {
+        Some(s) => s.chars().next().unwrap_or(' '),
+        None => ' ',
+    };
+    key.export_key = match keybindings.get("Export") {
+        Some(s) => s.chars().next().unwrap_or(' '),
+        None => ' ',
+    };
+    key.import_key = match keybindings.get("Import Playlist") {
+        Some(s) => s.chars().next().unwrap_or(' '),
+        None => ' ',
+    };
+    key.find_duplicates_key = match keybindings.get("Find Duplicates") {
+        Some(s) => s.chars().next().unwrap_or(' '),
+        None => ' ',
+    };
+}
+
+/// Builds the help entries from the live keymap, grouped by the context they apply to
+pub fn build_help_entries(keys: &mut Key) {
+    keys.help_contexts.clear();
+    keys.tasks.clear();
+    keys.first_keys.clear();
+
+    let entries: Vec<(&str, &str, String)> = vec![
+        ("Global", "Go to Search", key_label(keys.go_to_search_key)),
+        ("Global", "Go to Library", key_label(keys.go_to_library_key)),
+        (
+            "Global",
+            "Go to User Playlists",
+            key_label(keys.go_to_user_playlists_key),
+        ),
+        (
+            "Global",
+            "Go to New Release",
+            key_label(keys.new_release_key),
+        ),
+        (
+            "Global",
+            "Exit Application/Go Back",
+            key_label(keys.exit_application_key),
+        ),
+        ("Global", "Exit Search menu or Help menu", "Esc".to_string()),
+        ("Global", "Help", key_label(keys.help_key)),
+        ("Global", "Error Screen", key_label(keys.error_key)),
+        ("Global", "Theme Picker", key_label(keys.theme_picker_key)),
+        (
+            "Global",
+            "Change Key Bindings",
+            key_label(keys.change_keybind),
+        ),
+        ("Global", "Refresh", key_label(keys.refresh_key)),
+        (
+            "Global",
+            "Open the configure folder",
+            key_label(keys.open_config_fold_key),
+        ),
+        ("Player", "Play/Pause", "Space".to_string()),
+        ("Player", "Play next track", key_label(keys.next_track_key)),
+        (
+            "Player",
+            "Play previous track",
+            key_label(keys.previous_track_key),
+        ),
+        ("Player", "Volume Up", key_label(keys.volume_up_key)),
+        ("Player", "Volume Down", key_label(keys.volume_down_key)),
+        ("Player", "Set Volume", key_label(keys.set_volume_key)),
+        ("Player", "Mute/Unmute", key_label(keys.mute_key)),
+        ("Player", "Seek Forward", key_label(keys.seek_forward_key)),
+        ("Player", "Seek Backward", key_label(keys.seek_backward_key)),
+        (
+            "Player",
+            "Seek to Position (m:ss)",
+            key_label(keys.seek_to_position_key),
+        ),
+        ("Player", "Seek to 0%-90%", "0-9".to_string()),
+        ("Player", "Restart Track", key_label(keys.restart_track_key)),
+        ("Player", "Shuffle", "Ctrl+S".to_string()),
+        ("Player", "Repeat", "Ctrl+R".to_string()),
+        (
+            "Player",
+            "Player in fullscreen",
+            key_label(keys.player_fullscreen_key),
+        ),
+        ("Player", "Lyrics", key_label(keys.lyrics_key)),
+        ("Player", "Queue", key_label(keys.queue_key)),
+        ("Player", "Devices", key_label(keys.device_picker_key)),
+        (
+            "Player",
+            "Like/Unlike playing track",
+            key_label(keys.save_key),
+        ),
+        (
+            "Player",
+            "Save/Remove playing album",
+            key_label(keys.save_album_key),
+        ),
+        (
+            "Player",
+            "Follow/Unfollow playing artist",
+            key_label(keys.follow_artist_key),
+        ),
+        ("Player", "Transfer and play", "Enter".to_string()),
+        ("Player", "Transfer without playing", "Tab".to_string()),
+        ("Lists", "Move up/down", "Up/Down".to_string()),
+        (
+            "Lists",
+            "Open the selected item or play it with the rest of the list",
+            "Enter".to_string(),
+        ),
+        ("Lists", "Play only this track", "Ctrl+O".to_string()),
+        ("Lists", "Navigate between Menu", "Tab".to_string()),
+        ("Lists", "Add track to playlist", "Ctrl+P".to_string()),
+        ("Lists", "Add to queue", "Ctrl+Q".to_string()),
+        (
+            "Lists",
+            "Like/Unlike track, Save/Remove episode",
+            key_label(keys.save_key),
+        ),
+        (
+            "Lists",
+            "Select/unselect track",
+            key_label(keys.toggle_selection_key),
+        ),
+        (
+            "Lists",
+            "Select from the last selected track to this one",
+            key_label(keys.select_range_key),
+        ),
+        ("Lists", "Select all/none", "Ctrl+A".to_string()),
+        (
+            "Lists",
+            "Add to playlist, queue, like, play or remove the selected tracks",
+            format!("Ctrl+P/Ctrl+Q/{}/Ctrl+O/Delete", key_label(keys.save_key)),
+        ),
+        (
+            "Lists",
+            "Export Liked Songs or saved albums",
+            key_label(keys.export_key),
+        ),
+        (
+            "Lists",
+            "Find duplicates in Liked Songs",
+            key_label(keys.find_duplicates_key),
+        ),
+        ("Lists", "Save show", "Ctrl+F".to_string()),
+        ("Lists", "Remove show", "Ctrl+D".to_string()),
+        ("Lists", "Save album/Follow artist", "Ctrl+F".to_string()),
+        (
+            "Lists",
+            "Remove album/Unfollow artist",
+            "Ctrl+D".to_string(),
+        ),
+        ("Search", "Submit search", "Enter".to_string()),
+        ("Search", "Move the cursor", "Left/Right".to_string()),
+        ("Search", "Stop typing", "Esc".to_string()),
+        ("Search", "Follow playlist", "Ctrl+F".to_string()),
+        ("Playlists", "Add track to playlist", "Ctrl+P".to_string()),
+        (
+            "Playlists",
+            "Add at the end, the top or a position",
+            "Tab".to_string(),
+        ),
+        (
+            "Playlists",
+            "Create playlist",
+            key_label(keys.create_playlist_key),
+        ),
+        (
+            "Playlists",
+            "Rename, describe, make public or collaborative",
+            key_label(keys.edit_playlist_key),
+        ),
+        (
+            "Playlists",
+            "Remove track from playlist",
+            "Delete".to_string(),
+        ),
+        (
+            "Playlists",
+            "Move track up/down",
+            "Shift+Up/Down".to_string(),
+        ),
+        (
+            "Playlists",
+            "Move track to position",
+            key_label(keys.move_track_key),
+        ),
+        (
+            "Playlists",
+            "Export playlist to M3U, CSV or JSON",
+            key_label(keys.export_key),
+        ),
+        (
+            "Playlists",
+            "Import playlist from a text, CSV or M3U file",
+            key_label(keys.import_key),
+        ),
+        (
+            "Playlists",
+            "Accept/reject import match, create the imported playlist",
+            format!("Enter/{}", key_label(keys.create_playlist_key)),
+        ),
+        (
+            "Playlists",
+            "Find duplicate tracks in the opened playlist",
+            key_label(keys.find_duplicates_key),
+        ),
+        (
+            "Playlists",
+            "Keep/remove a duplicate copy, remove the copies not kept",
+            "Enter/Delete".to_string(),
+        ),
+        (
+            "Playlists",
+            "Unfollow/Delete playlist",
+            "Ctrl+D".to_string(),
+        ),
+    ];
+
+    for (context, task, key_binding) in entries {
+        keys.help_contexts.push(context.to_string());
+        keys.tasks.push(task.to_string());
+        keys.first_keys.push(key_binding);
+    }
 }
 
-/// Parses the keybindings from the configuration file and populates the tasks and first_keys fields in the App struct
-pub fn parse_keybindings(app: &mut App, keys: &mut Key) {
-    // Construct the paths relative to the root directory
-    let mut yaml_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-    yaml_path.push("..");
-    yaml_path.push("spoify");
-    yaml_path.push("configure");
-    yaml_path.push("keybindings.yml");
+/// Returns the help context that matches the menu the help screen was opened from
+pub fn help_context_for_menu(menu: Menu) -> &'static str {
+    match menu {
+        Menu::Player | Menu::Queue | Menu::DevicePicker => "Player",
+        Menu::Library | Menu::NewRelease => "Lists",
+        Menu::Search => "Search",
+        Menu::Playlists
+        | Menu::AddTrackToPlaylist
+        | Menu::PlaylistEditor
+        | Menu::ImportReview
+        | Menu::Duplicates => "Playlists",
+        Menu::Export => "Lists",
+        _ => "Global",
+    }
+}
 
-    // Load the YAML file
-    let yaml_data = std::fs::read_to_string(&yaml_path).expect("Failed to read YAML file");
+/// Returns the help entries matching the filter, with the context of `origin` listed first
+pub fn help_entries(keys: &Key, origin: Menu, filter: &str) -> Vec<(String, String, String)> {
+    let origin_context = help_context_for_menu(origin);
+    let filter = filter.to_lowercase();
 
-    let docs = YamlLoader::load_from_str(yaml_data.as_str()).expect("Failed to load YAML data");
-    let doc = &docs[0];
+    let mut entries: Vec<(String, String, String)> = keys
+        .help_contexts
+        .iter()
+        .zip(keys.tasks.iter())
+        .zip(keys.first_keys.iter())
+        .filter(|((context, task), key_binding)| {
+            filter.is_empty()
+                || context.to_lowercase().contains(&filter)
+                || task.to_lowercase().contains(&filter)
+                || key_binding.to_lowercase().contains(&filter)
+        })
+        .map(|((context, task), key_binding)| (context.clone(), task.clone(), key_binding.clone()))
+        .collect();
 
-    // Iterate over the YAML data
-    for (key, value) in doc.as_hash().unwrap() {
-        let task = key.as_str().unwrap().to_string();
-        let key_binding = value.as_str().unwrap().to_string();
+    // Stable sort keeps the order inside each context
+    entries.sort_by_key(|(context, _, _)| context != origin_context);
 
-        keys.tasks.push(task);
-        keys.first_keys.push(key_binding);
+    entries
+}
+
+/// Formats a single key binding for display
+fn key_label(key: char) -> String {
+    match key {
+        ' ' => "Space".to_string(),
+        _ => key.to_string(),
     }
 }
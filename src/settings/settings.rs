@@ -1,11 +1,13 @@
 use serde::Deserialize;
 use serde_yaml;
 use std::collections::HashMap;
+use std::env;
 use std::fs::File;
 use std::io::BufReader;
 use std::path::PathBuf;
 
 use crate::app::App;
+use crate::enums::ImageProtocol;
 use crate::structs::Settings;
 
 #[derive(Deserialize, Debug)]
@@ -39,7 +41,73 @@ pub fn set_settings_values(app: &mut App, settings: &mut Settings) {
         settings.volume_decreament_value = value_str.parse::<u8>().unwrap_or(0);
     }
 
+    if let Some(value_str) = settings_values.get("Seek Step") {
+        settings.seek_step = value_str.parse::<u32>().unwrap_or(10);
+    }
+
+    if let Some(value_str) = settings_values.get("Podcast Seek Step") {
+        settings.podcast_seek_step = value_str.parse::<u32>().unwrap_or(30);
+    }
+
+    if let Some(value_str) = settings_values.get("Poll Interval") {
+        settings.poll_interval = value_str.parse::<u64>().unwrap_or(1000).max(250);
+    }
+
+    if let Some(value_str) = settings_values.get("Paused Poll Interval") {
+        settings.paused_poll_interval = value_str.parse::<u64>().unwrap_or(5000).max(250);
+    }
+
+    if let Some(value_str) = settings_values.get("Idle Poll Interval") {
+        settings.idle_poll_interval = value_str.parse::<u64>().unwrap_or(15000).max(250);
+    }
+
     if let Some(value_str) = settings_values.get("Theme") {
         settings.theme_name = value_str.to_string();
     }
+
+    if let Some(value_str) = settings_values.get("Album Art") {
+        settings.album_art_protocol = parse_image_protocol(value_str);
+    }
+
+    if let Some(value_str) = settings_values.get("Lyrics Folder") {
+        settings.lyrics_folder = value_str.to_string();
+    }
+
+    if let Some(value_str) = settings_values.get("Export Folder") {
+        settings.export_folder = value_str.to_string();
+    }
+
+    if let Some(value_str) = settings_values.get("Preferred Device") {
+        settings.preferred_device = value_str.to_string();
+    }
+}
+
+/// Maps the "Album Art" setting to an image protocol, detecting the terminal's support for "auto"
+fn parse_image_protocol(value: &str) -> ImageProtocol {
+    match value.to_lowercase().as_str() {
+        "kitty" => ImageProtocol::Kitty,
+        "sixel" => ImageProtocol::Sixel,
+        "halfblocks" => ImageProtocol::HalfBlocks,
+        "off" => ImageProtocol::Off,
+        _ => {
+            let term = env::var("TERM").unwrap_or_default();
+            let term_program = env::var("TERM_PROGRAM").unwrap_or_default();
+
+            if env::var("KITTY_WINDOW_ID").is_ok()
+                || term.contains("kitty")
+                || term.contains("ghostty")
+                || term_program == "WezTerm"
+            {
+                ImageProtocol::Kitty
+            } else if term.contains("sixel")
+                || term.starts_with("foot")
+                || term.starts_with("mlterm")
+                || term_program == "contour"
+            {
+                ImageProtocol::Sixel
+            } else {
+                ImageProtocol::HalfBlocks
+            }
+        }
+    }
 }
@@ -3,8 +3,8 @@ use serde::Deserialize;
 use serde_json::Value;
 use serde_yaml;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::BufReader;
+use std::fs::{self, File};
+use std::io::{self, BufReader};
 use std::path::PathBuf;
 
 use crate::app::App;
@@ -102,6 +102,65 @@ pub fn set_theme(app: &mut App, themes: &mut Themes, settings: &mut Settings) {
     }
 }
 
+/// Lists every theme file in the configure folder, leaving out the non-theme configuration files
+pub fn list_themes(app: &mut App) {
+    app.theme_names.clear();
+
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("..");
+    path.push("spoify");
+    path.push("configure");
+
+    let non_theme_files = ["creds", "keybindings", "settings"];
+
+    if let Ok(entries) = fs::read_dir(&path) {
+        for entry in entries.flatten() {
+            let file_path = entry.path();
+            if file_path.extension().and_then(|ext| ext.to_str()) != Some("yml") {
+                continue;
+            }
+            if let Some(name) = file_path.file_stem().and_then(|stem| stem.to_str()) {
+                if !non_theme_files.contains(&name) {
+                    app.theme_names.push(name.to_string());
+                }
+            }
+        }
+    }
+
+    app.theme_names.sort();
+}
+
+/// Writes the selected theme name back to settings.yml, keeping the rest of the file untouched
+pub fn save_theme_to_settings(settings: &mut Settings) -> io::Result<()> {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("..");
+    path.push("spoify");
+    path.push("configure");
+    path.push("settings.yml");
+
+    let contents = fs::read_to_string(&path)?;
+    let theme_line = format!("Theme: \"{}\"", settings.theme_name);
+
+    let mut found = false;
+    let mut lines: Vec<String> = contents
+        .lines()
+        .map(|line| {
+            if line.starts_with("Theme:") {
+                found = true;
+                theme_line.clone()
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+
+    if !found {
+        lines.push(theme_line);
+    }
+
+    fs::write(&path, lines.join("\n") + "\n")
+}
+
 /// Parses an RGB color string in the format "Color::Rgb(r, g, b)" into a tuple of (u8, u8, u8)
 fn parse_color(value: &str) -> (u8, u8, u8) {
     let binding = value.replace("Color::Rgb(", "").replace(')', "");
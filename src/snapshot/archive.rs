@@ -0,0 +1,82 @@
+use std::fs::{self, File};
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+
+/// Bumped whenever the archive layout changes, older spoify versions refuse newer archives
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// A saved item, the name is only there to make diffs and the archive readable
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SnapshotItem {
+    pub uri: String,
+    pub name: String,
+}
+
+/// An owned playlist with its tracks in playlist order
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SnapshotPlaylist {
+    pub uri: String,
+    pub name: String,
+    pub public: bool,
+    pub collaborative: bool,
+    pub tracks: Vec<SnapshotItem>,
+    /// Names of local files in the playlist, they cannot be added back so they are only counted
+    #[serde(default)]
+    pub local_files: Vec<String>,
+}
+
+/// Everything a snapshot archive holds
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub version: u32,
+    pub created_at: String,
+    pub user_id: String,
+    pub liked_songs: Vec<SnapshotItem>,
+    pub saved_albums: Vec<SnapshotItem>,
+    pub followed_artists: Vec<SnapshotItem>,
+    pub saved_shows: Vec<SnapshotItem>,
+    /// Playlists of other users the account follows
+    pub followed_playlists: Vec<SnapshotItem>,
+    pub playlists: Vec<SnapshotPlaylist>,
+}
+
+/// Returns `spoify/snapshots/snapshot-<date>-<time>.json`
+pub fn default_snapshot_path() -> PathBuf {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push(".."); // Move up to the root of the Git repository
+    path.push("spoify");
+    path.push("snapshots");
+    path.push(format!(
+        "snapshot-{}.json",
+        Local::now().format("%Y-%m-%d-%H%M%S")
+    ));
+    path
+}
+
+pub fn write_snapshot(path: &Path, snapshot: &Snapshot) -> io::Result<()> {
+    if let Some(folder) = path
+        .parent()
+        .filter(|folder| !folder.as_os_str().is_empty())
+    {
+        fs::create_dir_all(folder)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(snapshot)?)
+}
+
+pub fn read_snapshot(path: &Path) -> io::Result<Snapshot> {
+    let file = File::open(path)?;
+    let snapshot: Snapshot = serde_json::from_reader(BufReader::new(file))?;
+    if snapshot.version > SNAPSHOT_VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "the snapshot has version {}, this spoify reads up to version {}",
+                snapshot.version, SNAPSHOT_VERSION
+            ),
+        ));
+    }
+    Ok(snapshot)
+}
@@ -0,0 +1,374 @@
+use std::collections::HashSet;
+
+use super::archive::{Snapshot, SnapshotItem, SnapshotPlaylist};
+
+/// Items listed per section in the dry run before the rest is summed up
+const LISTED_ITEMS: usize = 10;
+
+/// Where the tracks of a snapshot playlist end up on restore
+#[derive(Clone, Debug, PartialEq)]
+pub enum PlaylistTarget {
+    /// An owned playlist with the same id, or the same name on another account, its tracks are replaced
+    Existing(String),
+    /// Unfollowed by the account the snapshot was taken from, following it again brings it back
+    Refollow(String),
+    Create,
+}
+
+#[derive(Clone, Debug)]
+pub struct PlaylistRestore {
+    pub playlist: SnapshotPlaylist,
+    pub target: PlaylistTarget,
+    pub added: usize,
+    pub removed: usize,
+}
+
+/// The changes that make the account match a snapshot, nothing is removed from the library
+#[derive(Clone, Debug, Default)]
+pub struct RestorePlan {
+    pub liked_songs: Vec<SnapshotItem>,
+    pub saved_albums: Vec<SnapshotItem>,
+    pub followed_artists: Vec<SnapshotItem>,
+    pub saved_shows: Vec<SnapshotItem>,
+    pub followed_playlists: Vec<SnapshotItem>,
+    pub playlists: Vec<PlaylistRestore>,
+    /// Current playlists left alone because replacing their tracks would delete their local files
+    pub skipped_playlists: Vec<SnapshotPlaylist>,
+}
+
+impl RestorePlan {
+    pub fn is_empty(&self) -> bool {
+        self.liked_songs.is_empty()
+            && self.saved_albums.is_empty()
+            && self.followed_artists.is_empty()
+            && self.saved_shows.is_empty()
+            && self.followed_playlists.is_empty()
+            && self.playlists.is_empty()
+            && self.skipped_playlists.is_empty()
+    }
+}
+
+/// Compares `snapshot` with the `current` state of the account
+pub fn plan_restore(snapshot: &Snapshot, current: &Snapshot) -> RestorePlan {
+    let mut plan = RestorePlan {
+        liked_songs: missing(&snapshot.liked_songs, &current.liked_songs),
+        saved_albums: missing(&snapshot.saved_albums, &current.saved_albums),
+        followed_artists: missing(&snapshot.followed_artists, &current.followed_artists),
+        saved_shows: missing(&snapshot.saved_shows, &current.saved_shows),
+        followed_playlists: missing(&snapshot.followed_playlists, &current.followed_playlists),
+        playlists: Vec::new(),
+        skipped_playlists: Vec::new(),
+    };
+
+    // Each current playlist takes the tracks of one snapshot playlist at most
+    let mut used: HashSet<&str> = HashSet::new();
+    let same_account = snapshot.user_id == current.user_id;
+    for playlist in &snapshot.playlists {
+        let unused = |current: &&SnapshotPlaylist| !used.contains(current.uri.as_str());
+        let existing = current
+            .playlists
+            .iter()
+            .filter(unused)
+            .find(|current| current.uri == playlist.uri)
+            .or_else(|| {
+                // On the same account a playlist gone by uri was unfollowed,
+                // one with the same name is a different playlist
+                if same_account {
+                    return None;
+                }
+                current
+                    .playlists
+                    .iter()
+                    .filter(unused)
+                    .find(|current| current.name == playlist.name)
+            });
+
+        let restore = match existing {
+            Some(existing) => {
+                used.insert(existing.uri.as_str());
+                if !existing.local_files.is_empty() {
+                    plan.skipped_playlists.push(existing.clone());
+                    continue;
+                }
+                let uris = |tracks: &[SnapshotItem]| -> Vec<String> {
+                    tracks.iter().map(|track| track.uri.clone()).collect()
+                };
+                if uris(&existing.tracks) == uris(&playlist.tracks) {
+                    continue;
+                }
+                PlaylistRestore {
+                    playlist: playlist.clone(),
+                    target: PlaylistTarget::Existing(existing.uri.clone()),
+                    added: missing(&playlist.tracks, &existing.tracks).len(),
+                    removed: missing(&existing.tracks, &playlist.tracks).len(),
+                }
+            }
+            None => PlaylistRestore {
+                playlist: playlist.clone(),
+                target: if snapshot.user_id == current.user_id {
+                    PlaylistTarget::Refollow(playlist.uri.clone())
+                } else {
+                    PlaylistTarget::Create
+                },
+                added: playlist.tracks.len(),
+                removed: 0,
+            },
+        };
+        plan.playlists.push(restore);
+    }
+
+    plan
+}
+
+/// The dry run, one line per change
+pub fn describe_plan(plan: &RestorePlan) -> Vec<String> {
+    let mut lines = Vec::new();
+    describe_items(&mut lines, "Liked songs to add", &plan.liked_songs);
+    describe_items(&mut lines, "Albums to save", &plan.saved_albums);
+    describe_items(&mut lines, "Artists to follow", &plan.followed_artists);
+    describe_items(&mut lines, "Shows to save", &plan.saved_shows);
+    describe_items(&mut lines, "Playlists to follow", &plan.followed_playlists);
+
+    for restore in &plan.playlists {
+        let name = &restore.playlist.name;
+        lines.push(match restore.target {
+            PlaylistTarget::Existing(_) if restore.added == 0 && restore.removed == 0 => {
+                format!("Playlist \"{}\": put the tracks back in order", name)
+            }
+            PlaylistTarget::Existing(_) => format!(
+                "Playlist \"{}\": +{} -{} tracks, then put them in order",
+                name, restore.added, restore.removed
+            ),
+            PlaylistTarget::Refollow(_) => format!(
+                "Playlist \"{}\": follow it again and restore its {} tracks",
+                name, restore.added
+            ),
+            PlaylistTarget::Create => format!(
+                "Playlist \"{}\": create it with {} tracks",
+                name, restore.added
+            ),
+        });
+    }
+
+    for playlist in &plan.skipped_playlists {
+        lines.push(format!(
+            "Playlist \"{}\": skipped, restoring it would delete its {} local files",
+            playlist.name,
+            playlist.local_files.len()
+        ));
+        for name in playlist.local_files.iter().take(LISTED_ITEMS) {
+            lines.push(format!("  ! {}", name));
+        }
+        if playlist.local_files.len() > LISTED_ITEMS {
+            lines.push(format!(
+                "  ... and {} more",
+                playlist.local_files.len() - LISTED_ITEMS
+            ));
+        }
+    }
+
+    lines
+}
+
+fn describe_items(lines: &mut Vec<String>, title: &str, items: &[SnapshotItem]) {
+    if items.is_empty() {
+        return;
+    }
+    lines.push(format!("{}: {}", title, items.len()));
+    for item in items.iter().take(LISTED_ITEMS) {
+        lines.push(format!("  + {}", item.name));
+    }
+    if items.len() > LISTED_ITEMS {
+        lines.push(format!("  ... and {} more", items.len() - LISTED_ITEMS));
+    }
+}
+
+// Items of `wanted` that `have` does not contain
+fn missing(wanted: &[SnapshotItem], have: &[SnapshotItem]) -> Vec<SnapshotItem> {
+    let have: HashSet<&str> = have.iter().map(|item| item.uri.as_str()).collect();
+    wanted
+        .iter()
+        .filter(|item| !have.contains(item.uri.as_str()))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(uri: &str) -> SnapshotItem {
+        SnapshotItem {
+            uri: uri.to_string(),
+            name: uri.to_string(),
+        }
+    }
+
+    fn playlist(uri: &str, name: &str, tracks: &[&str]) -> SnapshotPlaylist {
+        SnapshotPlaylist {
+            uri: uri.to_string(),
+            name: name.to_string(),
+            tracks: tracks.iter().map(|uri| item(uri)).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn snapshot(user_id: &str, playlists: Vec<SnapshotPlaylist>) -> Snapshot {
+        Snapshot {
+            user_id: user_id.to_string(),
+            playlists,
+            ..Default::default()
+        }
+    }
+
+    fn targets(plan: &RestorePlan) -> Vec<(&str, PlaylistTarget, usize, usize)> {
+        plan.playlists
+            .iter()
+            .map(|restore| {
+                (
+                    restore.playlist.name.as_str(),
+                    restore.target.clone(),
+                    restore.added,
+                    restore.removed,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn playlists_match_by_uri_before_name() {
+        let saved = snapshot(
+            "me",
+            vec![playlist("spotify:playlist:a", "Renamed", &["t1", "t2"])],
+        );
+        let current = snapshot(
+            "me",
+            vec![
+                playlist("spotify:playlist:b", "Renamed", &["t1"]),
+                playlist("spotify:playlist:a", "New Name", &["t1", "t3"]),
+            ],
+        );
+
+        assert_eq!(
+            targets(&plan_restore(&saved, &current)),
+            vec![(
+                "Renamed",
+                PlaylistTarget::Existing("spotify:playlist:a".to_string()),
+                1,
+                1
+            )]
+        );
+    }
+
+    #[test]
+    fn playlists_match_by_name_when_the_uri_is_gone() {
+        let saved = snapshot("me", vec![playlist("spotify:playlist:a", "Mix", &["t1"])]);
+        let current = snapshot("other", vec![playlist("spotify:playlist:b", "Mix", &[])]);
+
+        assert_eq!(
+            targets(&plan_restore(&saved, &current)),
+            vec![(
+                "Mix",
+                PlaylistTarget::Existing("spotify:playlist:b".to_string()),
+                1,
+                0
+            )]
+        );
+    }
+
+    #[test]
+    fn each_current_playlist_is_used_once() {
+        let saved = snapshot(
+            "me",
+            vec![
+                playlist("spotify:playlist:a", "Mix", &["t1"]),
+                playlist("spotify:playlist:b", "Mix", &["t2"]),
+                playlist("spotify:playlist:c", "Other", &["t3"]),
+            ],
+        );
+        let current = snapshot(
+            "other",
+            vec![
+                playlist("spotify:playlist:a", "Mix", &[]),
+                playlist("spotify:playlist:d", "Mix", &[]),
+            ],
+        );
+
+        assert_eq!(
+            targets(&plan_restore(&saved, &current)),
+            vec![
+                (
+                    "Mix",
+                    PlaylistTarget::Existing("spotify:playlist:a".to_string()),
+                    1,
+                    0
+                ),
+                (
+                    "Mix",
+                    PlaylistTarget::Existing("spotify:playlist:d".to_string()),
+                    1,
+                    0
+                ),
+                ("Other", PlaylistTarget::Create, 1, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn unfollowed_playlists_are_followed_again_on_the_same_account() {
+        let saved = snapshot("me", vec![playlist("spotify:playlist:a", "Mix", &["t1"])]);
+        let current = snapshot("me", vec![playlist("spotify:playlist:b", "Mix", &["t2"])]);
+
+        assert_eq!(
+            targets(&plan_restore(&saved, &current)),
+            vec![(
+                "Mix",
+                PlaylistTarget::Refollow("spotify:playlist:a".to_string()),
+                1,
+                0
+            )]
+        );
+    }
+
+    #[test]
+    fn unchanged_playlists_are_left_out() {
+        let saved = snapshot("me", vec![playlist("spotify:playlist:a", "Mix", &["t1"])]);
+        let current = saved.clone();
+
+        assert!(plan_restore(&saved, &current).is_empty());
+    }
+
+    #[test]
+    fn playlists_with_local_files_are_skipped_and_listed() {
+        let saved = snapshot("me", vec![playlist("spotify:playlist:a", "Mix", &["t1"])]);
+        let mut with_local = playlist("spotify:playlist:a", "Mix", &[]);
+        with_local.local_files = vec!["Home Recording".to_string()];
+        let current = snapshot("me", vec![with_local]);
+
+        let plan = plan_restore(&saved, &current);
+        assert!(plan.playlists.is_empty());
+        assert_eq!(
+            describe_plan(&plan),
+            vec![
+                "Playlist \"Mix\": skipped, restoring it would delete its 1 local files",
+                "  ! Home Recording",
+            ]
+        );
+    }
+
+    #[test]
+    fn library_items_already_saved_are_not_added_again() {
+        let mut saved = snapshot("me", Vec::new());
+        saved.liked_songs = vec![item("t1"), item("t2")];
+        let mut current = snapshot("me", Vec::new());
+        current.liked_songs = vec![item("t2")];
+
+        let plan = plan_restore(&saved, &current);
+        let uris: Vec<&str> = plan
+            .liked_songs
+            .iter()
+            .map(|item| item.uri.as_str())
+            .collect();
+        assert_eq!(uris, vec!["t1"]);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::spotify::http_client::http_client;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Returns the path an image is cached at inside the album art cache directory
+pub fn album_art_path(image_url: &str) -> PathBuf {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push(".."); // Move up to the root of the Git repository
+    path.push("spoify");
+    path.push("spotify_cache");
+    path.push("album_art");
+
+    // Spotify image urls end with a unique id, keep only the characters that are safe in a file name
+    let file_name: String = image_url
+        .rsplit('/')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+        .collect();
+    path.push(file_name);
+
+    path
+}
+
+/// Downloads an image into the album art cache, skipping images that are already cached
+#[tokio::main]
+pub async fn fetch_album_art(image_url: &str) -> anyhow::Result<()> {
+    if image_url.is_empty() {
+        return Ok(());
+    }
+
+    download_image(image_url, &album_art_path(image_url)).await
+}
+
+// Saves the image at `image_url` to `path` unless the file is already there
+async fn download_image(image_url: &str, path: &Path) -> anyhow::Result<()> {
+    if path.exists() {
+        return Ok(());
+    }
+
+    let response = http_client()
+        .get(image_url)
+        .send()
+        .await?
+        .error_for_status()?;
+    let bytes = response.bytes().await?;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, &bytes)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    // Answers one request with `status` and `body`, returns the url to request
+    fn stand_in_server(status: &'static str, body: &'static [u8]) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 1024];
+            let _ = stream.read(&mut request);
+            let head = format!(
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                status,
+                body.len()
+            );
+            let _ = stream.write_all(head.as_bytes());
+            let _ = stream.write_all(body);
+        });
+        format!("http://{}/image/ab67616d0000b273", address)
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("spoify-album-art-{}", std::process::id()))
+            .join(name)
+    }
+
+    #[test]
+    fn cache_file_is_named_after_the_image_id() {
+        let path = album_art_path("https://i.scdn.co/image/ab67616d0000b273?size=640");
+
+        assert_eq!(
+            path.file_name().and_then(|name| name.to_str()),
+            Some("ab67616d0000b273size640")
+        );
+    }
+
+    #[tokio::test]
+    async fn image_is_downloaded_into_the_cache() {
+        let url = stand_in_server("200 OK", b"image bytes");
+        let path = temp_path("downloaded");
+
+        download_image(&url, &path).await.unwrap();
+
+        assert_eq!(fs::read(&path).unwrap(), b"image bytes");
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn failed_download_leaves_no_file() {
+        let url = stand_in_server("404 Not Found", b"");
+        let path = temp_path("missing");
+
+        assert!(download_image(&url, &path).await.is_err());
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn cached_image_is_not_downloaded_again() {
+        let path = temp_path("cached");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, b"cached").unwrap();
+
+        // Nothing listens on this port, a request would fail
+        download_image("http://127.0.0.1:9/image/x", &path)
+            .await
+            .unwrap();
+
+        assert_eq!(fs::read(&path).unwrap(), b"cached");
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,88 @@
+// Fetches full track details (album, ISRC) for exporting playlists, liked songs and saved albums
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+
+use crate::app::App;
+use crate::spotify::auth::get_spotify_client;
+use crate::spotify::player::util::context_uri;
+use futures_util::TryStreamExt;
+use rspotify::clients::{BaseClient, OAuthClient};
+use rspotify::model::{FullTrack, PlayableItem, PlaylistId, SavedTrack, TrackId};
+use rspotify::ClientError;
+
+/// Spotify returns at most this many tracks per request
+const TRACKS_BATCH_SIZE: usize = 50;
+
+/// Fetches every track of the playlist behind `playlist_link`, in playlist order
+#[tokio::main]
+pub async fn playlist_full_tracks(
+    app: &mut App,
+    playlist_link: &str,
+) -> Result<Vec<FullTrack>, ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let playlist_uri = context_uri("playlist", playlist_link);
+    let Ok(playlist_id) = PlaylistId::from_uri(&playlist_uri) else {
+        return Ok(Vec::new());
+    };
+
+    let mut tracks = Vec::new();
+    let mut items = spotify.playlist_items(playlist_id, None, None);
+    while let Some(item) = items.try_next().await? {
+        if let Some(PlayableItem::Track(track)) = item.track {
+            tracks.push(track);
+        }
+    }
+
+    Ok(tracks)
+}
+
+/// Fetches every track of every saved album, album by album
+#[tokio::main]
+pub async fn saved_album_full_tracks(app: &mut App) -> Result<Vec<FullTrack>, ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let mut track_ids: Vec<TrackId> = Vec::new();
+    let mut saved_albums = spotify.current_user_saved_albums(None);
+    while let Some(saved_album) = saved_albums.try_next().await? {
+        let album = saved_album.album;
+        // Saved albums only come with their first page of tracks
+        if album.tracks.total as usize == album.tracks.items.len() {
+            track_ids.extend(album.tracks.items.into_iter().filter_map(|track| track.id));
+        } else {
+            let mut album_tracks = spotify.album_track(album.id, None);
+            while let Some(track) = album_tracks.try_next().await? {
+                track_ids.extend(track.id);
+            }
+        }
+    }
+
+    // Album tracks leave out the ISRC, the full tracks have it
+    let mut tracks = Vec::new();
+    for batch in track_ids.chunks(TRACKS_BATCH_SIZE) {
+        tracks.extend(spotify.tracks(batch.iter().cloned(), None).await?);
+    }
+
+    Ok(tracks)
+}
+
+/// Reads the liked songs cached by `liked_tracks`, which also have the album and ISRC
+pub fn cached_liked_tracks() -> Option<Vec<FullTrack>> {
+    let saved_tracks: Vec<SavedTrack> = read_cache("liked_songs.json")?;
+    Some(saved_tracks.into_iter().map(|saved| saved.track).collect())
+}
+
+fn read_cache<T: serde::de::DeserializeOwned>(file_name: &str) -> Option<T> {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push(".."); // Move up to the root of the Git repository
+    path.push("spoify");
+    path.push("spotify_cache");
+    path.push(file_name);
+
+    let file = File::open(&path).ok()?;
+    serde_json::from_reader(BufReader::new(file)).ok()
+}
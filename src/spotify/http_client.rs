@@ -0,0 +1,16 @@
+use reqwest::Client;
+use std::sync::OnceLock;
+
+static HTTP_CLIENT: OnceLock<Client> = OnceLock::new();
+
+/// Returns the HTTP client shared by every request that doesn't go through rspotify
+pub fn http_client() -> &'static Client {
+    HTTP_CLIENT.get_or_init(|| {
+        // Every request runs inside its own short-lived tokio runtime, so pooled
+        // connections can't be reused between calls and are dropped right away
+        Client::builder()
+            .pool_max_idle_per_host(0)
+            .build()
+            .unwrap_or_default()
+    })
+}
@@ -0,0 +1,109 @@
+// Resolves import entries to Spotify tracks with the search API
+
+use crate::app::App;
+use crate::import::matching::{match_confidence, ImportMatch};
+use crate::import::parse::ImportEntry;
+use crate::spotify::auth::get_spotify_client;
+use crate::spotify::player::util::{playable_id_from_link, rate_limit_retry_after};
+use rspotify::clients::BaseClient;
+use rspotify::model::{FullTrack, Market, PlayableId, SearchResult, SearchType};
+use rspotify::{AuthCodeSpotify, ClientError};
+
+/// Search results compared against each entry
+const SEARCH_LIMIT: u32 = 5;
+
+/// Finds the best matching track for every entry, in file order.
+/// Entries whose lookup fails are kept as misses so the rest of the file is not lost.
+#[tokio::main]
+pub async fn match_import_entries(
+    app: &mut App,
+    entries: Vec<ImportEntry>,
+) -> Result<Vec<ImportMatch>, ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let mut matches = Vec::new();
+    for entry in entries {
+        let track = match best_match(&spotify, &entry).await {
+            Ok(track) => track,
+            // Searching a long file can hit the rate limit, wait as asked and try once more
+            Err(e) => match rate_limit_retry_after(&e) {
+                Some(retry_after) => {
+                    tokio::time::sleep(retry_after).await;
+                    best_match(&spotify, &entry).await.unwrap_or(None)
+                }
+                None => None,
+            },
+        };
+        matches.push(ImportMatch::new(
+            entry,
+            track
+                .as_ref()
+                .map(|(track, confidence)| (track, *confidence)),
+        ));
+    }
+
+    Ok(matches)
+}
+
+// Spotify links are taken as they are, then the ISRC is tried, then the title and artist
+async fn best_match(
+    spotify: &AuthCodeSpotify,
+    entry: &ImportEntry,
+) -> Result<Option<(FullTrack, u8)>, ClientError> {
+    if let Some(PlayableId::Track(track_id)) = playable_id_from_link(&entry.uri) {
+        let track = spotify.track(track_id, None).await?;
+        return Ok(Some((track, 100)));
+    }
+
+    if !entry.isrc.is_empty() {
+        let tracks = search_tracks(spotify, &format!("isrc:{}", entry.isrc)).await?;
+        if let Some(track) = tracks.into_iter().next() {
+            return Ok(Some((track, 100)));
+        }
+    }
+
+    if entry.title.is_empty() {
+        return Ok(None);
+    }
+    let first_artist = entry.artist.split(',').next().unwrap_or_default().trim();
+    let query = if first_artist.is_empty() {
+        format!("track:{}", entry.title)
+    } else {
+        format!("track:{} artist:{}", entry.title, first_artist)
+    };
+    let mut tracks = search_tracks(spotify, &query).await?;
+    if tracks.is_empty() {
+        // Field filters are strict, a plain search copes better with small spelling differences
+        tracks = search_tracks(spotify, &format!("{} {}", first_artist, entry.title)).await?;
+    }
+
+    Ok(tracks
+        .into_iter()
+        .map(|track| {
+            let confidence = match_confidence(entry, &track);
+            (track, confidence)
+        })
+        .max_by_key(|(_, confidence)| *confidence))
+}
+
+async fn search_tracks(
+    spotify: &AuthCodeSpotify,
+    query: &str,
+) -> Result<Vec<FullTrack>, ClientError> {
+    let result = spotify
+        .search(
+            query,
+            SearchType::Track,
+            Some(Market::FromToken),
+            None,
+            Some(SEARCH_LIMIT),
+            None,
+        )
+        .await?;
+
+    Ok(match result {
+        SearchResult::Tracks(page) => page.items,
+        _ => Vec::new(),
+    })
+}
@@ -0,0 +1 @@
+pub mod match_tracks;
@@ -0,0 +1,197 @@
+use crate::app::App;
+use crate::spotify::auth::get_spotify_client;
+use rspotify::prelude::BaseClient;
+use rspotify::ClientError;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Write};
+use std::path::PathBuf;
+
+// rspotify has no audiobook endpoints, so these requests go through its raw API helpers.
+// Spotify returns at most 50 items per page.
+const ITEMS_PER_REQUEST: usize = 50;
+
+/// Fetches the audiobooks the user saved
+#[tokio::main]
+pub async fn user_audiobooks(app: &mut App) -> Result<(), ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let audiobooks = fetch_all_pages(&spotify, "me/audiobooks").await?;
+    save_to_json("audiobooks.json", audiobooks);
+
+    Ok(())
+}
+
+/// Fetches the chapters of the opened audiobook, including where the user stopped listening
+#[tokio::main]
+pub async fn audiobook_chapters(app: &mut App) -> Result<(), ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let url = format!("audiobooks/{}/chapters", app.selected_show_link);
+    let chapters = fetch_all_pages(&spotify, &url).await?;
+    save_to_json("audiobook_chapters.json", chapters);
+
+    Ok(())
+}
+
+async fn fetch_all_pages(spotify: &impl BaseClient, url: &str) -> Result<Vec<Value>, ClientError> {
+    let mut items = Vec::new();
+    let limit = ITEMS_PER_REQUEST.to_string();
+    loop {
+        let offset = items.len().to_string();
+        let query = HashMap::from([("limit", limit.as_str()), ("offset", offset.as_str())]);
+        let response = spotify.api_get(url, &query).await?;
+        let page: Value = serde_json::from_str(&response)?;
+
+        let page_items = page
+            .get("items")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+        let item_count = page_items.len();
+        items.extend(page_items);
+
+        if item_count < ITEMS_PER_REQUEST || page.get("next").is_none_or(Value::is_null) {
+            break;
+        }
+    }
+
+    Ok(items)
+}
+
+/// Saves fetched items to a JSON file in the Spotify cache directory
+fn save_to_json(file_name: &str, items: Vec<Value>) {
+    let json_data = json!(items);
+
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push(".."); // Move up to the root of the Git repository
+    path.push("spoify");
+    path.push("spotify_cache");
+    std::fs::create_dir_all(&path).unwrap();
+    path.push(file_name);
+
+    let mut file = File::create(&path).unwrap();
+    let _ = file.write_all(json_data.to_string().as_bytes());
+}
+
+/// Reads a cached JSON file, returns None when it is missing or broken
+fn read_from_json(file_name: &str) -> Option<Value> {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push(".."); // Move up to the root of the Git repository
+    path.push("spoify");
+    path.push("spotify_cache");
+    path.push(file_name);
+
+    let file = File::open(&path).ok()?;
+    serde_json::from_reader(BufReader::new(file)).ok()
+}
+
+/// Processes the saved audiobooks and populates the app's data structures
+pub fn process_audiobooks(app: &mut App) {
+    app.audiobook_names.clear();
+    app.audiobook_ids.clear();
+    app.audiobook_authors.clear();
+    app.audiobook_narrators.clear();
+    app.audiobook_descriptions.clear();
+
+    let names_of = |audiobook: &Value, key: &str| -> String {
+        audiobook
+            .get(key)
+            .and_then(Value::as_array)
+            .map(|people| {
+                people
+                    .iter()
+                    .filter_map(|person| person.get("name").and_then(Value::as_str))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .unwrap_or_default()
+    };
+
+    if let Some(Value::Array(audiobooks)) = read_from_json("audiobooks.json") {
+        for audiobook in audiobooks {
+            let Some(id) = audiobook.get("id").and_then(Value::as_str) else {
+                continue;
+            };
+            app.audiobook_ids.push(id.to_string());
+            app.audiobook_names.push(
+                audiobook
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+            );
+            app.audiobook_authors.push(names_of(&audiobook, "authors"));
+            app.audiobook_narrators
+                .push(names_of(&audiobook, "narrators"));
+            app.audiobook_descriptions.push(
+                audiobook
+                    .get("description")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+            );
+        }
+    }
+}
+
+/// Fills the episode table with the chapters of the opened audiobook
+pub fn process_audiobook_chapters(app: &mut App) {
+    app.podcast_episode_names.clear();
+    app.podcast_episode_dates.clear();
+    app.podcast_episode_durations.clear();
+    app.podcast_episode_resume_points.clear();
+    app.podcast_episode_fully_played.clear();
+    app.podcast_episode_links.clear();
+    app.podcast_episode_saved.clear();
+
+    if let Some(Value::Array(chapters)) = read_from_json("audiobook_chapters.json") {
+        for chapter in chapters {
+            let Some(id) = chapter.get("id").and_then(Value::as_str) else {
+                continue;
+            };
+            // The player handles chapters as episodes of the audiobook
+            app.podcast_episode_links
+                .push(format!("spotify:episode:{}", id));
+
+            app.podcast_episode_names.push(
+                chapter
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+            );
+            app.podcast_episode_dates.push(
+                chapter
+                    .get("release_date")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+            );
+            app.podcast_episode_durations.push(
+                chapter
+                    .get("duration_ms")
+                    .and_then(Value::as_i64)
+                    .unwrap_or(0),
+            );
+
+            let resume_point = chapter.get("resume_point");
+            app.podcast_episode_resume_points.push(
+                resume_point
+                    .and_then(|v| v.get("resume_position_ms"))
+                    .and_then(Value::as_i64)
+                    .unwrap_or(0),
+            );
+            app.podcast_episode_fully_played.push(
+                resume_point
+                    .and_then(|v| v.get("fully_played"))
+                    .and_then(Value::as_bool)
+                    .unwrap_or(false),
+            );
+            app.podcast_episode_saved.push(false);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::app::App;
+use crate::enums::Menu;
+use crate::spotify::auth::get_spotify_client;
+use crate::spotify::player::util::context_uri;
+use rspotify::clients::{BaseClient, OAuthClient};
+use rspotify::model::ArtistId;
+use rspotify::ClientError;
+
+/// Follows the artist behind `artist_link` and puts them at the top of the followed artists list
+#[tokio::main]
+pub async fn follow_artist(app: &mut App, artist_link: &str) -> Result<(), ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let Some(artist_id) = artist_id(app, artist_link) else {
+        return Ok(());
+    };
+
+    if let Err(e) = spotify.user_follow_artists([artist_id.clone()]).await {
+        app.error_text = format!("Error following artist: {}", e);
+        app.selected_menu = Menu::Error;
+        return Err(e);
+    }
+
+    let uri = context_uri("artist", artist_link);
+    if app
+        .user_artist_links
+        .iter()
+        .any(|link| context_uri("artist", link) == uri)
+    {
+        return Ok(());
+    }
+
+    // Only the new artist is fetched, the rest of the list stays as it is
+    let artist = spotify.artist(artist_id).await?;
+    app.user_artist_names.insert(0, artist.name);
+    app.user_artist_links.insert(
+        0,
+        artist.external_urls.get("spotify").cloned().unwrap_or(uri),
+    );
+
+    // Keep the same artist selected now that everything moved down by one
+    if app.user_artist_display && app.user_artist_links.len() > 1 {
+        app.user_artist_index += 1;
+        app.user_artist_state.select(Some(app.user_artist_index));
+    }
+
+    Ok(())
+}
+
+/// Unfollows the artist behind `artist_link` and drops them from the followed artists list
+#[tokio::main]
+pub async fn unfollow_artist(app: &mut App, artist_link: &str) -> Result<(), ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let Some(artist_id) = artist_id(app, artist_link) else {
+        return Ok(());
+    };
+
+    if let Err(e) = spotify.user_unfollow_artists([artist_id]).await {
+        app.error_text = format!("Error unfollowing artist: {}", e);
+        app.selected_menu = Menu::Error;
+        return Err(e);
+    }
+
+    let uri = context_uri("artist", artist_link);
+    if let Some(row) = app
+        .user_artist_links
+        .iter()
+        .position(|link| context_uri("artist", link) == uri)
+    {
+        app.user_artist_names.remove(row);
+        app.user_artist_links.remove(row);
+
+        if app.user_artist_index > row || app.user_artist_index >= app.user_artist_links.len() {
+            app.user_artist_index = app.user_artist_index.saturating_sub(1);
+        }
+        app.user_artist_state
+            .select(if app.user_artist_links.is_empty() {
+                None
+            } else {
+                Some(app.user_artist_index)
+            });
+    }
+
+    Ok(())
+}
+
+/// Checks whether the artist behind `artist_link` is followed
+#[tokio::main]
+pub async fn check_artist_followed(app: &mut App, artist_link: &str) -> Result<bool, ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let Some(artist_id) = artist_id(app, artist_link) else {
+        return Ok(false);
+    };
+    let followed = spotify.user_artist_check_follow([artist_id]).await?;
+
+    Ok(followed.first().copied().unwrap_or(false))
+}
+
+fn artist_id(app: &mut App, artist_link: &str) -> Option<ArtistId<'static>> {
+    match ArtistId::from_uri(&context_uri("artist", artist_link)) {
+        Ok(artist_id) => Some(artist_id.into_static()),
+        Err(_) => {
+            app.error_text = format!("\"{}\" is not an artist", artist_link);
+            app.selected_menu = Menu::Error;
+            None
+        }
+    }
+}
@@ -1,8 +1,15 @@
+pub mod audiobooks;
+pub mod followed_artists;
 pub mod liked_songs;
 pub mod made_fy;
 pub mod made_fy_tracks;
 pub mod podcast;
+pub mod podcast_episodes;
 pub mod recently_played;
+pub mod saved_albums;
+pub mod saved_episodes;
+pub mod saved_shows;
+pub mod saved_tracks;
 pub mod user_album_tracks;
 pub mod user_albums;
 pub mod user_artist_tracks;
@@ -55,6 +55,7 @@ pub fn process_podcasts(app: &mut App) {
     app.podcast_names.clear();
     app.podcast_links.clear();
     app.podcast_publisher.clear();
+    app.podcast_descriptions.clear();
 
     let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     path.push(".."); // Move up to the root of the Git repository
@@ -88,6 +89,14 @@ pub fn process_podcasts(app: &mut App) {
                     {
                         app.podcast_publisher.push(show_publisher.to_string());
                     }
+                    // Always pushed so descriptions stay aligned with the names
+                    app.podcast_descriptions.push(
+                        show_info
+                            .get("description")
+                            .and_then(Value::as_str)
+                            .unwrap_or_default()
+                            .to_string(),
+                    );
                 }
             }
         }
@@ -0,0 +1,141 @@
+use crate::app::App;
+use crate::enums::Menu;
+use crate::spotify::auth::get_spotify_client;
+use crate::spotify::player::util::context_uri;
+use futures::StreamExt;
+use futures_util::TryStreamExt;
+use rspotify::model::{Market, ShowId, SimplifiedEpisode};
+use rspotify::prelude::BaseClient;
+use rspotify::ClientError;
+use serde_json::{json, Value};
+use std::fs::File;
+use std::io::{BufReader, Write};
+use std::path::PathBuf;
+
+// Shows can have thousands of episodes, only the newest ones are listed
+const MAX_EPISODES: usize = 100;
+
+/// Fetches the newest episodes of the opened show, including where the user stopped listening.
+/// Returns false when the opened link is not a show.
+#[tokio::main]
+pub async fn podcast_episodes(app: &mut App) -> Result<bool, ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let show_uri = context_uri("show", &app.selected_show_link);
+    let show_id = match ShowId::from_uri(&show_uri) {
+        Ok(show_id) => show_id,
+        Err(_) => {
+            app.error_text = format!("Cannot open \"{}\", it is not a show", show_uri);
+            app.selected_menu = Menu::Error;
+            return Ok(false);
+        }
+    };
+
+    let mut episodes = Vec::new();
+    let stream = spotify
+        .get_shows_episodes(show_id, Some(Market::FromToken))
+        .take(MAX_EPISODES)
+        .try_for_each(|item| {
+            episodes.push(item);
+            futures::future::ok(())
+        });
+
+    stream.await?;
+
+    save_episodes_to_json(episodes);
+
+    Ok(true)
+}
+
+/// Saves a vector of episodes to a JSON file in the Spotify cache directory
+fn save_episodes_to_json(episodes: Vec<SimplifiedEpisode>) {
+    let json_data = json!(episodes);
+
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push(".."); // Move up to the root of the Git repository
+    path.push("spoify");
+    path.push("spotify_cache");
+    std::fs::create_dir_all(&path).unwrap();
+    path.push("podcast_episodes.json");
+
+    let mut file = File::create(&path).unwrap();
+    let _ = file.write_all(json_data.to_string().as_bytes());
+}
+
+/// Processes the cached episodes of the opened show and populates the app's data structures
+pub fn process_podcast_episodes(app: &mut App) {
+    app.podcast_episode_names.clear();
+    app.podcast_episode_dates.clear();
+    app.podcast_episode_durations.clear();
+    app.podcast_episode_resume_points.clear();
+    app.podcast_episode_fully_played.clear();
+    app.podcast_episode_links.clear();
+    app.podcast_episode_saved.clear();
+
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push(".."); // Move up to the root of the Git repository
+    path.push("spoify");
+    path.push("spotify_cache");
+    path.push("podcast_episodes.json");
+
+    // A missing or broken cache leaves the table empty
+    let Ok(file) = File::open(&path) else {
+        return;
+    };
+    let Ok(json_data) = serde_json::from_reader::<_, Value>(BufReader::new(file)) else {
+        return;
+    };
+
+    if let Value::Array(episodes) = json_data {
+        for episode in episodes {
+            if let Value::Object(episode_obj) = episode {
+                // Every column is pushed for every episode so the rows stay aligned
+                let link = episode_obj
+                    .get("external_urls")
+                    .and_then(|v| v.get("spotify"))
+                    .and_then(Value::as_str);
+                let Some(link) = link else {
+                    continue;
+                };
+                app.podcast_episode_links.push(link.to_string());
+
+                app.podcast_episode_names.push(
+                    episode_obj
+                        .get("name")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .to_string(),
+                );
+                app.podcast_episode_dates.push(
+                    episode_obj
+                        .get("release_date")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .to_string(),
+                );
+                app.podcast_episode_durations.push(
+                    episode_obj
+                        .get("duration_ms")
+                        .and_then(Value::as_i64)
+                        .unwrap_or(0),
+                );
+
+                let resume_point = episode_obj.get("resume_point");
+                app.podcast_episode_resume_points.push(
+                    resume_point
+                        .and_then(|v| v.get("resume_position_ms"))
+                        .and_then(Value::as_i64)
+                        .unwrap_or(0),
+                );
+                app.podcast_episode_fully_played.push(
+                    resume_point
+                        .and_then(|v| v.get("fully_played"))
+                        .and_then(Value::as_bool)
+                        .unwrap_or(false),
+                );
+                app.podcast_episode_saved.push(false);
+            }
+        }
+    }
+}
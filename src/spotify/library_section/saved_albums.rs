@@ -0,0 +1,138 @@
+use crate::app::App;
+use crate::enums::Menu;
+use crate::spotify::auth::get_spotify_client;
+use crate::spotify::player::util::context_uri;
+use rspotify::clients::{BaseClient, OAuthClient};
+use rspotify::model::AlbumId;
+use rspotify::ClientError;
+
+/// Saves the album behind `album_link` and puts it at the top of the saved albums list
+#[tokio::main]
+pub async fn save_album(app: &mut App, album_link: &str) -> Result<(), ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let Some(album_id) = album_id(app, album_link) else {
+        return Ok(());
+    };
+
+    if let Err(e) = spotify
+        .current_user_saved_albums_add([album_id.clone()])
+        .await
+    {
+        app.error_text = format!("Error saving album: {}", e);
+        app.selected_menu = Menu::Error;
+        return Err(e);
+    }
+
+    let uri = context_uri("album", album_link);
+    if app
+        .user_album_links
+        .iter()
+        .any(|link| context_uri("album", link) == uri)
+    {
+        return Ok(());
+    }
+
+    // Only the new album is fetched, the rest of the list stays as it is
+    let album = spotify.album(album_id, None).await?;
+    app.user_album_names.insert(0, album.name);
+    app.user_album_links.insert(
+        0,
+        album.external_urls.get("spotify").cloned().unwrap_or(uri),
+    );
+    app.user_album_artist_names.insert(
+        0,
+        album
+            .artists
+            .first()
+            .map(|artist| artist.name.clone())
+            .unwrap_or_default(),
+    );
+    app.user_album_image_urls.insert(
+        0,
+        album
+            .images
+            .first()
+            .map(|image| image.url.clone())
+            .unwrap_or_default(),
+    );
+    app.user_album_tracks.insert(0, album.tracks.total as usize);
+
+    // Keep the same album selected now that everything moved down by one
+    if app.user_album_display && app.user_album_links.len() > 1 {
+        app.user_album_index += 1;
+        app.user_album_state.select(Some(app.user_album_index));
+    }
+
+    Ok(())
+}
+
+/// Removes the album behind `album_link` from the saved albums and drops its row from the list
+#[tokio::main]
+pub async fn remove_album(app: &mut App, album_link: &str) -> Result<(), ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let Some(album_id) = album_id(app, album_link) else {
+        return Ok(());
+    };
+
+    if let Err(e) = spotify.current_user_saved_albums_delete([album_id]).await {
+        app.error_text = format!("Error removing album: {}", e);
+        app.selected_menu = Menu::Error;
+        return Err(e);
+    }
+
+    let uri = context_uri("album", album_link);
+    if let Some(row) = app
+        .user_album_links
+        .iter()
+        .position(|link| context_uri("album", link) == uri)
+    {
+        app.user_album_names.remove(row);
+        app.user_album_links.remove(row);
+        app.user_album_artist_names.remove(row);
+        app.user_album_image_urls.remove(row);
+        app.user_album_tracks.remove(row);
+
+        if app.user_album_index > row || app.user_album_index >= app.user_album_links.len() {
+            app.user_album_index = app.user_album_index.saturating_sub(1);
+        }
+        app.user_album_state
+            .select(if app.user_album_links.is_empty() {
+                None
+            } else {
+                Some(app.user_album_index)
+            });
+    }
+
+    Ok(())
+}
+
+/// Checks whether the album behind `album_link` is saved
+#[tokio::main]
+pub async fn check_album_saved(app: &mut App, album_link: &str) -> Result<bool, ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let Some(album_id) = album_id(app, album_link) else {
+        return Ok(false);
+    };
+    let saved = spotify
+        .current_user_saved_albums_contains([album_id])
+        .await?;
+
+    Ok(saved.first().copied().unwrap_or(false))
+}
+
+fn album_id(app: &mut App, album_link: &str) -> Option<AlbumId<'static>> {
+    match AlbumId::from_uri(&context_uri("album", album_link)) {
+        Ok(album_id) => Some(album_id.into_static()),
+        Err(_) => {
+            app.error_text = format!("\"{}\" is not an album", album_link);
+            app.selected_menu = Menu::Error;
+            None
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use crate::app::App;
+use crate::enums::Menu;
+use crate::spotify::auth::get_spotify_client;
+use crate::spotify::player::util::context_uri;
+use rspotify::prelude::BaseClient;
+use rspotify::ClientError;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Write};
+use std::path::PathBuf;
+
+// rspotify has no saved episode endpoints, so these requests go through its raw API helpers.
+// Spotify accepts at most 50 episodes per request.
+const EPISODES_PER_REQUEST: usize = 50;
+
+/// Fetches the episodes the user saved to Your Episodes
+#[tokio::main]
+pub async fn saved_episodes(app: &mut App) -> Result<(), ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let mut episodes = Vec::new();
+    let limit = EPISODES_PER_REQUEST.to_string();
+    loop {
+        let offset = episodes.len().to_string();
+        let query = HashMap::from([("limit", limit.as_str()), ("offset", offset.as_str())]);
+        let response = spotify.api_get("me/episodes", &query).await?;
+        let page: Value = serde_json::from_str(&response)?;
+
+        let items = page
+            .get("items")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+        let item_count = items.len();
+        episodes.extend(items);
+
+        if item_count < EPISODES_PER_REQUEST || page.get("next").is_none_or(Value::is_null) {
+            break;
+        }
+    }
+
+    save_saved_episodes_to_json(episodes);
+
+    Ok(())
+}
+
+/// Saves the fetched Your Episodes items to a JSON file in the Spotify cache directory
+fn save_saved_episodes_to_json(episodes: Vec<Value>) {
+    let json_data = json!(episodes);
+
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push(".."); // Move up to the root of the Git repository
+    path.push("spoify");
+    path.push("spotify_cache");
+    std::fs::create_dir_all(&path).unwrap();
+    path.push("saved_episodes.json");
+
+    let mut file = File::create(&path).unwrap();
+    let _ = file.write_all(json_data.to_string().as_bytes());
+}
+
+/// Fills the episode table with Your Episodes, every row is saved
+pub fn process_saved_episodes(app: &mut App) {
+    app.podcast_episode_names.clear();
+    app.podcast_episode_dates.clear();
+    app.podcast_episode_durations.clear();
+    app.podcast_episode_resume_points.clear();
+    app.podcast_episode_fully_played.clear();
+    app.podcast_episode_links.clear();
+    app.podcast_episode_saved.clear();
+
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push(".."); // Move up to the root of the Git repository
+    path.push("spoify");
+    path.push("spotify_cache");
+    path.push("saved_episodes.json");
+
+    // A missing or broken cache leaves the table empty
+    let Ok(file) = File::open(&path) else {
+        return;
+    };
+    let Ok(json_data) = serde_json::from_reader::<_, Value>(BufReader::new(file)) else {
+        return;
+    };
+
+    if let Value::Array(items) = json_data {
+        for item in items {
+            let Some(episode) = item.get("episode").and_then(Value::as_object) else {
+                continue;
+            };
+            let Some(link) = episode
+                .get("external_urls")
+                .and_then(|v| v.get("spotify"))
+                .and_then(Value::as_str)
+            else {
+                continue;
+            };
+            app.podcast_episode_links.push(link.to_string());
+
+            app.podcast_episode_names.push(
+                episode
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+            );
+            app.podcast_episode_dates.push(
+                episode
+                    .get("release_date")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+            );
+            app.podcast_episode_durations.push(
+                episode
+                    .get("duration_ms")
+                    .and_then(Value::as_i64)
+                    .unwrap_or(0),
+            );
+
+            let resume_point = episode.get("resume_point");
+            app.podcast_episode_resume_points.push(
+                resume_point
+                    .and_then(|v| v.get("resume_position_ms"))
+                    .and_then(Value::as_i64)
+                    .unwrap_or(0),
+            );
+            app.podcast_episode_fully_played.push(
+                resume_point
+                    .and_then(|v| v.get("fully_played"))
+                    .and_then(Value::as_bool)
+                    .unwrap_or(false),
+            );
+            app.podcast_episode_saved.push(true);
+        }
+    }
+}
+
+/// Checks which of the listed episodes are in Your Episodes
+#[tokio::main]
+pub async fn check_saved_episodes(app: &mut App) -> Result<(), ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let mut saved = Vec::new();
+    for links in app.podcast_episode_links.chunks(EPISODES_PER_REQUEST) {
+        let ids = links
+            .iter()
+            .map(|link| episode_id(link))
+            .collect::<Vec<_>>()
+            .join(",");
+        let query = HashMap::from([("ids", ids.as_str())]);
+        let response = spotify.api_get("me/episodes/contains", &query).await?;
+        let contains: Vec<bool> = serde_json::from_str(&response)?;
+        saved.extend(contains);
+    }
+
+    saved.resize(app.podcast_episode_links.len(), false);
+    app.podcast_episode_saved = saved;
+
+    Ok(())
+}
+
+/// Adds the selected episode to Your Episodes
+#[tokio::main]
+pub async fn save_episode(app: &mut App, episode_link: &str) -> Result<(), ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let body = json!({ "ids": [episode_id(episode_link)] });
+    let result = spotify.api_put("me/episodes", &body).await;
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            app.error_text = format!("Error saving episode: {}", e);
+            app.selected_menu = Menu::Error;
+            Err(e)
+        }
+    }
+}
+
+/// Removes the selected episode from Your Episodes
+#[tokio::main]
+pub async fn remove_episode(app: &mut App, episode_link: &str) -> Result<(), ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let body = json!({ "ids": [episode_id(episode_link)] });
+    let result = spotify.api_delete("me/episodes", &body).await;
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            app.error_text = format!("Error removing episode: {}", e);
+            app.selected_menu = Menu::Error;
+            Err(e)
+        }
+    }
+}
+
+// Turns an episode link or uri into the bare id the endpoints expect
+fn episode_id(episode_link: &str) -> String {
+    context_uri("episode", episode_link)
+        .trim_start_matches("spotify:episode:")
+        .to_string()
+}
@@ -0,0 +1,77 @@
+use crate::app::App;
+use crate::enums::Menu;
+use crate::spotify::auth::get_spotify_client;
+use crate::spotify::player::util::context_uri;
+use rspotify::clients::OAuthClient;
+use rspotify::model::ShowId;
+use rspotify::ClientError;
+
+/// Adds the show behind `app.show_link_to_save` to the user's podcasts
+#[tokio::main]
+pub async fn save_show(app: &mut App) -> Result<(), ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let Some(show_id) = show_id_to_save(app) else {
+        return Ok(());
+    };
+    let result = spotify.save_shows([show_id]).await;
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            app.error_text = format!("Error saving show: {}", e);
+            app.selected_menu = Menu::Error;
+            Err(e)
+        }
+    }
+}
+
+/// Removes the show behind `app.show_link_to_save` from the user's podcasts
+#[tokio::main]
+pub async fn remove_show(app: &mut App) -> Result<(), ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let Some(show_id) = show_id_to_save(app) else {
+        return Ok(());
+    };
+    let result = spotify.remove_users_saved_shows([show_id], None).await;
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            app.error_text = format!("Error removing show: {}", e);
+            app.selected_menu = Menu::Error;
+            Err(e)
+        }
+    }
+}
+
+/// Checks whether the opened show is saved, so the show page can say so
+#[tokio::main]
+pub async fn check_selected_show_saved(app: &mut App) -> Result<(), ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let show_uri = context_uri("show", &app.selected_show_link);
+    let Ok(show_id) = ShowId::from_uri(&show_uri) else {
+        return Ok(());
+    };
+    let saved = spotify.check_users_saved_shows([show_id]).await?;
+    app.selected_show_saved = saved.first().copied().unwrap_or(false);
+
+    Ok(())
+}
+
+fn show_id_to_save(app: &mut App) -> Option<ShowId<'static>> {
+    let show_uri = context_uri("show", &app.show_link_to_save);
+    match ShowId::from_uri(&show_uri) {
+        Ok(show_id) => Some(show_id.into_static()),
+        Err(_) => {
+            app.error_text = format!("\"{}\" is not a show", app.show_link_to_save);
+            app.selected_menu = Menu::Error;
+            None
+        }
+    }
+}
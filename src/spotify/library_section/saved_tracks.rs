@@ -0,0 +1,207 @@
+use crate::app::App;
+use crate::enums::Menu;
+use crate::spotify::auth::get_spotify_client;
+use crate::spotify::player::util::playable_id_from_link;
+use rspotify::clients::{BaseClient, OAuthClient};
+use rspotify::model::{PlayableId, TrackId};
+use rspotify::prelude::Id;
+use rspotify::ClientError;
+
+// The contains, save and remove endpoints accept at most 50 ids per request
+const TRACK_BATCH_SIZE: usize = 50;
+
+/// Checks which of `track_links` are liked, only asking about tracks that were not checked before
+#[tokio::main]
+pub async fn check_saved_tracks(app: &mut App, track_links: &[String]) -> Result<(), ClientError> {
+    let mut unknown_ids: Vec<String> = Vec::new();
+    for id in track_links.iter().filter_map(|link| track_id(link)) {
+        if !app.liked_track_ids.contains_key(&id) && !unknown_ids.contains(&id) {
+            unknown_ids.push(id);
+        }
+    }
+    if unknown_ids.is_empty() {
+        return Ok(());
+    }
+
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    for batch in unknown_ids.chunks(TRACK_BATCH_SIZE) {
+        let ids = batch
+            .iter()
+            .filter_map(|id| TrackId::from_id(id.as_str()).ok());
+        let contains = spotify.current_user_saved_tracks_contains(ids).await?;
+        for (id, liked) in batch.iter().zip(contains) {
+            app.liked_track_ids.insert(id.clone(), liked);
+        }
+    }
+
+    Ok(())
+}
+
+/// Marks every track in Liked Songs as liked, they need no check
+pub fn mark_liked_songs(app: &mut App) {
+    for id in app
+        .liked_song_links
+        .iter()
+        .filter_map(|link| track_id(link))
+    {
+        app.liked_track_ids.insert(id, true);
+    }
+}
+
+/// Adds the track to Liked Songs and puts it at the top of the loaded list
+pub fn like_track(app: &mut App, track_link: &str) -> Result<(), ClientError> {
+    like_tracks(app, &[track_link.to_string()]).map(|_| ())
+}
+
+/// Adds the tracks to Liked Songs in batches and puts them at the top of the loaded list,
+/// returns how many were added
+#[tokio::main]
+pub async fn like_tracks(app: &mut App, track_links: &[String]) -> Result<usize, ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let ids = unique_track_ids(track_links);
+    let mut added = 0;
+    // Later batches go below the earlier ones so the tracks keep their order
+    let mut insert_at = 0;
+    for batch in ids.chunks(TRACK_BATCH_SIZE) {
+        let track_ids: Vec<TrackId> = batch
+            .iter()
+            .filter_map(|id| TrackId::from_id(id.as_str()).ok())
+            .collect();
+        if let Err(e) = spotify
+            .current_user_saved_tracks_add(track_ids.clone())
+            .await
+        {
+            app.error_text = format!("Error liking track: {}", e);
+            app.selected_menu = Menu::Error;
+            return Err(e);
+        }
+        for id in batch {
+            app.liked_track_ids.insert(id.clone(), true);
+            app.liked_changed_ids.insert(id.clone());
+        }
+
+        // Only the new tracks are fetched, the rest of Liked Songs stays as it is
+        let tracks = spotify.tracks(track_ids, None).await?;
+        for track in tracks {
+            let row = insert_at;
+            insert_at += 1;
+            app.liked_song_names.insert(row, track.name);
+            app.liked_song_artist_names.insert(
+                row,
+                track
+                    .artists
+                    .first()
+                    .map(|artist| artist.name.clone())
+                    .unwrap_or_default(),
+            );
+            app.liked_song_album_names.insert(row, track.album.name);
+            app.liked_song_duration
+                .insert(row, track.duration.num_milliseconds());
+            app.liked_song_links.insert(
+                row,
+                track
+                    .external_urls
+                    .get("spotify")
+                    .cloned()
+                    .or_else(|| track.id.map(|id| id.url()))
+                    .unwrap_or_default(),
+            );
+
+            // Keep the same row selected now that everything moved down by one
+            if app.liked_song_display && app.liked_song_links.len() > 1 {
+                app.liked_songs_index += 1;
+                app.liked_songs_state.select(Some(app.liked_songs_index));
+            }
+        }
+        added += batch.len();
+    }
+
+    Ok(added)
+}
+
+/// Removes the track from Liked Songs and drops its row from the loaded list
+pub fn unlike_track(app: &mut App, track_link: &str) -> Result<(), ClientError> {
+    unlike_tracks(app, &[track_link.to_string()]).map(|_| ())
+}
+
+/// Removes the tracks from Liked Songs in batches and drops their rows from the loaded list,
+/// returns how many were removed
+#[tokio::main]
+pub async fn unlike_tracks(app: &mut App, track_links: &[String]) -> Result<usize, ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let ids = unique_track_ids(track_links);
+    let mut removed = 0;
+    for batch in ids.chunks(TRACK_BATCH_SIZE) {
+        let track_ids = batch
+            .iter()
+            .filter_map(|id| TrackId::from_id(id.as_str()).ok());
+        if let Err(e) = spotify.current_user_saved_tracks_delete(track_ids).await {
+            app.error_text = format!("Error unliking track: {}", e);
+            app.selected_menu = Menu::Error;
+            return Err(e);
+        }
+
+        for id in batch {
+            app.liked_track_ids.insert(id.clone(), false);
+            app.liked_changed_ids.insert(id.clone());
+            remove_liked_song_row(app, id);
+        }
+        removed += batch.len();
+    }
+
+    Ok(removed)
+}
+
+fn remove_liked_song_row(app: &mut App, id: &str) {
+    let Some(row) = app
+        .liked_song_links
+        .iter()
+        .position(|link| track_id_matches(link, id))
+    else {
+        return;
+    };
+    app.liked_song_names.remove(row);
+    app.liked_song_artist_names.remove(row);
+    app.liked_song_album_names.remove(row);
+    app.liked_song_duration.remove(row);
+    app.liked_song_links.remove(row);
+
+    if app.liked_songs_index > row || app.liked_songs_index >= app.liked_song_links.len() {
+        app.liked_songs_index = app.liked_songs_index.saturating_sub(1);
+    }
+    app.liked_songs_state
+        .select(if app.liked_song_links.is_empty() {
+            None
+        } else {
+            Some(app.liked_songs_index)
+        });
+}
+
+// Track ids behind the links without repeats, episodes are left out
+fn unique_track_ids(track_links: &[String]) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::new();
+    for id in track_links.iter().filter_map(|link| track_id(link)) {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    ids
+}
+
+/// Returns the bare track id behind a track link, or `None` for episodes and other items
+pub fn track_id(track_link: &str) -> Option<String> {
+    match playable_id_from_link(track_link)? {
+        PlayableId::Track(track_id) => Some(track_id.id().to_string()),
+        PlayableId::Episode(_) => None,
+    }
+}
+
+fn track_id_matches(track_link: &str, id: &str) -> bool {
+    track_id(track_link).as_deref() == Some(id)
+}
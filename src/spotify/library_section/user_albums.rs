@@ -56,6 +56,7 @@ pub fn process_user_albums(app: &mut App) {
     app.user_album_links.clear();
     app.user_album_tracks.clear();
     app.user_album_artist_names.clear();
+    app.user_album_image_urls.clear();
 
     let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     path.push(".."); // Move up to the root of the Git repository
@@ -95,6 +96,15 @@ pub fn process_user_albums(app: &mut App) {
                             }
                         }
                     }
+                    let image_url = album_info
+                        .get("images")
+                        .and_then(Value::as_array)
+                        .and_then(|images| images.first())
+                        .and_then(|image| image.get("url"))
+                        .and_then(Value::as_str)
+                        .unwrap_or_default();
+                    app.user_album_image_urls.push(image_url.to_string());
+
                     if let Some(tracks) = album_info.get("tracks").and_then(Value::as_object) {
                         if let Some(total_tracks) = tracks.get("total").and_then(Value::as_u64) {
                             app.user_album_tracks.push(total_tracks as usize);
@@ -1,8 +1,13 @@
+pub mod album_art;
 pub mod auth;
+pub mod export;
+pub mod http_client;
+pub mod import;
 pub mod library_section;
 pub mod new_release_section;
 pub mod player;
 pub mod playlist_control;
 pub mod search;
+pub mod snapshot;
 pub mod user_playlist;
 pub mod user_stats;
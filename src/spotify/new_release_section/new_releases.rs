@@ -65,6 +65,7 @@ fn save_new_releases_to_json(app: &mut App, items: Vec<SimplifiedAlbum>) {
 pub fn process_new_releases(app: &mut App) {
     app.new_release_artist.clear();
     app.new_release_name.clear();
+    app.new_release_image_urls.clear();
 
     let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     path.push(".."); // Move up to the root of the Git repository
@@ -97,6 +98,14 @@ pub fn process_new_releases(app: &mut App) {
                 if let Some(id) = album_obj.get("id").and_then(Value::as_str) {
                     app.new_release_album_links.push(id.to_string());
                 }
+                let image_url = album_obj
+                    .get("images")
+                    .and_then(Value::as_array)
+                    .and_then(|images| images.first())
+                    .and_then(|image| image.get("url"))
+                    .and_then(Value::as_str)
+                    .unwrap_or_default();
+                app.new_release_image_urls.push(image_url.to_string());
                 if let Some(name) = album_obj.get("name") {
                     if let Value::String(album_name) = name {
                         app.new_release_name.push(album_name.clone());
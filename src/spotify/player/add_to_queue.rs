@@ -0,0 +1,38 @@
+use crate::app::App;
+use crate::enums::Menu;
+use crate::spotify::auth::get_spotify_client;
+use crate::spotify::player::util::playable_id_from_link;
+use rspotify::clients::OAuthClient;
+use rspotify::ClientError;
+
+// Main function to add the selected track or episode to the end of the playback queue
+#[tokio::main]
+pub async fn add_to_queue(app: &mut App) -> Result<(), ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let playable_id = match playable_id_from_link(&app.selected_link_for_queue) {
+        Some(playable_id) => playable_id,
+        None => {
+            app.error_text = format!(
+                "Cannot add \"{}\" to the queue, it is not a track or an episode",
+                app.selected_link_for_queue
+            );
+            app.selected_menu = Menu::Error;
+            return Ok(());
+        }
+    };
+
+    let device_id: Option<&str> = app.current_device_id.as_deref().filter(|id| !id.is_empty());
+
+    let result = spotify.add_item_to_queue(playable_id, device_id).await;
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            app.error_text = format!("Error adding to the queue: {}", e);
+            app.selected_menu = Menu::Error;
+            Err(e)
+        }
+    }
+}
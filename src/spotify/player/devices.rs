@@ -0,0 +1,121 @@
+use crate::app::App;
+use crate::spotify::auth::get_spotify_client;
+use crate::spotify::player::transfer_playback::transfer_playback;
+use crate::structs::Settings;
+use rspotify::model::Device;
+use rspotify::prelude::OAuthClient;
+use rspotify::ClientError;
+use serde_json::{json, Value};
+use std::fs::File;
+use std::io::{BufReader, Write};
+use std::path::PathBuf;
+
+// Main function to fetch the devices the user can play on
+#[tokio::main]
+pub async fn devices(app: &mut App) -> Result<(), ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let devices: Vec<Device> = spotify.device().await?;
+
+    save_devices_to_json(devices);
+
+    Ok(())
+}
+
+// Function to save the device data to a JSON file
+fn save_devices_to_json(devices: Vec<Device>) {
+    let json_data: Value = json!(devices);
+    let mut path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push(".."); // Move up to the root of the Git repository
+    path.push("spoify");
+    path.push("spotify_cache");
+    std::fs::create_dir_all(&path).unwrap();
+    path.push("devices.json");
+    let mut file: File = File::create(&path).unwrap();
+    let _ = file.write_all(json_data.to_string().as_bytes());
+}
+
+// Function to process the devices and update the application state
+pub fn process_devices(app: &mut App) {
+    app.device_names.clear();
+    app.device_types.clear();
+    app.device_volumes.clear();
+    app.device_ids.clear();
+    app.device_active.clear();
+
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push(".."); // Move up to the root of the Git repository
+    path.push("spoify");
+    path.push("spotify_cache");
+    path.push("devices.json");
+
+    let file = match File::open(&path) {
+        Ok(file) => file,
+        Err(_) => return,
+    };
+    let reader = BufReader::new(file);
+    let devices: Value = match serde_json::from_reader(reader) {
+        Ok(devices) => devices,
+        Err(_) => return,
+    };
+
+    if let Some(devices) = devices.as_array() {
+        for device in devices {
+            // Restricted devices have no id and cannot be controlled through the API
+            let device_id = match device.get("id").and_then(Value::as_str) {
+                Some(device_id) => device_id,
+                None => continue,
+            };
+
+            app.device_ids.push(device_id.to_string());
+            app.device_names.push(
+                device
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+            );
+            app.device_types.push(
+                device
+                    .get("type")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+            );
+            app.device_volumes.push(
+                device
+                    .get("volume_percent")
+                    .and_then(Value::as_u64)
+                    .map(|volume| format!("{}%", volume))
+                    .unwrap_or_else(|| "-".to_string()),
+            );
+            app.device_active.push(
+                device
+                    .get("is_active")
+                    .and_then(Value::as_bool)
+                    .unwrap_or(false),
+            );
+        }
+    }
+}
+
+/// Moves playback to the preferred device from settings.yml, returns whether it was found
+pub fn activate_preferred_device(app: &mut App, settings: &Settings) -> Result<bool, ClientError> {
+    devices(app)?;
+    process_devices(app);
+
+    let preferred_index = app
+        .device_names
+        .iter()
+        .position(|name| name.eq_ignore_ascii_case(&settings.preferred_device));
+
+    match preferred_index {
+        Some(index) => {
+            app.selected_device_id = app.device_ids[index].clone();
+            transfer_playback(app, false)?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
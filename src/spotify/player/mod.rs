@@ -1,11 +1,17 @@
+pub mod add_to_queue;
+pub mod devices;
 pub mod next_track;
 pub mod pause_playback;
 pub mod play_playback;
 pub mod player;
 pub mod previous_track;
+pub mod queue;
 pub mod repeat;
+pub mod seek;
+pub mod set_volume;
 pub mod shuffle;
 pub mod start_playback;
+pub mod transfer_playback;
 pub mod util;
 pub mod volume_decrease;
 pub mod volume_increase;
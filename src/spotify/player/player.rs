@@ -12,6 +12,7 @@ use serde_json::{json, Value};
 use std::fs::File;
 use std::io::{BufReader, Write};
 use std::path::PathBuf;
+use std::time::Instant;
 
 // Main function to fetch the currently playing track information
 #[tokio::main]
@@ -53,26 +54,8 @@ pub async fn currently_playing(app: &mut App) -> Result<(), ClientError> {
             currently_playing_type: CurrentlyPlayingType::Unknown,
             actions: Actions::default(),
         }),
-        Err(_err) => CurrentPlaybackContext {
-            device: Device {
-                id: None,
-                is_active: false,
-                is_private_session: false,
-                is_restricted: false,
-                name: "Device Offline".to_string(),
-                _type: DeviceType::Computer,
-                volume_percent: Some(0),
-            },
-            repeat_state: RepeatState::Off,
-            shuffle_state: false,
-            context: None,
-            timestamp: DateTime::default(),
-            progress: None,
-            is_playing: false,
-            item: None,
-            currently_playing_type: CurrentlyPlayingType::Unknown,
-            actions: Actions::default(),
-        },
+        // Let the poller decide how long to wait, the last known state stays on screen
+        Err(e) => return Err(e),
     };
 
     save_data_to_json(app, currently_playing_tracks);
@@ -88,36 +71,50 @@ fn save_data_to_json(app: &mut App, items: CurrentPlaybackContext) {
     path.push("spoify");
     path.push("spotify_cache");
     std::fs::create_dir_all(&path).unwrap();
+
+    // The UI thread reads the file while the poller writes it, so it is written aside and moved
+    // into place, a reader sees either the old or the new file and never half of one
+    let temp_path = path.join("currently_playing.json.tmp");
     path.push("currently_playing.json");
-    let mut file: File = File::create(&path).unwrap();
-    let _ = file.write_all(json_data.to_string().as_bytes());
+    let Ok(mut file) = File::create(&temp_path) else {
+        return;
+    };
+    if file.write_all(json_data.to_string().as_bytes()).is_ok() {
+        let _ = std::fs::rename(&temp_path, &path);
+    }
 }
 
 // Function to process the currently playing track information and update the application state
 pub fn process_currently_playing(app: &mut App, settings: &mut Settings) {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push(".."); // Move up to the root of the Git repository
+    path.push("spoify");
+    path.push("spotify_cache");
+    path.push("currently_playing.json");
+
+    // Keep showing the last state when there is nothing readable yet
+    let Ok(file) = File::open(&path) else {
+        return;
+    };
+    let Ok(json_data) = serde_json::from_reader::<_, Value>(BufReader::new(file)) else {
+        return;
+    };
+
     // Clear any existing currently playing data in the app before processing new data
     app.currrent_timestamp = 0.0;
     app.ending_timestamp = 0.0;
     app.currently_playing_artist.clear();
     app.current_playing_name.clear();
     app.current_playing_album.clear();
+    app.current_playing_album_uri.clear();
+    app.current_playing_artist_uri.clear();
     app.current_device_name.clear();
     app.current_device_volume.clear();
     app.current_device_id = Some("".to_string());
+    app.current_playing_image_url.clear();
 
     let mut repeat_state = String::new();
 
-    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-    path.push(".."); // Move up to the root of the Git repository
-    path.push("spoify");
-    path.push("spotify_cache");
-    path.push("currently_playing.json");
-
-    let file = File::open(&path).expect("Failed to open currently_playing.json");
-    let reader = BufReader::new(file);
-    let json_data: Value =
-        serde_json::from_reader(reader).expect("Failed to parse currently_playing.json");
-
     // Extract relevant information from the JSON data and update the application state
     if let Value::Object(currently_playing) = json_data {
         if let Some(currently_playing_type) = currently_playing
@@ -172,6 +169,9 @@ pub fn process_currently_playing(app: &mut App, settings: &mut Settings) {
                     if let Some(album_name) = album.get("name").and_then(Value::as_str) {
                         app.current_playing_album = album_name.to_string();
                     }
+                    if let Some(album_uri) = album.get("uri").and_then(Value::as_str) {
+                        app.current_playing_album_uri = album_uri.to_string();
+                    }
                 }
 
                 if let Some(artist_section) = item.get("artists").and_then(Value::as_array) {
@@ -180,10 +180,28 @@ pub fn process_currently_playing(app: &mut App, settings: &mut Settings) {
                         {
                             app.currently_playing_artist = artist_name.to_string();
                         }
+                        if let Some(artist_uri) = first_artist.get("uri").and_then(Value::as_str) {
+                            app.current_playing_artist_uri = artist_uri.to_string();
+                        }
                     }
                 }
             }
 
+            // Episodes carry their own cover, tracks use the album cover
+            let images = if app.currently_playing_media_type == "episode" {
+                item.get("images")
+            } else {
+                item.get("album").and_then(|album| album.get("images"))
+            };
+            if let Some(image_url) = images
+                .and_then(Value::as_array)
+                .and_then(|images| images.first())
+                .and_then(|image| image.get("url"))
+                .and_then(Value::as_str)
+            {
+                app.current_playing_image_url = image_url.to_string();
+            }
+
             if let Some(name) = item.get("name").and_then(Value::as_str) {
                 app.current_playing_name = name.to_string();
             }
@@ -211,4 +229,22 @@ pub fn process_currently_playing(app: &mut App, settings: &mut Settings) {
     } else {
         app.repeat_status = "Off".to_string();
     }
+
+    sync_progress(app);
+}
+
+/// Remembers the current position as the point the progress is interpolated from
+pub fn sync_progress(app: &mut App) {
+    app.progress_synced_ms = app.currrent_timestamp;
+    app.progress_synced_at = Instant::now();
+}
+
+/// Advances the progress from the last known position while playing, so the bar moves smoothly between polls
+pub fn interpolate_progress(app: &mut App) {
+    if !app.is_playing || app.ending_timestamp <= 0.0 {
+        return;
+    }
+
+    let elapsed_ms = app.progress_synced_at.elapsed().as_secs_f64() * 1000.0;
+    app.currrent_timestamp = (app.progress_synced_ms + elapsed_ms).min(app.ending_timestamp);
 }
@@ -0,0 +1,119 @@
+use crate::app::App;
+use crate::spotify::auth::get_spotify_client;
+use rspotify::model::CurrentUserQueue;
+use rspotify::prelude::OAuthClient;
+use rspotify::ClientError;
+use serde_json::{json, Value};
+use std::fs::File;
+use std::io::{BufReader, Write};
+use std::path::PathBuf;
+
+// Main function to fetch the user's playback queue
+#[tokio::main]
+pub async fn user_queue(app: &mut App) -> Result<(), ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let queue: CurrentUserQueue = spotify.current_user_queue().await?;
+
+    save_queue_to_json(queue);
+
+    Ok(())
+}
+
+// Function to save the queue data to a JSON file
+fn save_queue_to_json(queue: CurrentUserQueue) {
+    let json_data: Value = json!(queue);
+    let mut path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push(".."); // Move up to the root of the Git repository
+    path.push("spoify");
+    path.push("spotify_cache");
+    std::fs::create_dir_all(&path).unwrap();
+    path.push("queue.json");
+    let mut file: File = File::create(&path).unwrap();
+    let _ = file.write_all(json_data.to_string().as_bytes());
+}
+
+// Function to process the queue and update the application state, the playing item comes first
+pub fn process_queue(app: &mut App) {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push(".."); // Move up to the root of the Git repository
+    path.push("spoify");
+    path.push("spotify_cache");
+    path.push("queue.json");
+
+    let file = match File::open(&path) {
+        Ok(file) => file,
+        Err(_) => return,
+    };
+    let reader = BufReader::new(file);
+    let queue: Value = match serde_json::from_reader(reader) {
+        Ok(queue) => queue,
+        Err(_) => return,
+    };
+
+    app.queue_names.clear();
+    app.queue_artist_names.clear();
+    app.queue_album_names.clear();
+    app.queue_durations.clear();
+    app.queue_links.clear();
+
+    let currently_playing = queue
+        .get("currently_playing")
+        .filter(|item| !item.is_null());
+    app.queue_has_current = currently_playing.is_some();
+
+    let upcoming = queue
+        .get("queue")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+
+    for item in currently_playing.into_iter().chain(upcoming.iter()) {
+        app.queue_names.push(
+            item.get("name")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+        );
+
+        // Episodes have a show instead of artists and an album
+        if let Some(show) = item.get("show") {
+            let show_name = show.get("name").and_then(Value::as_str).unwrap_or_default();
+            app.queue_artist_names.push(show_name.to_string());
+            app.queue_album_names.push("Episode".to_string());
+        } else {
+            let artists = item
+                .get("artists")
+                .and_then(Value::as_array)
+                .map(|artists| {
+                    artists
+                        .iter()
+                        .filter_map(|artist| artist.get("name").and_then(Value::as_str))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .unwrap_or_default();
+            app.queue_artist_names.push(artists);
+            app.queue_album_names.push(
+                item.get("album")
+                    .and_then(|album| album.get("name"))
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+            );
+        }
+
+        app.queue_durations.push(
+            item.get("duration_ms")
+                .and_then(Value::as_i64)
+                .unwrap_or_default(),
+        );
+        app.queue_links.push(
+            item.get("uri")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use crate::app::App;
+use crate::spotify::auth::get_spotify_client;
+use chrono::Duration;
+use rspotify::clients::OAuthClient;
+use rspotify::ClientError;
+
+// Main function to move the playback position of the current item
+#[tokio::main]
+pub async fn seek(app: &mut App, position_ms: i64) -> Result<(), ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    // Get the device ID from the application state (if available)
+    let device_id: Option<&str> = app.current_device_id.as_deref().filter(|id| !id.is_empty());
+
+    let result = spotify.seek_track(Duration::milliseconds(position_ms), device_id);
+
+    result.await?;
+
+    Ok(())
+}
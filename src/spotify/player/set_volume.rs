@@ -0,0 +1,102 @@
+use crate::app::App;
+use crate::enums::Menu;
+use crate::spotify::auth::get_spotify_client;
+use crate::spotify::player::util::playback_device_id;
+use crate::structs::Settings;
+use rspotify::clients::OAuthClient;
+use rspotify::ClientError;
+use serde_json::{json, Map, Value};
+use std::fs::File;
+use std::io::{BufReader, Write};
+use std::path::PathBuf;
+
+// Main function to set the volume of the current device, values above 100 are clamped
+#[tokio::main]
+pub async fn set_volume(
+    app: &mut App,
+    settings: &mut Settings,
+    volume: u8,
+) -> Result<(), ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let volume = volume.min(100);
+    let result = spotify.volume(volume, playback_device_id(app)).await;
+
+    match result {
+        Ok(_) => {
+            // Show the new level right away instead of waiting for the next poll
+            settings.volume_percent = volume;
+            app.current_device_volume = volume.to_string();
+            Ok(())
+        }
+        Err(e) => {
+            app.error_text = format!("Error setting the volume: {}", e);
+            app.selected_menu = Menu::Error;
+            Err(e)
+        }
+    }
+}
+
+/// Sets the volume the user chose and remembers it for the current device
+pub fn set_device_volume(
+    app: &mut App,
+    settings: &mut Settings,
+    volume: u8,
+) -> Result<(), ClientError> {
+    set_volume(app, settings, volume)?;
+    app.volume_before_mute = None;
+    remember_device_volume(&app.current_device_name, settings.volume_percent);
+    Ok(())
+}
+
+/// Restores the volume last chosen on the current device, called when playback moves to another device
+pub fn apply_remembered_volume(app: &mut App, settings: &mut Settings) {
+    if let Some(volume) = remembered_device_volume(&app.current_device_name) {
+        if volume != settings.volume_percent {
+            let _ = set_volume(app, settings, volume);
+        }
+    }
+}
+
+fn device_volumes_path() -> PathBuf {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push(".."); // Move up to the root of the Git repository
+    path.push("spoify");
+    path.push("spotify_cache");
+    path.push("device_volumes.json");
+    path
+}
+
+fn load_device_volumes() -> Map<String, Value> {
+    File::open(device_volumes_path())
+        .ok()
+        .and_then(|file| serde_json::from_reader(BufReader::new(file)).ok())
+        .and_then(|json_data: Value| json_data.as_object().cloned())
+        .unwrap_or_default()
+}
+
+fn remembered_device_volume(device_name: &str) -> Option<u8> {
+    load_device_volumes()
+        .get(device_name)
+        .and_then(Value::as_u64)
+        .map(|volume| volume.min(100) as u8)
+}
+
+// Volumes are stored by device name since device IDs can change between sessions
+fn remember_device_volume(device_name: &str, volume: u8) {
+    if device_name.is_empty() {
+        return;
+    }
+
+    let mut device_volumes = load_device_volumes();
+    device_volumes.insert(device_name.to_string(), json!(volume));
+
+    let path = device_volumes_path();
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    if let Ok(mut file) = File::create(&path) {
+        let _ = file.write_all(Value::Object(device_volumes).to_string().as_bytes());
+    }
+}
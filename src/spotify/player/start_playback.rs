@@ -1,38 +1,95 @@
 use crate::app::App;
+use crate::enums::Menu;
 use crate::spotify::auth::get_spotify_client;
+use crate::spotify::player::util::{
+    play_context_id_from_uri, playable_id_from_link, playback_device_id,
+};
 use chrono::Duration;
-use regex::Regex;
-use rspotify::clients::OAuthClient;
-use rspotify::model::{PlayableId, TrackId};
+use rspotify::clients::{BaseClient, OAuthClient};
+use rspotify::model::{Offset, PlayableId};
+use rspotify::prelude::Id;
 use rspotify::ClientError;
+use serde_json::json;
 
+/// Plays only the selected track, playback stops after it unless something is queued
 #[tokio::main]
 pub async fn start_playback(app: &mut App) -> Result<(), ClientError> {
     let spotify = get_spotify_client(app).await?;
-    let device_id;
-    let track_uri;
+    let device_id = playback_device_id(app);
 
-    if app.current_device_id == Some("".to_string()) {
-        device_id = app.device_id_after_pause.as_deref();
-    } else {
-        device_id = app.current_device_id.as_deref();
-    }
-
-    if app.is_only_id {
-        let track_id = app.selected_link_for_playback.as_str();
-        track_uri = format!("spotify:track:{}", track_id);
-    } else {
-        let track_url = app.selected_link_for_playback.as_str();
-        let re = Regex::new(r"/track/(.+)").unwrap();
-        let captures = re.captures(track_url).unwrap();
-        let track_id = captures.get(1).unwrap().as_str();
-        track_uri = format!("spotify:track:{}", track_id);
-    }
+    let playable_id = match playable_id_from_link(&app.selected_link_for_playback) {
+        Some(playable_id) => playable_id,
+        None => {
+            unplayable_link_error(app);
+            return Ok(());
+        }
+    };
 
-    let playable_id = PlayableId::Track(TrackId::from_uri(&track_uri).unwrap());
     let position = Duration::milliseconds(0);
     let result = spotify.start_uris_playback(vec![playable_id], device_id, None, Some(position));
     result.await?;
 
     Ok(())
 }
+
+/// Plays the selected track from inside its album or playlist so the rest of the list keeps playing.
+/// Liked Songs play in the user's collection, other lists without a playable context (artist tracks,
+/// search results) are played as the list of uris they were given.
+#[tokio::main]
+pub async fn start_playback_in_context(app: &mut App) -> Result<(), ClientError> {
+    let spotify = get_spotify_client(app).await?;
+    // The start position only applies to this playback
+    let position = Some(Duration::milliseconds(std::mem::take(
+        &mut app.playback_start_position_ms,
+    )));
+    let device_id = playback_device_id(app);
+
+    let track_uri = match playable_id_from_link(&app.selected_link_for_playback) {
+        Some(playable_id) => playable_id.uri(),
+        None => {
+            unplayable_link_error(app);
+            return Ok(());
+        }
+    };
+
+    // rspotify only knows album, artist, playlist and show contexts, so the collection is sent as is
+    if app.playback_context_uri.ends_with(":collection") {
+        let url = match device_id {
+            Some(device_id) => format!("me/player/play?device_id={}", device_id),
+            None => "me/player/play".to_string(),
+        };
+        let payload = json!({
+            "context_uri": app.playback_context_uri,
+            "offset": { "uri": track_uri },
+            "position_ms": position.map(|position| position.num_milliseconds()),
+        });
+        spotify.api_put(&url, &payload).await?;
+        return Ok(());
+    }
+
+    let offset = Some(Offset::Uri(track_uri));
+    if let Some(context_id) = play_context_id_from_uri(&app.playback_context_uri) {
+        spotify
+            .start_context_playback(context_id, device_id, offset, position)
+            .await?;
+    } else {
+        let playable_ids: Vec<PlayableId> = app
+            .playback_context_track_links
+            .iter()
+            .filter_map(|link| playable_id_from_link(link))
+            .collect();
+        spotify
+            .start_uris_playback(playable_ids, device_id, offset, position)
+            .await?;
+    }
+
+    Ok(())
+}
+
+fn unplayable_link_error(app: &mut App) {
+    app.error_text = format!(
+        "Cannot play \"{}\", it is not a track or an episode",
+        app.selected_link_for_playback
+    );
+    app.selected_menu = Menu::Error;
+}
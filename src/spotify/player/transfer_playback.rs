@@ -0,0 +1,29 @@
+use crate::app::App;
+use crate::enums::Menu;
+use crate::spotify::auth::get_spotify_client;
+use rspotify::clients::OAuthClient;
+use rspotify::ClientError;
+
+// Main function to move playback to the selected device
+#[tokio::main]
+pub async fn transfer_playback(app: &mut App, play: bool) -> Result<(), ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let device_id = app.selected_device_id.clone();
+    let result = spotify.transfer_playback(&device_id, Some(play)).await;
+
+    match result {
+        Ok(_) => {
+            // Later play/pause calls target the new device even before the next poll
+            app.current_device_id = Some(device_id.clone());
+            app.device_id_after_pause = Some(device_id);
+            Ok(())
+        }
+        Err(e) => {
+            app.error_text = format!("Error transferring playback: {}", e);
+            app.selected_menu = Menu::Error;
+            Err(e)
+        }
+    }
+}
@@ -1,4 +1,10 @@
+use crate::app::App;
 use chrono::TimeDelta;
+use rspotify::http::HttpError;
+use rspotify::model::{
+    AlbumId, ArtistId, EpisodeId, PlayContextId, PlayableId, PlaylistId, ShowId, TrackId,
+};
+use rspotify::ClientError;
 use std::time::Duration;
 
 /// Converts a floating-point timestamp to a `chrono::TimeDelta`
@@ -15,3 +21,88 @@ pub fn f64_to_duration(timestamp: f64) -> Result<TimeDelta, chrono::OutOfRangeEr
 
     Ok(time_delta)
 }
+
+/// Turns a track or episode link into a playable id
+///
+/// The app stores open.spotify.com urls for most lists, bare track ids for searched tracks,
+/// and spotify uris for a few endpoints, so all three forms are accepted.
+pub fn playable_id_from_link(link: &str) -> Option<PlayableId<'static>> {
+    let link = link.split('?').next().unwrap_or_default();
+
+    let (kind, id) = if let Some(uri) = link.strip_prefix("spotify:") {
+        uri.split_once(':')?
+    } else if let Some((_, id)) = link.split_once("/episode/") {
+        ("episode", id)
+    } else if let Some((_, id)) = link.split_once("/track/") {
+        ("track", id)
+    } else {
+        ("track", link)
+    };
+
+    match kind {
+        "episode" => EpisodeId::from_id(id.to_string())
+            .ok()
+            .map(PlayableId::Episode),
+        "track" => TrackId::from_id(id.to_string()).ok().map(PlayableId::Track),
+        _ => None,
+    }
+}
+
+/// Builds a `spotify:<kind>:<id>` uri from an open.spotify.com url, a uri or a bare id of that kind
+pub fn context_uri(kind: &str, link: &str) -> String {
+    let link = link.split('?').next().unwrap_or_default();
+
+    if link.starts_with("spotify:") {
+        link.to_string()
+    } else if let Some((_, id)) = link.split_once(&format!("/{}/", kind)) {
+        format!("spotify:{}:{}", kind, id)
+    } else {
+        format!("spotify:{}:{}", kind, link)
+    }
+}
+
+/// Parses an album, playlist, artist or show uri into a context playback can start from
+pub fn play_context_id_from_uri(uri: &str) -> Option<PlayContextId<'static>> {
+    let (kind, id) = uri.strip_prefix("spotify:")?.split_once(':')?;
+    let id = id.to_string();
+
+    match kind {
+        "album" => AlbumId::from_id(id).ok().map(PlayContextId::Album),
+        "playlist" => PlaylistId::from_id(id).ok().map(PlayContextId::Playlist),
+        "artist" => ArtistId::from_id(id).ok().map(PlayContextId::Artist),
+        "show" => ShowId::from_id(id).ok().map(PlayContextId::Show),
+        _ => None,
+    }
+}
+
+/// Returns the device playback commands target, falling back to the device playback was paused on when no device is active
+pub fn playback_device_id(app: &App) -> Option<&str> {
+    if app.current_device_id == Some("".to_string()) {
+        app.device_id_after_pause.as_deref()
+    } else {
+        app.current_device_id.as_deref()
+    }
+}
+
+/// Returns how long Spotify asked to wait when a request was rate limited (HTTP 429)
+pub fn rate_limit_retry_after(error: &ClientError) -> Option<Duration> {
+    let ClientError::Http(http_error) = error else {
+        return None;
+    };
+    let HttpError::StatusCode(response) = http_error.as_ref() else {
+        return None;
+    };
+    if response.status().as_u16() != 429 {
+        return None;
+    }
+
+    // The header is in seconds, wait a little when it is missing
+    let seconds = response
+        .headers()
+        .get("retry-after")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .unwrap_or(5);
+
+    Some(Duration::from_secs(seconds.max(1)))
+}
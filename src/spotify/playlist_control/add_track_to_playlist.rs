@@ -1,50 +1,49 @@
 use crate::app::App;
-use crate::enums::Menu;
+use crate::enums::{InsertPosition, Menu};
 use crate::spotify::auth::get_spotify_client;
-use regex::Regex;
+use crate::spotify::player::util::{context_uri, playable_id_from_link};
 use rspotify::clients::OAuthClient;
-use rspotify::model::{PlayableId, PlaylistId, TrackId};
+use rspotify::model::{PlayableId, PlaylistId};
 use rspotify::ClientError;
 
-// Main function to add a track to a playlist
+/// Spotify accepts at most this many items per request
+const PLAYLIST_ADD_BATCH_SIZE: usize = 100;
+
+// Main function to add the tracks in `track_added_to_playlist_links` to a playlist
 #[tokio::main]
 pub async fn add_track_to_playlist(app: &mut App) -> Result<(), ClientError> {
     // Get a Spotify client using an existing access token (if available).
     let spotify = get_spotify_client(app).await?;
 
-    let playlist_url = app.playlist_link_for_track_addition.as_str();
-    let re_playlist = Regex::new(r"/playlist/(.+)").unwrap();
-    let captures = re_playlist.captures(playlist_url).unwrap();
-    let playlist_uri = captures.get(1).unwrap().as_str();
-    let playlist_id = PlaylistId::from_id(playlist_uri).unwrap();
-    let mut track_id: TrackId = TrackId::from_id("").unwrap();
-    let _ = track_id;
-
-    if app.is_in_track {
-        let track_url = app.track_added_to_playlist_link.as_str();
-        track_id = TrackId::from_id(track_url).unwrap();
-    } else {
-        let track_url = app.track_added_to_playlist_link.as_str();
-        let re_track = Regex::new(r"/track/(.+)").unwrap();
-        let captures = re_track.captures(track_url).unwrap();
-        let track_uri = captures.get(1).unwrap().as_str();
-        track_id = TrackId::from_id(track_uri).unwrap();
-    }
+    let playlist_uri = context_uri("playlist", &app.playlist_link_for_track_addition);
+    let Ok(playlist_id) = PlaylistId::from_uri(&playlist_uri) else {
+        return Ok(());
+    };
+    let track_ids: Vec<PlayableId> = app
+        .track_added_to_playlist_links
+        .iter()
+        .filter_map(|link| playable_id_from_link(link))
+        .collect();
 
-    let position = Some(0);
+    let mut position = match app.playlist_insert_position {
+        InsertPosition::End => None,
+        InsertPosition::Top => Some(0),
+        InsertPosition::At(position) => Some(position),
+    };
 
-    let track_ids = [PlayableId::Track(track_id.clone())].into_iter();
+    for batch in track_ids.chunks(PLAYLIST_ADD_BATCH_SIZE) {
+        let result = spotify
+            .playlist_add_items(playlist_id.clone(), batch.iter().cloned(), position)
+            .await;
 
-    let result = spotify
-        .playlist_add_items(playlist_id, track_ids, position)
-        .await;
-
-    match result {
-        Ok(_) => Ok(()),
-        Err(e) => {
+        if let Err(e) = result {
             app.error_text = format!("Error adding track to the playlist: {}", e);
             app.selected_menu = Menu::Error;
-            Err(e)
+            return Err(e);
         }
+        // The next batch goes right after this one
+        position = position.map(|position| position + batch.len() as u32);
     }
+
+    Ok(())
 }
@@ -1,3 +1,6 @@
 pub mod add_track_to_playlist;
+pub mod playlist_details;
 pub mod playlist_follow;
+pub mod playlist_tracks;
 pub mod playlist_unfollow;
+pub mod source_tracks;
@@ -0,0 +1,105 @@
+use crate::app::App;
+use crate::enums::Menu;
+use crate::spotify::auth::get_spotify_client;
+use crate::spotify::player::util::context_uri;
+use rspotify::clients::{BaseClient, OAuthClient};
+use rspotify::model::{PlaylistId, UserId};
+use rspotify::prelude::Id;
+use rspotify::ClientError;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Changes to apply to a playlist, fields left as `None` stay as they are
+#[derive(Default)]
+pub struct PlaylistChanges<'a> {
+    pub name: Option<&'a str>,
+    pub description: Option<&'a str>,
+    pub public: Option<bool>,
+    pub collaborative: Option<bool>,
+}
+
+/// Creates an empty playlist called `name` for the current user and returns its URI
+#[tokio::main]
+pub async fn create_playlist(app: &mut App, name: &str) -> Result<String, ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let user_id = match UserId::from_id(app.user_id.clone()) {
+        Ok(user_id) => user_id,
+        Err(_) => {
+            app.error_text = "Could not tell which account to create the playlist in".to_string();
+            app.selected_menu = Menu::Error;
+            return Ok(String::new());
+        }
+    };
+    let result = spotify
+        .user_playlist_create(user_id, name, None, None, None)
+        .await;
+
+    match result {
+        Ok(playlist) => Ok(playlist.id.uri()),
+        Err(e) => {
+            app.error_text = format!("Error creating playlist: {}", e);
+            app.selected_menu = Menu::Error;
+            Err(e)
+        }
+    }
+}
+
+/// Applies `changes` to the playlist behind `playlist_link`
+#[tokio::main]
+pub async fn change_playlist_details(
+    app: &mut App,
+    playlist_link: &str,
+    changes: PlaylistChanges<'_>,
+) -> Result<(), ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let playlist_uri = context_uri("playlist", playlist_link);
+    let Ok(playlist_id) = PlaylistId::from_uri(&playlist_uri) else {
+        return Ok(());
+    };
+    let result = spotify
+        .playlist_change_detail(
+            playlist_id,
+            changes.name,
+            changes.public,
+            changes.description,
+            changes.collaborative,
+        )
+        .await;
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            app.error_text = format!("Error changing the playlist: {}", e);
+            app.selected_menu = Menu::Error;
+            Err(e)
+        }
+    }
+}
+
+/// Fetches the description of the playlist behind `playlist_link`
+#[tokio::main]
+pub async fn playlist_description(
+    app: &mut App,
+    playlist_link: &str,
+) -> Result<String, ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let playlist_uri = context_uri("playlist", playlist_link);
+    let playlist_id = playlist_uri.trim_start_matches("spotify:playlist:");
+    let query = HashMap::from([("fields", "description")]);
+    let response = spotify
+        .api_get(&format!("playlists/{}", playlist_id), &query)
+        .await?;
+    let playlist: Value = serde_json::from_str(&response)?;
+
+    Ok(playlist
+        .get("description")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string())
+}
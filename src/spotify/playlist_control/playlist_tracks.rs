@@ -0,0 +1,105 @@
+use crate::app::App;
+use crate::spotify::auth::get_spotify_client;
+use crate::spotify::player::util::{context_uri, playable_id_from_link};
+use futures_util::TryStreamExt;
+use rspotify::clients::{BaseClient, OAuthClient};
+use rspotify::model::{ItemPositions, PlayableItem, PlaylistId};
+use rspotify::prelude::Id;
+use rspotify::ClientError;
+
+/// Removes the items at `positions` from the playlist and returns its new snapshot id
+///
+/// Each entry pairs a track link with its position, so only those copies of a repeated track are removed.
+#[tokio::main]
+pub async fn remove_playlist_tracks(
+    app: &mut App,
+    playlist_link: &str,
+    positions: &[(String, u32)],
+    snapshot_id: &str,
+) -> Result<String, ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let playlist_uri = context_uri("playlist", playlist_link);
+    let Ok(playlist_id) = PlaylistId::from_uri(&playlist_uri) else {
+        return Ok(snapshot_id.to_string());
+    };
+
+    let single_positions: Vec<[u32; 1]> =
+        positions.iter().map(|(_, position)| [*position]).collect();
+    let items: Vec<ItemPositions> = positions
+        .iter()
+        .zip(single_positions.iter())
+        .filter_map(|((link, _), position)| {
+            Some(ItemPositions {
+                id: playable_id_from_link(link)?,
+                positions: position,
+            })
+        })
+        .collect();
+
+    let snapshot_id = (!snapshot_id.is_empty()).then_some(snapshot_id);
+    let result = spotify
+        .playlist_remove_specific_occurrences_of_items(playlist_id, items, snapshot_id)
+        .await?;
+
+    Ok(result.snapshot_id)
+}
+
+/// Moves the item at `range_start` so it comes right before `insert_before`, and returns the new snapshot id
+#[tokio::main]
+pub async fn reorder_playlist_track(
+    app: &mut App,
+    playlist_link: &str,
+    range_start: u32,
+    insert_before: u32,
+    snapshot_id: &str,
+) -> Result<String, ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let playlist_uri = context_uri("playlist", playlist_link);
+    let Ok(playlist_id) = PlaylistId::from_uri(&playlist_uri) else {
+        return Ok(snapshot_id.to_string());
+    };
+
+    let snapshot_id = (!snapshot_id.is_empty()).then_some(snapshot_id);
+    let result = spotify
+        .playlist_reorder_items(
+            playlist_id,
+            Some(range_start as i32),
+            Some(insert_before as i32),
+            Some(1),
+            snapshot_id,
+        )
+        .await?;
+
+    Ok(result.snapshot_id)
+}
+
+/// Fetches the ids of every track in the playlist, in playlist order
+#[tokio::main]
+pub async fn playlist_track_ids(
+    app: &mut App,
+    playlist_link: &str,
+) -> Result<Vec<String>, ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let playlist_uri = context_uri("playlist", playlist_link);
+    let Ok(playlist_id) = PlaylistId::from_uri(&playlist_uri) else {
+        return Ok(Vec::new());
+    };
+
+    let mut track_ids = Vec::new();
+    let mut items = spotify.playlist_items(playlist_id, None, None);
+    while let Some(item) = items.try_next().await? {
+        if let Some(PlayableItem::Track(track)) = item.track {
+            if let Some(id) = track.id {
+                track_ids.push(id.id().to_string());
+            }
+        }
+    }
+
+    Ok(track_ids)
+}
@@ -0,0 +1,71 @@
+use crate::app::App;
+use crate::enums::Menu;
+use crate::spotify::auth::get_spotify_client;
+use crate::spotify::player::util::context_uri;
+use futures_util::TryStreamExt;
+use rspotify::clients::BaseClient;
+use rspotify::model::{AlbumId, ArtistId, Market};
+use rspotify::prelude::Id;
+use rspotify::ClientError;
+
+/// Fetches the ids of every track on the album behind `album_link`, in album order
+#[tokio::main]
+pub async fn album_track_ids(app: &mut App, album_link: &str) -> Result<Vec<String>, ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let album_uri = context_uri("album", album_link);
+    let Ok(album_id) = AlbumId::from_uri(&album_uri) else {
+        return Ok(Vec::new());
+    };
+
+    let mut track_ids = Vec::new();
+    let mut tracks = spotify.album_track(album_id, None);
+    loop {
+        match tracks.try_next().await {
+            Ok(Some(track)) => {
+                if let Some(id) = track.id {
+                    track_ids.push(id.id().to_string());
+                }
+            }
+            Ok(None) => break,
+            Err(e) => {
+                app.error_text = format!("Error fetching the tracks of the album: {}", e);
+                app.selected_menu = Menu::Error;
+                return Err(e);
+            }
+        }
+    }
+
+    Ok(track_ids)
+}
+
+/// Fetches the ids of the top tracks of the artist behind `artist_link`
+#[tokio::main]
+pub async fn artist_top_track_ids(
+    app: &mut App,
+    artist_link: &str,
+) -> Result<Vec<String>, ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let artist_uri = context_uri("artist", artist_link);
+    let Ok(artist_id) = ArtistId::from_uri(&artist_uri) else {
+        return Ok(Vec::new());
+    };
+
+    match spotify
+        .artist_top_tracks(artist_id, Some(Market::FromToken))
+        .await
+    {
+        Ok(tracks) => Ok(tracks
+            .into_iter()
+            .filter_map(|track| track.id.map(|id| id.id().to_string()))
+            .collect()),
+        Err(e) => {
+            app.error_text = format!("Error fetching the top tracks of the artist: {}", e);
+            app.selected_menu = Menu::Error;
+            Err(e)
+        }
+    }
+}
@@ -22,6 +22,9 @@ use std::path::{Path, PathBuf};
 
 use crate::app::App;
 
+// Names, ids and descriptions of the searched shows
+type ShowSearchResults = (Vec<String>, Vec<String>, Vec<String>);
+
 // Main function to perform the search and store the results in JSON files
 #[tokio::main]
 pub async fn search(user_query: &str, app: &mut App) -> Result<(), std::io::Error> {
@@ -107,6 +110,23 @@ pub async fn search(user_query: &str, app: &mut App) -> Result<(), std::io::Erro
         write!(file, "{}", json_data).unwrap();
     }
 
+    let show_query = user_query;
+    let result = spotify
+        .search(
+            show_query,
+            SearchType::Show,
+            Some(Market::Country(Country::UnitedStates)),
+            None,
+            Some(10),
+            None,
+        )
+        .await;
+    if let Ok(shows) = result {
+        let json_data = serde_json::to_string(&shows).unwrap();
+        let mut file = File::create(path.join("show_search_results.json")).unwrap();
+        write!(file, "{}", json_data).unwrap();
+    }
+
     Ok(())
 }
 
@@ -121,6 +141,9 @@ pub fn process_search(app: &mut App, query: &str) -> io::Result<()> {
     app.artist_links_search_results.clear();
     app.track_links_search_results.clear();
     app.playlist_links_search_results.clear();
+    app.show_names_search_results.clear();
+    app.show_links_search_results.clear();
+    app.show_descriptions_search_results.clear();
 
     let mut spotify_cache_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     spotify_cache_path.push("..");
@@ -171,6 +194,18 @@ pub fn process_search(app: &mut App, query: &str) -> io::Result<()> {
                 return Err(err);
             }
         };
+
+        (
+            app.show_names_search_results,
+            app.show_links_search_results,
+            app.show_descriptions_search_results,
+        ) = match show_storage(&spotify_cache_path) {
+            Ok(result) => result,
+            Err(err) => {
+                println!("Error reading show data: {}", err);
+                return Err(err);
+            }
+        };
     }
 
     Ok(())
@@ -410,3 +445,60 @@ pub fn album_storage(spotify_cache_path: &Path) -> Result<(Vec<String>, Vec<Stri
 
     Ok((album_names_search_results, album_links_search_results))
 }
+
+#[derive(Serialize, Deserialize, Debug)]
+struct ShowResponse {
+    shows: Shows,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Shows {
+    // Spotify sometimes returns null in place of shows that are not available
+    items: Vec<Option<Show>>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Show {
+    id: String,
+    name: String,
+    description: String,
+}
+
+/// Reads the searched shows, returning their names, ids and descriptions
+pub fn show_storage(spotify_cache_path: &Path) -> Result<ShowSearchResults, io::Error> {
+    let json_file_path = spotify_cache_path.join("show_search_results.json");
+
+    if !json_file_path.exists() {
+        println!("show_search_results.json file does not exist");
+        return Ok((Vec::new(), Vec::new(), Vec::new()));
+    }
+
+    let metadata = fs::metadata(&json_file_path)?;
+    if metadata.len() == 0 {
+        println!("show_search_results.json file is empty");
+        return Ok((Vec::new(), Vec::new(), Vec::new()));
+    }
+
+    let data = fs::read_to_string(json_file_path)?;
+
+    let show_response: ShowResponse = serde_json::from_str(&data).map_err(|e| {
+        println!("Deserialization error: {}", e);
+        e
+    })?;
+
+    let mut show_names_search_results: Vec<String> = Vec::new();
+    let mut show_links_search_results: Vec<String> = Vec::new();
+    let mut show_descriptions_search_results: Vec<String> = Vec::new();
+
+    for show in show_response.shows.items.iter().flatten() {
+        show_names_search_results.push(show.name.clone());
+        show_links_search_results.push(show.id.clone());
+        show_descriptions_search_results.push(show.description.clone());
+    }
+
+    Ok((
+        show_names_search_results,
+        show_links_search_results,
+        show_descriptions_search_results,
+    ))
+}
@@ -0,0 +1,145 @@
+// Applies a restore plan to the current account
+
+use crate::app::App;
+use crate::snapshot::archive::SnapshotItem;
+use crate::snapshot::plan::{PlaylistTarget, RestorePlan};
+use crate::spotify::auth::get_spotify_client;
+use crate::spotify::player::util::playable_id_from_link;
+use rspotify::clients::OAuthClient;
+use rspotify::model::{AlbumId, ArtistId, PlayableId, PlaylistId, ShowId, TrackId, UserId};
+use rspotify::{AuthCodeSpotify, ClientError};
+
+/// Spotify accepts at most this many ids per library request
+const LIBRARY_BATCH_SIZE: usize = 50;
+/// Saving albums takes fewer ids per request
+const ALBUM_BATCH_SIZE: usize = 20;
+/// Spotify accepts at most this many items per playlist request
+const PLAYLIST_BATCH_SIZE: usize = 100;
+
+/// Saves, follows and rebuilds everything in `plan`, playlists are created for `user_id` when needed
+#[tokio::main]
+pub async fn restore_snapshot(
+    app: &mut App,
+    plan: &RestorePlan,
+    user_id: &str,
+) -> Result<(), ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    // Liked songs are listed newest first, adding the oldest first keeps that order
+    let track_ids: Vec<TrackId> = plan
+        .liked_songs
+        .iter()
+        .rev()
+        .filter_map(|item| TrackId::from_uri(&item.uri).ok())
+        .collect();
+    for batch in track_ids.chunks(LIBRARY_BATCH_SIZE) {
+        spotify
+            .current_user_saved_tracks_add(batch.iter().cloned())
+            .await?;
+    }
+
+    let album_ids: Vec<AlbumId> = plan
+        .saved_albums
+        .iter()
+        .rev()
+        .filter_map(|item| AlbumId::from_uri(&item.uri).ok())
+        .collect();
+    for batch in album_ids.chunks(ALBUM_BATCH_SIZE) {
+        spotify
+            .current_user_saved_albums_add(batch.iter().cloned())
+            .await?;
+    }
+
+    let artist_ids: Vec<ArtistId> = plan
+        .followed_artists
+        .iter()
+        .filter_map(|item| ArtistId::from_uri(&item.uri).ok())
+        .collect();
+    for batch in artist_ids.chunks(LIBRARY_BATCH_SIZE) {
+        spotify.user_follow_artists(batch.iter().cloned()).await?;
+    }
+
+    let show_ids: Vec<ShowId> = plan
+        .saved_shows
+        .iter()
+        .rev()
+        .filter_map(|item| ShowId::from_uri(&item.uri).ok())
+        .collect();
+    for batch in show_ids.chunks(LIBRARY_BATCH_SIZE) {
+        spotify.save_shows(batch.iter().cloned()).await?;
+    }
+
+    for item in &plan.followed_playlists {
+        if let Ok(playlist_id) = PlaylistId::from_uri(&item.uri) {
+            spotify.playlist_follow(playlist_id, None).await?;
+        }
+    }
+
+    for restore in &plan.playlists {
+        let playlist_id = match &restore.target {
+            PlaylistTarget::Existing(uri) => PlaylistId::from_uri(uri).ok(),
+            // A playlist that cannot be followed again is created from scratch
+            PlaylistTarget::Refollow(uri) => match PlaylistId::from_uri(uri) {
+                Ok(playlist_id)
+                    if spotify
+                        .playlist_follow(playlist_id.clone(), None)
+                        .await
+                        .is_ok() =>
+                {
+                    Some(playlist_id)
+                }
+                _ => None,
+            },
+            PlaylistTarget::Create => None,
+        };
+        let playlist_id = match playlist_id {
+            Some(playlist_id) => playlist_id,
+            None => {
+                let Ok(user_id) = UserId::from_id(user_id) else {
+                    continue;
+                };
+                let playlist = &restore.playlist;
+                spotify
+                    .user_playlist_create(
+                        user_id,
+                        &playlist.name,
+                        Some(playlist.public && !playlist.collaborative),
+                        Some(playlist.collaborative),
+                        None,
+                    )
+                    .await?
+                    .id
+            }
+        };
+
+        replace_playlist_tracks(&spotify, playlist_id, &restore.playlist.tracks).await?;
+    }
+
+    Ok(())
+}
+
+// Replaces the whole playlist, only the first batch can be replaced in one request
+async fn replace_playlist_tracks(
+    spotify: &AuthCodeSpotify,
+    playlist_id: PlaylistId<'_>,
+    tracks: &[SnapshotItem],
+) -> Result<(), ClientError> {
+    let items: Vec<PlayableId> = tracks
+        .iter()
+        .filter_map(|track| playable_id_from_link(&track.uri))
+        .collect();
+
+    let mut batches = items.chunks(PLAYLIST_BATCH_SIZE);
+    let first = batches.next().unwrap_or_default();
+    spotify
+        .playlist_replace_items(playlist_id.clone(), first.iter().cloned())
+        .await?;
+    for batch in batches {
+        spotify
+            .playlist_add_items(playlist_id.clone(), batch.iter().cloned(), None)
+            .await?;
+    }
+
+    Ok(())
+}
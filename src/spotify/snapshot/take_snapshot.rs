@@ -0,0 +1,133 @@
+// Reads everything a library snapshot holds from the current account
+
+use crate::app::App;
+use crate::snapshot::archive::{Snapshot, SnapshotItem, SnapshotPlaylist, SNAPSHOT_VERSION};
+use crate::spotify::auth::get_spotify_client;
+use chrono::Local;
+use futures_util::TryStreamExt;
+use rspotify::clients::{BaseClient, OAuthClient};
+use rspotify::model::PlayableItem;
+use rspotify::prelude::Id;
+use rspotify::ClientError;
+
+/// Spotify returns at most this many followed artists per request
+const FOLLOWED_ARTISTS_PAGE_SIZE: u32 = 50;
+
+#[tokio::main]
+pub async fn take_snapshot(app: &mut App) -> Result<Snapshot, ClientError> {
+    // Get a Spotify client using an existing access token (if available).
+    let spotify = get_spotify_client(app).await?;
+
+    let user_id = spotify.current_user().await?.id.id().to_string();
+    let mut snapshot = Snapshot {
+        version: SNAPSHOT_VERSION,
+        created_at: Local::now().to_rfc3339(),
+        user_id: user_id.clone(),
+        ..Default::default()
+    };
+
+    let mut saved_tracks = spotify.current_user_saved_tracks(None);
+    while let Some(saved_track) = saved_tracks.try_next().await? {
+        let track = saved_track.track;
+        if let Some(id) = track.id {
+            snapshot.liked_songs.push(SnapshotItem {
+                uri: id.uri(),
+                name: item_name(track.artists.iter().map(|a| a.name.as_str()), &track.name),
+            });
+        }
+    }
+
+    let mut saved_albums = spotify.current_user_saved_albums(None);
+    while let Some(saved_album) = saved_albums.try_next().await? {
+        let album = saved_album.album;
+        snapshot.saved_albums.push(SnapshotItem {
+            uri: album.id.uri(),
+            name: item_name(album.artists.iter().map(|a| a.name.as_str()), &album.name),
+        });
+    }
+
+    // Followed artists are paged with a cursor instead of an offset
+    let mut after: Option<String> = None;
+    loop {
+        let page = spotify
+            .current_user_followed_artists(after.as_deref(), Some(FOLLOWED_ARTISTS_PAGE_SIZE))
+            .await?;
+        snapshot
+            .followed_artists
+            .extend(page.items.into_iter().map(|artist| SnapshotItem {
+                uri: artist.id.uri(),
+                name: artist.name,
+            }));
+        match page.cursors.and_then(|cursors| cursors.after) {
+            Some(cursor) => after = Some(cursor),
+            None => break,
+        }
+    }
+
+    let mut saved_shows = spotify.get_saved_show();
+    while let Some(saved_show) = saved_shows.try_next().await? {
+        snapshot.saved_shows.push(SnapshotItem {
+            uri: saved_show.show.id.uri(),
+            name: saved_show.show.name,
+        });
+    }
+
+    let mut playlists = Vec::new();
+    let mut user_playlists = spotify.current_user_playlists();
+    while let Some(playlist) = user_playlists.try_next().await? {
+        playlists.push(playlist);
+    }
+    for playlist in playlists {
+        if playlist.owner.id.id() != user_id {
+            snapshot.followed_playlists.push(SnapshotItem {
+                uri: playlist.id.uri(),
+                name: playlist.name,
+            });
+            continue;
+        }
+
+        let mut tracks = Vec::new();
+        let mut local_files = Vec::new();
+        let mut items = spotify.playlist_items(playlist.id.clone(), None, None);
+        while let Some(item) = items.try_next().await? {
+            let track = match item.track {
+                Some(PlayableItem::Track(track)) => {
+                    let name =
+                        item_name(track.artists.iter().map(|a| a.name.as_str()), &track.name);
+                    match track.id {
+                        Some(id) => Some(SnapshotItem {
+                            uri: id.uri(),
+                            name,
+                        }),
+                        // Local files have no id and cannot be added back
+                        None => {
+                            local_files.push(name);
+                            None
+                        }
+                    }
+                }
+                Some(PlayableItem::Episode(episode)) => Some(SnapshotItem {
+                    uri: episode.id.uri(),
+                    name: episode.name,
+                }),
+                None => None,
+            };
+            tracks.extend(track);
+        }
+
+        snapshot.playlists.push(SnapshotPlaylist {
+            uri: playlist.id.uri(),
+            name: playlist.name,
+            public: playlist.public.unwrap_or(false),
+            collaborative: playlist.collaborative,
+            tracks,
+            local_files,
+        });
+    }
+
+    Ok(snapshot)
+}
+
+fn item_name<'a>(artists: impl Iterator<Item = &'a str>, name: &str) -> String {
+    format!("{} - {}", artists.collect::<Vec<_>>().join(", "), name)
+}
@@ -4,6 +4,7 @@ use crate::spotify::auth::get_spotify_client;
 use futures_util::TryStreamExt;
 use rspotify::clients::OAuthClient;
 use rspotify::model::SimplifiedPlaylist;
+use rspotify::prelude::Id;
 use rspotify::{AuthCodeSpotify, ClientError};
 use serde_json;
 use serde_json::Value;
@@ -50,6 +51,15 @@ fn save_playlists_to_json(app: &mut App, playlists: &[SimplifiedPlaylist]) {
 pub async fn get_playlists(app: &mut App) {
     // Obtain a Spotify client using the access token (if available)
     let spotify = get_spotify_client(app).await.unwrap();
+
+    // The user id tells which playlists are owned and can be edited
+    if app.user_id.is_empty() {
+        match spotify.current_user().await {
+            Ok(user) => app.user_id = user.id.id().to_string(),
+            Err(e) => println!("Error fetching the current user: {}", e),
+        }
+    }
+
     match fetch_user_playlists(app, &spotify).await {
         Ok(playlists) => {
             save_playlists_to_json(app, &playlists);
@@ -62,6 +72,11 @@ pub async fn get_playlists(app: &mut App) {
 pub fn process_user_playlists(app: &mut App) {
     app.user_playlist_names.clear();
     app.user_playlist_links.clear();
+    app.user_playlist_image_urls.clear();
+    app.user_playlist_owned.clear();
+    app.user_playlist_public.clear();
+    app.user_playlist_collaborative.clear();
+    app.user_playlist_snapshot_ids.clear();
 
     let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     path.push(".."); // Move up to the root of the Git repository
@@ -90,6 +105,43 @@ pub fn process_user_playlists(app: &mut App) {
                 {
                     app.user_playlist_links.push(link.to_string());
                 }
+
+                // Playlists without a cover still get an entry so indices stay aligned
+                let image_url = playlist_obj
+                    .get("images")
+                    .and_then(Value::as_array)
+                    .and_then(|images| images.first())
+                    .and_then(|image| image.get("url"))
+                    .and_then(Value::as_str)
+                    .unwrap_or_default();
+                app.user_playlist_image_urls.push(image_url.to_string());
+
+                let owner_id = playlist_obj
+                    .get("owner")
+                    .and_then(|owner| owner.get("id"))
+                    .and_then(Value::as_str)
+                    .unwrap_or_default();
+                app.user_playlist_owned
+                    .push(!app.user_id.is_empty() && owner_id == app.user_id);
+                app.user_playlist_public.push(
+                    playlist_obj
+                        .get("public")
+                        .and_then(Value::as_bool)
+                        .unwrap_or(false),
+                );
+                app.user_playlist_collaborative.push(
+                    playlist_obj
+                        .get("collaborative")
+                        .and_then(Value::as_bool)
+                        .unwrap_or(false),
+                );
+                app.user_playlist_snapshot_ids.push(
+                    playlist_obj
+                        .get("snapshot_id")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .to_string(),
+                );
             }
         }
     }
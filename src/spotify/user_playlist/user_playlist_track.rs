@@ -68,6 +68,7 @@ pub fn process_playlist_tracks(app: &mut App) {
     app.user_playlist_track_duration.clear();
     app.user_playlist_artist_names.clear();
     app.user_playlist_album_names.clear();
+    app.user_playlist_track_positions.clear();
 
     let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     path.push(".."); // Move up to the root of the Git repository
@@ -82,11 +83,13 @@ pub fn process_playlist_tracks(app: &mut App) {
 
     // Extract information about each track from the JSON data and populate the app's data structures for displaying the playlist
     if let Value::Array(tracks) = json_data {
-        for track in tracks {
+        for (position, track) in tracks.into_iter().enumerate() {
             if let Value::Object(track_obj) = track {
                 if let Some(track_info) = track_obj.get("track").and_then(Value::as_object) {
                     if let Some(track_name) = track_info.get("name").and_then(Value::as_str) {
                         app.user_playlist_track_names.push(track_name.to_string());
+                        // Unavailable items have no row, so rows remember where they are in the playlist
+                        app.user_playlist_track_positions.push(position as u32);
                     }
 
                     if let Some(track_duration) =
@@ -1,5 +1,7 @@
 use ratatui::style::Color;
 
+use crate::enums::ImageProtocol;
+
 #[derive(Clone, Debug)]
 pub struct Key {
     pub go_to_search_key: char,
@@ -18,9 +20,31 @@ pub struct Key {
     pub change_keybind: char,
     pub refresh_key: char,
     pub open_config_fold_key: char,
+    pub theme_picker_key: char,
+    pub lyrics_key: char,
+    pub queue_key: char,
+    pub device_picker_key: char,
+    pub seek_forward_key: char,
+    pub seek_backward_key: char,
+    pub seek_to_position_key: char,
+    pub restart_track_key: char,
+    pub set_volume_key: char,
+    pub mute_key: char,
+    pub save_key: char,
+    pub follow_artist_key: char,
+    pub save_album_key: char,
+    pub create_playlist_key: char,
+    pub edit_playlist_key: char,
+    pub move_track_key: char,
+    pub toggle_selection_key: char,
+    pub select_range_key: char,
+    pub export_key: char,
+    pub import_key: char,
+    pub find_duplicates_key: char,
 
     pub first_keys: Vec<String>,
     pub tasks: Vec<String>,
+    pub help_contexts: Vec<String>,
 }
 
 impl Default for Key {
@@ -42,9 +66,31 @@ impl Default for Key {
             player_fullscreen_key: ' ',
             change_keybind: ' ',
             refresh_key: ' ',
+            theme_picker_key: ' ',
+            lyrics_key: ' ',
+            queue_key: ' ',
+            device_picker_key: ' ',
+            seek_forward_key: ' ',
+            seek_backward_key: ' ',
+            seek_to_position_key: ' ',
+            restart_track_key: ' ',
+            set_volume_key: ' ',
+            mute_key: ' ',
+            save_key: ' ',
+            follow_artist_key: ' ',
+            save_album_key: ' ',
+            create_playlist_key: ' ',
+            edit_playlist_key: ' ',
+            move_track_key: ' ',
+            toggle_selection_key: ' ',
+            select_range_key: ' ',
+            export_key: ' ',
+            import_key: ' ',
+            find_duplicates_key: ' ',
 
             first_keys: Vec::new(),
             tasks: Vec::new(),
+            help_contexts: Vec::new(),
         }
     }
 }
@@ -139,6 +185,27 @@ pub struct Settings {
     pub volume_decreament_value: u8,
     pub volume_percent: u8,
     pub theme_name: String,
+
+    // Seconds skipped by the seek keys, podcasts use a separate step
+    pub seek_step: u32,
+    pub podcast_seek_step: u32,
+
+    // How album art is drawn in the terminal
+    pub album_art_protocol: ImageProtocol,
+
+    // Folder with the .lrc/.txt lyrics files, empty for the default folder
+    pub lyrics_folder: String,
+
+    // Folder exported playlists are written to, empty for the default folder
+    pub export_folder: String,
+
+    // Device playback moves to when no device is active, empty to disable
+    pub preferred_device: String,
+
+    // Milliseconds between player polls while playing, paused and with nothing playing
+    pub poll_interval: u64,
+    pub paused_poll_interval: u64,
+    pub idle_poll_interval: u64,
 }
 
 impl Default for Settings {
@@ -148,6 +215,22 @@ impl Default for Settings {
             volume_decreament_value: 0,
             volume_percent: 0,
             theme_name: String::new(),
+            seek_step: 10,
+            podcast_seek_step: 30,
+            album_art_protocol: ImageProtocol::HalfBlocks,
+            lyrics_folder: String::new(),
+            export_folder: String::new(),
+            preferred_device: String::new(),
+            poll_interval: 1000,
+            paused_poll_interval: 5000,
+            idle_poll_interval: 15000,
         }
     }
 }
+
+/// A single line of lyrics, with the time it starts at when the lyrics are synced
+#[derive(Clone, Debug)]
+pub struct LyricLine {
+    pub timestamp_ms: Option<f64>,
+    pub text: String,
+}
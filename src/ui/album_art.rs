@@ -0,0 +1,294 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use crossterm::{cursor::MoveTo, queue, terminal::window_size};
+use image::{imageops::FilterType, RgbImage};
+use ratatui::{layout::Rect, style::Color, widgets::Clear, Frame};
+use std::io::{self, Write};
+
+use crate::{
+    app::App, enums::ImageProtocol, spotify::album_art::fetch_album_art::album_art_path,
+    structs::Settings, ui::tui::Tui,
+};
+
+/// Covers kept decoded in memory, older ones are read from the disk cache again when needed
+const ALBUM_ART_CACHE_SIZE: usize = 8;
+
+/// Renders the cover behind `image_url` inside `area`, keeping it square
+pub fn render_album_art(
+    f: &mut Frame,
+    area: Rect,
+    image_url: &str,
+    app: &mut App,
+    settings: &Settings,
+) {
+    if settings.album_art_protocol == ImageProtocol::Off || image_url.is_empty() {
+        return;
+    }
+
+    // Terminal cells are about twice as tall as they are wide
+    let width = area.width.min(area.height * 2);
+    let height = width / 2;
+    if width < 2 || height < 1 {
+        return;
+    }
+    let art_area = Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    );
+
+    // Covers are only ever drawn this big, so there is no need to keep more pixels
+    let pixel_size = match settings.album_art_protocol {
+        ImageProtocol::HalfBlocks => (width as u32, height as u32 * 2),
+        _ => {
+            let (cell_width, cell_height) = cell_size_in_pixels();
+            (width as u32 * cell_width, height as u32 * cell_height)
+        }
+    };
+    if !load_album_art(app, image_url, pixel_size) {
+        return;
+    }
+
+    match settings.album_art_protocol {
+        ImageProtocol::HalfBlocks => {
+            if let Some(image) = album_art_image(app, image_url) {
+                render_half_blocks(f, art_area, image);
+            }
+        }
+        ImageProtocol::Kitty | ImageProtocol::Sixel => {
+            // Leave the cells empty, the image is written on top after the frame is drawn
+            f.render_widget(Clear, art_area);
+            app.album_art_placements
+                .push((art_area, image_url.to_string()));
+        }
+        ImageProtocol::Off => {}
+    }
+}
+
+/// Makes sure the cover is decoded and at least `pixel_size` big, returns false when it is not downloaded yet
+fn load_album_art(app: &mut App, image_url: &str, pixel_size: (u32, u32)) -> bool {
+    if let Some(index) = app
+        .album_art_images
+        .iter()
+        .position(|(url, _, _)| url == image_url)
+    {
+        let cached = app.album_art_images.remove(index);
+        let (cached_width, cached_height) = cached.1;
+        if cached_width >= pixel_size.0 && cached_height >= pixel_size.1 {
+            app.album_art_images.push(cached);
+            return true;
+        }
+    }
+
+    let path = album_art_path(image_url);
+    if !path.exists() {
+        return false;
+    }
+    let Ok(image) = image::open(&path) else {
+        return false;
+    };
+    // Only ever scaled down, keeping the aspect ratio
+    let image = if image.width() > pixel_size.0 || image.height() > pixel_size.1 {
+        image.resize(pixel_size.0, pixel_size.1, FilterType::Triangle)
+    } else {
+        image
+    };
+
+    app.album_art_images
+        .push((image_url.to_string(), pixel_size, image.to_rgb8()));
+    if app.album_art_images.len() > ALBUM_ART_CACHE_SIZE {
+        app.album_art_images.remove(0);
+    }
+    true
+}
+
+fn album_art_image<'a>(app: &'a App, image_url: &str) -> Option<&'a RgbImage> {
+    app.album_art_images
+        .iter()
+        .find(|(url, _, _)| url == image_url)
+        .map(|(_, _, image)| image)
+}
+
+/// Draws the image with '▀' characters, using the foreground for the upper pixel and the background for the lower one
+fn render_half_blocks(f: &mut Frame, area: Rect, image: &RgbImage) {
+    let resized = image::imageops::resize(
+        image,
+        area.width as u32,
+        area.height as u32 * 2,
+        FilterType::Triangle,
+    );
+
+    let buffer = f.buffer_mut();
+    for row in 0..area.height {
+        for column in 0..area.width {
+            let upper = resized.get_pixel(column as u32, row as u32 * 2);
+            let lower = resized.get_pixel(column as u32, row as u32 * 2 + 1);
+            buffer
+                .get_mut(area.x + column, area.y + row)
+                .set_char('▀')
+                .set_fg(Color::Rgb(upper[0], upper[1], upper[2]))
+                .set_bg(Color::Rgb(lower[0], lower[1], lower[2]));
+        }
+    }
+}
+
+/// Writes the kitty or sixel images placed during the last frame, only when they changed
+pub fn draw_album_art_graphics(
+    app: &mut App,
+    terminal: &mut Tui,
+    settings: &Settings,
+) -> io::Result<()> {
+    let placements = std::mem::take(&mut app.album_art_placements);
+    if placements == app.album_art_drawn {
+        return Ok(());
+    }
+
+    let mut stdout = io::stdout();
+    match settings.album_art_protocol {
+        ImageProtocol::Kitty => {
+            // Remove the previous images before placing the new ones
+            write!(stdout, "\x1b_Ga=d,d=A,q=2\x1b\\")?;
+            for (index, (area, image_url)) in placements.iter().enumerate() {
+                if let Some(image) = album_art_image(app, image_url) {
+                    queue!(stdout, MoveTo(area.x, area.y))?;
+                    write!(stdout, "{}", kitty_escape(image, *area, index + 1))?;
+                }
+            }
+        }
+        ImageProtocol::Sixel => {
+            if !app.album_art_drawn.is_empty() {
+                // Sixel images stay on screen until their cells are redrawn, so repaint
+                // everything first and place the new images on the next frame
+                terminal.clear()?;
+                app.album_art_drawn.clear();
+                return Ok(());
+            }
+            let (cell_width, cell_height) = cell_size_in_pixels();
+            for (area, image_url) in placements.iter() {
+                if let Some(image) = album_art_image(app, image_url) {
+                    let resized = image::imageops::resize(
+                        image,
+                        area.width as u32 * cell_width,
+                        area.height as u32 * cell_height,
+                        FilterType::Triangle,
+                    );
+                    queue!(stdout, MoveTo(area.x, area.y))?;
+                    write!(stdout, "{}", sixel_escape(&resized))?;
+                }
+            }
+        }
+        ImageProtocol::HalfBlocks | ImageProtocol::Off => {}
+    }
+    stdout.flush()?;
+
+    app.album_art_drawn = placements;
+    Ok(())
+}
+
+/// Builds the kitty graphics escape sequence that scales `image` over `area`
+fn kitty_escape(image: &RgbImage, area: Rect, id: usize) -> String {
+    let data = STANDARD.encode(image.as_raw());
+    let chunks: Vec<&[u8]> = data.as_bytes().chunks(4096).collect();
+
+    let mut escape = String::new();
+    for (index, chunk) in chunks.iter().enumerate() {
+        let more = if index + 1 < chunks.len() { 1 } else { 0 };
+        let payload = String::from_utf8_lossy(chunk);
+        if index == 0 {
+            escape.push_str(&format!(
+                "\x1b_Ga=T,f=24,s={},v={},c={},r={},i={},C=1,q=2,m={};{}\x1b\\",
+                image.width(),
+                image.height(),
+                area.width,
+                area.height,
+                id,
+                more,
+                payload
+            ));
+        } else {
+            escape.push_str(&format!("\x1b_Gm={};{}\x1b\\", more, payload));
+        }
+    }
+
+    escape
+}
+
+/// Builds a sixel escape sequence for `image` using a 6x6x6 colour cube palette
+fn sixel_escape(image: &RgbImage) -> String {
+    let (width, height) = image.dimensions();
+    let mut escape = format!("\x1bPq\"1;1;{};{}", width, height);
+
+    for index in 0..216 {
+        let (r, g, b) = (index / 36, (index / 6) % 6, index % 6);
+        escape.push_str(&format!("#{};2;{};{};{}", index, r * 20, g * 20, b * 20));
+    }
+
+    let palette_index = |x: u32, y: u32| -> usize {
+        let pixel = image.get_pixel(x, y);
+        let level = |channel: u8| (channel as usize * 5 + 127) / 255;
+        level(pixel[0]) * 36 + level(pixel[1]) * 6 + level(pixel[2])
+    };
+
+    // Sixel data is written in bands of six pixel rows, one pass per colour
+    for band_top in (0..height).step_by(6) {
+        let band_bottom = (band_top + 6).min(height);
+
+        let mut used_colors = [false; 216];
+        for y in band_top..band_bottom {
+            for x in 0..width {
+                used_colors[palette_index(x, y)] = true;
+            }
+        }
+
+        for (color, _) in used_colors.iter().enumerate().filter(|(_, used)| **used) {
+            escape.push_str(&format!("#{}", color));
+
+            let mut previous: Option<char> = None;
+            let mut run = 0;
+            for x in 0..width {
+                let mut bits = 0;
+                for y in band_top..band_bottom {
+                    if palette_index(x, y) == color {
+                        bits |= 1 << (y - band_top);
+                    }
+                }
+                let sixel = (63 + bits) as u8 as char;
+
+                if previous == Some(sixel) {
+                    run += 1;
+                } else {
+                    push_sixel_run(&mut escape, previous, run);
+                    previous = Some(sixel);
+                    run = 1;
+                }
+            }
+            push_sixel_run(&mut escape, previous, run);
+            escape.push('$');
+        }
+        escape.push('-');
+    }
+
+    escape.push_str("\x1b\\");
+    escape
+}
+
+fn push_sixel_run(escape: &mut String, sixel: Option<char>, run: usize) {
+    if let Some(sixel) = sixel {
+        if run > 3 {
+            escape.push_str(&format!("!{}{}", run, sixel));
+        } else {
+            escape.extend(std::iter::repeat_n(sixel, run));
+        }
+    }
+}
+
+/// Returns the size of a terminal cell in pixels, falling back to a common default
+fn cell_size_in_pixels() -> (u32, u32) {
+    match window_size() {
+        Ok(size) if size.width > 0 && size.height > 0 && size.columns > 0 && size.rows > 0 => (
+            (size.width / size.columns) as u32,
+            (size.height / size.rows) as u32,
+        ),
+        _ => (10, 20),
+    }
+}
@@ -0,0 +1,43 @@
+use ratatui::{
+    style::Style,
+    widgets::{block::Title, Block, Borders, Clear},
+    Frame,
+};
+
+use crate::{
+    app::App,
+    structs::{Key, Themes},
+    ui::util::{centered_rect, device_table_ui},
+};
+
+/// Renders the devices playback can be moved to as a popup
+pub fn render_device_picker(f: &mut Frame, app: &mut App, key: &mut Key, theme: &mut Themes) {
+    let device_picker_label = format!(
+        "Devices (Enter to play here, Tab to move paused, {} to cancel)",
+        key.exit_application_key
+    );
+    let popup_area = centered_rect(60, 40, f.size());
+
+    let device_picker_block = Block::default()
+        .borders(Borders::ALL)
+        .title(Title::from(device_picker_label))
+        .border_style(Style::new().fg(theme.main_border_color))
+        .style(
+            Style::default()
+                .bg(theme.main_background_color)
+                .fg(theme.main_inactive_border_color),
+        );
+
+    let device_table = device_table_ui(
+        app.device_names.clone(),
+        app.device_types.clone(),
+        app.device_volumes.clone(),
+        app.device_active.clone(),
+        device_picker_block,
+        theme.main_highlight_color,
+        theme.main_background_color,
+    );
+
+    f.render_widget(Clear, popup_area);
+    f.render_stateful_widget(device_table, popup_area, &mut app.device_picker_state);
+}
@@ -0,0 +1,113 @@
+use ratatui::{
+    layout::Constraint,
+    style::{Style, Stylize},
+    widgets::{block::Title, Block, Borders, Cell, Clear, Row, Table},
+    Frame,
+};
+
+use crate::{
+    app::App,
+    duplicates::{duplicate_entries, DuplicateKind},
+    structs::{Key, Themes},
+    ui::util::{centered_rect, format_duration},
+};
+
+/// Renders the duplicate groups found in the opened playlist or Liked Songs, copies that go are dimmed
+pub fn render_duplicates(f: &mut Frame, app: &mut App, key: &mut Key, theme: &mut Themes) {
+    let removed = app
+        .duplicate_groups
+        .iter()
+        .map(|group| group.removed_rows().count())
+        .sum::<usize>();
+    let duplicates_label = format!(
+        "Duplicates in {}: {} to remove (Enter to keep/remove, Delete to remove them, {} to cancel)",
+        app.duplicates_source_name, removed, key.exit_application_key
+    );
+    let popup_area = centered_rect(80, 70, f.size());
+
+    let (names, artists, albums, durations) = if app.duplicates_in_liked_songs {
+        (
+            &app.liked_song_names,
+            &app.liked_song_artist_names,
+            &app.liked_song_album_names,
+            &app.liked_song_duration,
+        )
+    } else {
+        (
+            &app.user_playlist_track_names,
+            &app.user_playlist_artist_names,
+            &app.user_playlist_album_names,
+            &app.user_playlist_track_duration,
+        )
+    };
+
+    let rows: Vec<Row> = duplicate_entries(&app.duplicate_groups)
+        .into_iter()
+        .map(|(group, copy)| {
+            let duplicates = &app.duplicate_groups[group];
+            let row = duplicates.rows[copy];
+            let kind = match (copy, duplicates.kind) {
+                (0, DuplicateKind::SameTrack) => "Same track",
+                (0, DuplicateKind::SameSong) => "Same song",
+                _ => "",
+            };
+            let text = |values: &Vec<String>| values.get(row).cloned().unwrap_or_default();
+            let cells = Row::new(vec![
+                Cell::from(kind),
+                Cell::from(if duplicates.keep[copy] {
+                    "Keep"
+                } else {
+                    "Remove"
+                }),
+                Cell::from((row + 1).to_string()),
+                Cell::from(text(names)),
+                Cell::from(text(artists)),
+                Cell::from(text(albums)),
+                Cell::from(format_duration(
+                    durations.get(row).copied().unwrap_or_default(),
+                )),
+            ]);
+            if duplicates.keep[copy] {
+                cells
+            } else {
+                cells.style(Style::default().fg(theme.playlist_inactive_border_color))
+            }
+        })
+        .collect();
+
+    let duplicates_block = Block::default()
+        .borders(Borders::ALL)
+        .title(Title::from(duplicates_label))
+        .border_style(Style::new().fg(theme.playlist_border_color))
+        .style(Style::default().bg(theme.playlist_background_color));
+
+    let duplicates_table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(12),
+            Constraint::Percentage(8),
+            Constraint::Percentage(6),
+            Constraint::Percentage(28),
+            Constraint::Percentage(20),
+            Constraint::Percentage(18),
+            Constraint::Percentage(8),
+        ],
+    )
+    .header(
+        Row::new(vec![
+            Cell::from(""),
+            Cell::from(""),
+            Cell::from("#"),
+            Cell::from("Title"),
+            Cell::from("Artist"),
+            Cell::from("Album"),
+            Cell::from("Length"),
+        ])
+        .bold(),
+    )
+    .block(duplicates_block)
+    .highlight_style(Style::default().fg(theme.playlist_highlight_color));
+
+    f.render_widget(Clear, popup_area);
+    f.render_stateful_widget(duplicates_table, popup_area, &mut app.duplicates_state);
+}
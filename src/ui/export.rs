@@ -0,0 +1,43 @@
+use ratatui::{
+    style::Style,
+    widgets::{block::Title, Block, Borders, Clear, List, ListItem},
+    Frame,
+};
+
+use crate::{
+    app::App,
+    export::formats::{format_name, EXPORT_FORMATS},
+    structs::{Key, Themes},
+    ui::util::centered_rect,
+};
+
+/// Renders the export format picker as a popup
+pub fn render_export(f: &mut Frame, app: &mut App, key: &mut Key, theme: &mut Themes) {
+    let export_label = format!(
+        "Export {} as (Enter to save, {} to go back)",
+        app.export_source_name, key.exit_application_key
+    );
+    let popup_area = centered_rect(50, 25, f.size());
+
+    let formats: Vec<ListItem> = EXPORT_FORMATS
+        .iter()
+        .map(|format| ListItem::new(format_name(*format)))
+        .collect();
+
+    let export_block = Block::default()
+        .borders(Borders::ALL)
+        .title(Title::from(export_label))
+        .border_style(Style::new().fg(theme.playlist_border_color))
+        .style(
+            Style::default()
+                .bg(theme.playlist_background_color)
+                .fg(theme.playlist_inactive_border_color),
+        );
+
+    let export_list = List::new(formats)
+        .block(export_block)
+        .highlight_style(Style::default().fg(theme.playlist_highlight_color));
+
+    f.render_widget(Clear, popup_area);
+    f.render_stateful_widget(export_list, popup_area, &mut app.export_format_state);
+}
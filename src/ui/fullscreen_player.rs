@@ -8,7 +8,10 @@ use ratatui::{
 
 use crate::{app::App, structs::Themes};
 
-use super::{blank_screen::render_blank_screen, util::format_duration};
+use super::{
+    blank_screen::render_blank_screen,
+    util::{format_duration, player_title},
+};
 
 /// Renders the player UI section, including playback info, progress bar, and current media details
 pub fn render_player_in_fullscreen(
@@ -22,14 +25,7 @@ pub fn render_player_in_fullscreen(
 
     let player_info_block = Block::default()
         .borders(Borders::TOP | Borders::RIGHT | Borders::LEFT)
-        .title(format!(
-            "{} ({} | Shuffle: {} | Repeat: {} | Volume: {}%)",
-            app.playback_status,
-            app.current_device_name,
-            app.shuffle_status,
-            app.repeat_status,
-            app.current_device_volume
-        ))
+        .title(player_title(app))
         .style(
             Style::default()
                 .bg(theme.player_background_color)
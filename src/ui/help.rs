@@ -6,13 +6,21 @@ use ratatui::{
     Frame,
 };
 
+use crate::app::App;
+use crate::enums::InputMode;
+use crate::settings::keybindings::{help_context_for_menu, help_entries};
 use crate::structs::Key;
 use crate::structs::Themes;
 
 use super::util::help_table_ui;
 
 /// Renders the default help block
-pub fn render_default_help(f: &mut Frame, header_chunk: &[Rect], theme: &mut Themes) {
+pub fn render_default_help(
+    f: &mut Frame,
+    header_chunk: &[Rect],
+    key: &mut Key,
+    theme: &mut Themes,
+) {
     let help_block = Block::default()
         .borders(Borders::ALL)
         .title(Title::from("Help"))
@@ -26,7 +34,10 @@ pub fn render_default_help(f: &mut Frame, header_chunk: &[Rect], theme: &mut The
     let mut help_panel_vec = Vec::new();
     let _var = help_panel_vec;
 
-    help_panel_vec = vec![Line::from(vec![Span::raw("Type ?")])];
+    help_panel_vec = vec![Line::from(vec![Span::raw(format!(
+        "Type {}",
+        key.help_key
+    ))])];
 
     let help_panel = Paragraph::new(help_panel_vec)
         .wrap(Wrap { trim: true })
@@ -35,9 +46,17 @@ pub fn render_default_help(f: &mut Frame, header_chunk: &[Rect], theme: &mut The
     f.render_widget(help_panel, header_chunk[1]);
 }
 
-/// Renders the full help UI with a table of available commands and keybindings
-pub fn render_help(f: &mut Frame, key: &mut Key, theme: &mut Themes) {
-    let help_label = format!("Help (press {} to go back)", key.help_key);
+/// Renders the full help UI with the keybindings for the pane it was opened from listed first
+pub fn render_help(f: &mut Frame, app: &mut App, key: &mut Key, theme: &mut Themes) {
+    let context = help_context_for_menu(app.help_origin_menu);
+    let help_label = if app.input_mode == InputMode::HelpSearch || !app.help_filter.is_empty() {
+        format!("Help: {} first (search: {})", context, app.help_filter)
+    } else {
+        format!(
+            "Help: {} first (press {} to go back, / to search)",
+            context, key.help_key
+        )
+    };
     f.render_widget(Clear, f.size());
 
     let help_block = Block::default()
@@ -46,13 +65,14 @@ pub fn render_help(f: &mut Frame, key: &mut Key, theme: &mut Themes) {
         .border_style(Style::new().fg(theme.help_border_color))
         .style(Style::default().bg(theme.help_background_color));
 
+    let entries = help_entries(key, app.help_origin_menu, &app.help_filter);
+
     let help_table = help_table_ui(
-        key.tasks.clone(),
-        key.first_keys.clone(),
+        entries,
         help_block,
         theme.help_highlight_color,
         theme.help_background_color,
     );
 
-    f.render_widget(help_table, f.size());
+    f.render_stateful_widget(help_table, f.size(), &mut app.help_state);
 }
@@ -0,0 +1,89 @@
+use ratatui::{
+    layout::Constraint,
+    style::{Style, Stylize},
+    widgets::{block::Title, Block, Borders, Cell, Clear, Row, Table},
+    Frame,
+};
+
+use crate::{
+    app::App,
+    structs::{Key, Themes},
+    ui::util::centered_rect,
+};
+
+/// Renders the matches found for an imported file, with how sure each match is
+pub fn render_import_review(f: &mut Frame, app: &mut App, key: &mut Key, theme: &mut Themes) {
+    let accepted = app
+        .import_matches
+        .iter()
+        .filter(|import_match| import_match.accepted)
+        .count();
+    let import_label = format!(
+        "Import: {} of {} accepted (Enter to accept/reject, {} to create the playlist, {} to cancel)",
+        accepted,
+        app.import_matches.len(),
+        key.create_playlist_key,
+        key.exit_application_key
+    );
+    let popup_area = centered_rect(90, 80, f.size());
+
+    let rows: Vec<Row> = app
+        .import_matches
+        .iter()
+        .enumerate()
+        .map(|(index, import_match)| {
+            let (confidence, found) = if import_match.track_uri.is_empty() {
+                ("-".to_string(), "No match".to_string())
+            } else {
+                (
+                    format!("{}%", import_match.confidence),
+                    import_match.track_name.clone(),
+                )
+            };
+            let row = Row::new(vec![
+                Cell::from((index + 1).to_string()),
+                Cell::from(if import_match.accepted { "✓" } else { "" }),
+                Cell::from(import_match.entry.source.clone()),
+                Cell::from(found),
+                Cell::from(confidence),
+            ]);
+            if import_match.accepted {
+                row
+            } else {
+                row.style(Style::default().fg(theme.playlist_inactive_border_color))
+            }
+        })
+        .collect();
+
+    let import_block = Block::default()
+        .borders(Borders::ALL)
+        .title(Title::from(import_label))
+        .border_style(Style::new().fg(theme.playlist_border_color))
+        .style(Style::default().bg(theme.playlist_background_color));
+
+    let import_table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(5),
+            Constraint::Percentage(5),
+            Constraint::Percentage(40),
+            Constraint::Percentage(40),
+            Constraint::Percentage(10),
+        ],
+    )
+    .header(
+        Row::new(vec![
+            Cell::from("#"),
+            Cell::from(""),
+            Cell::from("From the file"),
+            Cell::from("Match"),
+            Cell::from("Confidence"),
+        ])
+        .bold(),
+    )
+    .block(import_block)
+    .highlight_style(Style::default().fg(theme.playlist_highlight_color));
+
+    f.render_widget(Clear, popup_area);
+    f.render_stateful_widget(import_table, popup_area, &mut app.import_review_state);
+}
@@ -5,11 +5,15 @@ use ratatui::{
     Frame,
 };
 
-use crate::ui::util::{album_table_ui, artist_table_ui, podcast_table_ui, track_table_ui};
+use crate::ui::util::{
+    album_table_ui, artist_table_ui, audiobook_table_ui, podcast_table_ui, row_marks,
+    track_table_ui, TableColors,
+};
 use crate::{app::App, structs::Themes};
 
 use super::{
     search::util::{searched_track_table_for_album_ui, searched_track_table_for_artist_ui},
+    show::render_show_episodes,
     util::made_fy_table_ui,
 };
 
@@ -67,6 +71,16 @@ pub fn render_library(
         })
         .style(Style::default().bg(theme.main_background_color));
 
+    let audiobook_block = Block::default()
+        .borders(Borders::ALL)
+        .title(Title::from("Audiobooks"))
+        .border_style(if app.audiobook_selected {
+            Style::default().fg(theme.main_border_color)
+        } else {
+            Style::default().fg(theme.main_inactive_border_color)
+        })
+        .style(Style::default().bg(theme.main_background_color));
+
     let user_artist_block = Block::default()
         .borders(Borders::ALL)
         .title(Title::from("Artists"))
@@ -94,6 +108,8 @@ pub fn render_library(
         String::from("Albums"),
         String::from("Artists"),
         String::from("Podcasts"),
+        String::from("Your Episodes"),
+        String::from("Audiobooks"),
     ];
     // Rendering currently selected menu
     let library_list = List::new(library_items)
@@ -144,10 +160,9 @@ pub fn render_library(
             app.made_fy_artist_names.clone(),
             app.made_fy_album_names.clone(),
             app.made_fy_track_duration.clone(),
+            row_marks(app, &app.made_fy_track_links),
             made_fy_track_block,
-            theme.main_highlight_color,
-            theme.main_background_color,
-            theme.main_inactive_border_color,
+            TableColors::main(theme),
         );
 
         f.render_widget(Clear, content_chunk[1]);
@@ -167,10 +182,9 @@ pub fn render_library(
             app.liked_song_artist_names.clone(),
             app.liked_song_album_names.clone(),
             app.liked_song_duration.clone(),
+            row_marks(app, &app.liked_song_links),
             liked_song_block,
-            theme.main_highlight_color,
-            theme.main_background_color,
-            theme.main_inactive_border_color,
+            TableColors::main(theme),
         );
 
         f.render_widget(Clear, content_chunk[1]);
@@ -190,10 +204,9 @@ pub fn render_library(
             app.recently_played_artist_names.clone(),
             app.recently_played_album_names.clone(),
             app.recently_played_duration.clone(),
+            row_marks(app, &app.recently_played_links),
             recently_played_block,
-            theme.main_highlight_color,
-            theme.main_background_color,
-            theme.main_inactive_border_color,
+            TableColors::main(theme),
         );
 
         f.render_widget(Clear, content_chunk[1]);
@@ -222,6 +235,25 @@ pub fn render_library(
         f.render_stateful_widget(podcast_table, content_chunk[1], &mut app.podcast_state);
     }
 
+    if app.audiobook_display {
+        f.render_widget(Clear, content_chunk[1]);
+
+        let audiobook_table = audiobook_table_ui(
+            app,
+            audiobook_block,
+            theme.main_highlight_color,
+            theme.main_background_color,
+            theme.main_inactive_border_color,
+        );
+
+        f.render_stateful_widget(audiobook_table, content_chunk[1], &mut app.audiobook_state);
+    }
+
+    if app.podcast_episode_display {
+        f.render_widget(Clear, content_chunk[1]);
+        render_show_episodes(f, content_chunk[1], app, theme);
+    }
+
     if app.user_artist_display {
         f.render_widget(Clear, content_chunk[1]);
 
@@ -278,10 +310,9 @@ pub fn render_library(
             app.user_album_track_names.clone(),
             app.user_album_track_artist.clone(),
             app.user_album_track_duration.clone(),
+            row_marks(app, &app.user_album_track_links),
             user_album_track_block,
-            theme.main_highlight_color,
-            theme.main_background_color,
-            theme.main_inactive_border_color,
+            TableColors::main(theme),
         );
 
         f.render_widget(Clear, content_chunk[1]);
@@ -311,10 +342,9 @@ pub fn render_library(
             app.user_artist_track_names.clone(),
             app.user_artist_track_album.clone(),
             app.user_artist_track_duration.clone(),
+            row_marks(app, &app.user_artist_track_links),
             user_artist_track_block,
-            theme.main_highlight_color,
-            theme.main_background_color,
-            theme.main_inactive_border_color,
+            TableColors::main(theme),
         );
 
         f.render_widget(Clear, content_chunk[1]);
@@ -337,6 +367,8 @@ pub fn render_default_library(f: &mut Frame, content_sub_chunk: &[Rect], theme:
         String::from("Albums"),
         String::from("Artists"),
         String::from("Podcasts"),
+        String::from("Your Episodes"),
+        String::from("Audiobooks"),
     ];
 
     let library_block = Block::default()
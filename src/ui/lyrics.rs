@@ -0,0 +1,78 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{block::Title, Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{app::App, lyrics::lrc::current_lyric_index, structs::Themes};
+
+/// Renders the lyrics of the current track, keeping the line being sung in the middle of the pane
+pub fn render_lyrics(f: &mut Frame, area: Rect, app: &mut App, theme: &mut Themes) {
+    let lyrics_block = Block::default()
+        .borders(Borders::ALL)
+        .title(Title::from("Lyrics"))
+        .border_style(Style::default().fg(theme.player_inactive_border_color))
+        .style(
+            Style::default()
+                .bg(theme.player_background_color)
+                .fg(theme.player_inactive_border_color),
+        );
+
+    f.render_widget(Clear, area);
+
+    if app.lyrics_lines.is_empty() {
+        let message = if app.current_playing_name.is_empty() {
+            "Nothing is playing".to_string()
+        } else {
+            format!("No lyrics found for \"{}\"", app.lyrics_loaded_for)
+        };
+        let no_lyrics = Paragraph::new(message)
+            .block(lyrics_block)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+        f.render_widget(no_lyrics, area);
+        return;
+    }
+
+    let current_index = if app.lyrics_synced {
+        current_lyric_index(&app.lyrics_lines, app.currrent_timestamp)
+    } else {
+        None
+    };
+
+    let lines: Vec<Line> = app
+        .lyrics_lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            if Some(index) == current_index {
+                Line::styled(
+                    line.text.clone(),
+                    Style::default()
+                        .fg(theme.player_highlight_color)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Line::from(line.text.clone())
+            }
+        })
+        .collect();
+
+    // Synced lyrics follow the playback, plain lyrics scroll along with the track's progress
+    let inner_height = lyrics_block.inner(area).height as usize;
+    let scroll = match current_index {
+        Some(index) => index.saturating_sub(inner_height / 2),
+        None => {
+            let overflow = lines.len().saturating_sub(inner_height);
+            (overflow as f64 * app.progress_bar_ratio.clamp(0.0, 1.0)) as usize
+        }
+    };
+
+    let lyrics = Paragraph::new(lines)
+        .block(lyrics_block)
+        .alignment(Alignment::Center)
+        .scroll((scroll as u16, 0));
+    f.render_widget(lyrics, area);
+}
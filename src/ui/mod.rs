@@ -1,14 +1,27 @@
+pub mod album_art;
 pub mod blank_screen;
+pub mod device_picker;
+pub mod duplicates;
 pub mod error_screen;
+pub mod export;
 pub mod fullscreen_player;
 pub mod help;
+pub mod import_review;
 pub mod library;
+pub mod lyrics;
 pub mod main_area;
 pub mod new_release;
 pub mod player;
 pub mod playlist_control;
+pub mod queue;
 pub mod search;
+pub mod seek;
+pub mod show;
+pub mod text_input;
+pub mod theme_picker;
+pub mod toast;
 pub mod tui;
 pub mod ui;
 pub mod user_playlist;
 pub mod util;
+pub mod volume;
@@ -7,7 +7,7 @@ use ratatui::{
 
 use crate::{app::App, structs::Themes};
 
-use super::util::{convert_to_list, new_release_table_ui};
+use super::util::{convert_to_list, new_release_table_ui, row_marks, TableColors};
 
 /// Renders a simplified list of new release album names
 pub fn render_default_new_releases(
@@ -83,10 +83,13 @@ pub fn render_new_releases(
             app.new_release_track_names.clone(),
             app.new_release_artist_names.clone(),
             app.new_release_durations_ms.clone(),
+            row_marks(app, &app.new_release_spotify_urls),
             current_new_release_block,
-            theme.new_release_highlight_color.clone(),
-            theme.new_release_background_color.clone(),
-            theme.new_release_inactive_border_color.clone(),
+            TableColors {
+                highlight: theme.new_release_highlight_color,
+                background: theme.new_release_background_color,
+                inactive_border: theme.new_release_inactive_border_color,
+            },
         );
         f.render_widget(Clear, content_chunk[1]);
 
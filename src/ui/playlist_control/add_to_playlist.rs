@@ -6,6 +6,7 @@ use ratatui::{
 
 use crate::{
     app::App,
+    enums::InsertPosition,
     structs::{Key, Themes},
     ui::util::convert_to_list,
 };
@@ -17,9 +18,14 @@ pub fn render_add_track_to_playlist_screen(
     key: &mut Key,
     theme: &mut Themes,
 ) {
+    let insert_position = match app.playlist_insert_position {
+        InsertPosition::End => "at the end".to_string(),
+        InsertPosition::Top => "at the top".to_string(),
+        InsertPosition::At(position) => format!("at position {}", position + 1),
+    };
     let add_playlist_label = format!(
-        "Select a playlist to add {} to (press {} to cancel)",
-        app.track_added_to_playlist_name, key.exit_application_key
+        "Select a playlist to add {} to, {} (Tab to change, {} to cancel)",
+        app.track_added_to_playlist_name, insert_position, key.exit_application_key
     );
     f.render_widget(Clear, f.size());
 
@@ -1 +1,2 @@
 pub mod add_to_playlist;
+pub mod playlist_editor;
@@ -0,0 +1,64 @@
+use ratatui::{
+    style::Style,
+    widgets::{block::Title, Block, Borders, Clear, List, ListItem},
+    Frame,
+};
+
+use crate::{
+    app::App,
+    structs::{Key, Themes},
+    ui::util::centered_rect,
+};
+
+/// Renders the settings of the selected playlist as a popup
+pub fn render_playlist_editor(f: &mut Frame, app: &mut App, key: &mut Key, theme: &mut Themes) {
+    let Some(index) = app.user_playlist_state.selected() else {
+        return;
+    };
+    let yes_no = |value: bool| if value { "Yes" } else { "No" };
+
+    let playlist_editor_label = format!(
+        "Edit {} (Enter to change, {} to go back)",
+        app.user_playlist_names[index], key.exit_application_key
+    );
+    let popup_area = centered_rect(60, 30, f.size());
+
+    let description = if app.playlist_editor_description.is_empty() {
+        "(none)".to_string()
+    } else {
+        app.playlist_editor_description.clone()
+    };
+    let options = vec![
+        ListItem::new(format!("Rename: {}", app.user_playlist_names[index])),
+        ListItem::new(format!("Description: {}", description)),
+        ListItem::new(format!(
+            "Public: {}",
+            yes_no(app.user_playlist_public[index])
+        )),
+        ListItem::new(format!(
+            "Collaborative: {}",
+            yes_no(app.user_playlist_collaborative[index])
+        )),
+    ];
+
+    let playlist_editor_block = Block::default()
+        .borders(Borders::ALL)
+        .title(Title::from(playlist_editor_label))
+        .border_style(Style::new().fg(theme.playlist_border_color))
+        .style(
+            Style::default()
+                .bg(theme.playlist_background_color)
+                .fg(theme.playlist_inactive_border_color),
+        );
+
+    let playlist_editor_list = List::new(options)
+        .block(playlist_editor_block)
+        .highlight_style(Style::default().fg(theme.playlist_highlight_color));
+
+    f.render_widget(Clear, popup_area);
+    f.render_stateful_widget(
+        playlist_editor_list,
+        popup_area,
+        &mut app.playlist_editor_state,
+    );
+}
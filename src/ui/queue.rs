@@ -0,0 +1,45 @@
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    widgets::{block::Title, Block, Borders, Clear},
+    Frame,
+};
+
+use crate::{app::App, structs::Themes};
+
+use super::util::{row_marks, track_table_ui, TableColors};
+
+/// Renders the playback queue in the main area, with the playing item on the first row
+pub fn render_queue(f: &mut Frame, content_chunk: &[Rect], app: &mut App, theme: &mut Themes) {
+    let upcoming = if app.queue_has_current {
+        app.queue_names.len().saturating_sub(1)
+    } else {
+        app.queue_names.len()
+    };
+
+    let queue_block = Block::default()
+        .borders(Borders::ALL)
+        .title(Title::from(format!("Queue ({} up next)", upcoming)))
+        .border_style(Style::default().fg(theme.main_border_color))
+        .style(Style::default().bg(theme.main_background_color));
+
+    let mut queue_names = app.queue_names.clone();
+    if app.queue_has_current {
+        if let Some(current) = queue_names.first_mut() {
+            *current = format!("▶ {}", current);
+        }
+    }
+
+    let queue_table = track_table_ui(
+        queue_names,
+        app.queue_artist_names.clone(),
+        app.queue_album_names.clone(),
+        app.queue_durations.clone(),
+        row_marks(app, &app.queue_links),
+        queue_block,
+        TableColors::main(theme),
+    );
+
+    f.render_widget(Clear, content_chunk[1]);
+    f.render_stateful_widget(queue_table, content_chunk[1], &mut app.queue_state);
+}
@@ -9,7 +9,7 @@ use crate::{
     app::App,
     enums::{InputMode, SearchMenu},
     structs::Themes,
-    ui::util::convert_to_list,
+    ui::{show::render_show_episodes, util::convert_to_list},
 };
 
 use super::{
@@ -71,12 +71,26 @@ pub fn render_search(
         })
         .style(Style::default().bg(theme.search_background_color));
 
+    let show_block = Block::default()
+        .borders(Borders::ALL)
+        .title(Title::from("Shows"))
+        .border_style(if app.selected_show_in_search_result {
+            Style::default().fg(theme.search_border_color)
+        } else {
+            Style::default().fg(theme.search_inactive_border_color)
+        })
+        .style(Style::default().bg(theme.search_background_color));
+
     // Create a Paragraph widget for displaying the search input text
     let search_input = Paragraph::new(app.input.as_str())
         .style(match app.input_mode {
             InputMode::Normal => Style::default(),
             InputMode::Editing => Style::default().fg(theme.search_border_color),
             InputMode::SearchResults => Style::default(),
+            InputMode::HelpSearch => Style::default(),
+            InputMode::SeekInput => Style::default(),
+            InputMode::VolumeInput => Style::default(),
+            InputMode::TextInput => Style::default(),
         })
         .block(Block::default().borders(Borders::ALL).title("Search"))
         .style(
@@ -106,6 +120,7 @@ pub fn render_search(
             let track_names_list = convert_to_list(&app.track_names_search_results);
             let artist_names_list = convert_to_list(&app.artist_names_search_results);
             let playlist_names_list = convert_to_list(&app.playlist_names_search_results);
+            let show_names_list = convert_to_list(&app.show_names_search_results);
 
             let album_list = List::new(album_names_list)
                 .block(album_block.clone())
@@ -119,6 +134,10 @@ pub fn render_search(
                 .block(playlist_block.clone())
                 .highlight_style(Style::default().fg(theme.search_highlight_color));
 
+            let show_list = List::new(show_names_list)
+                .block(show_block.clone())
+                .highlight_style(Style::default().fg(theme.search_highlight_color));
+
             let artist_list = List::new(artist_names_list)
                 .block(artist_block.clone())
                 .highlight_style(Style::default().fg(theme.search_highlight_color));
@@ -143,6 +162,11 @@ pub fn render_search(
                 main_chunk_lower[1],
                 &mut app.playlist_state_in_search_result,
             );
+            f.render_stateful_widget(
+                show_list,
+                main_chunk_lower[2],
+                &mut app.show_state_in_search_result,
+            );
         }
         _ => {}
     }
@@ -161,6 +185,10 @@ pub fn render_search(
             f.render_widget(Clear, content_chunk[1]);
             render_searched_playlist(f, content_chunk, app, theme);
         }
+        SearchMenu::SearchedShow => {
+            f.render_widget(Clear, content_chunk[1]);
+            render_show_episodes(f, content_chunk[1], app, theme);
+        }
     }
 }
 
@@ -5,7 +5,11 @@ use ratatui::{
     Frame,
 };
 
-use crate::{app::App, structs::Themes};
+use crate::{
+    app::App,
+    structs::Themes,
+    ui::util::{row_marks, TableColors},
+};
 
 use super::util::searched_track_table_for_album_ui;
 
@@ -32,10 +36,9 @@ pub fn render_searched_album(
         app.selected_album_tracks_names.clone(),
         app.selected_album_tracks_artists.clone(),
         app.selected_album_tracks_duration.clone(),
+        row_marks(app, &app.selected_album_tracks_links),
         album_block,
-        theme.main_highlight_color,
-        theme.main_background_color,
-        theme.main_inactive_border_color,
+        TableColors::main(theme),
     );
 
     f.render_widget(Clear, content_chunk[1]);
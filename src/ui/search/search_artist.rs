@@ -6,7 +6,11 @@ use ratatui::{
 };
 
 use super::util::searched_track_table_for_artist_ui;
-use crate::{app::App, structs::Themes};
+use crate::{
+    app::App,
+    structs::Themes,
+    ui::util::{row_marks, TableColors},
+};
 
 pub fn render_searched_artist(
     f: &mut Frame,
@@ -31,10 +35,9 @@ pub fn render_searched_artist(
         app.selected_artist_tracks_names.clone(),
         app.selected_artist_track_album_names.clone(),
         app.selected_artist_tracks_duration.clone(),
+        row_marks(app, &app.selected_artist_tracks_links),
         artist_block,
-        theme.main_highlight_color,
-        theme.main_background_color,
-        theme.main_inactive_border_color,
+        TableColors::main(theme),
     );
 
     f.render_widget(Clear, content_chunk[1]);
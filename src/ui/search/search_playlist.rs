@@ -5,7 +5,11 @@ use ratatui::{
     Frame,
 };
 
-use crate::{app::App, structs::Themes, ui::util::track_table_ui};
+use crate::{
+    app::App,
+    structs::Themes,
+    ui::util::{row_marks, track_table_ui, TableColors},
+};
 
 pub fn render_searched_playlist(
     f: &mut Frame,
@@ -31,10 +35,9 @@ pub fn render_searched_playlist(
         app.selected_playlist_tracks_artists.clone(),
         app.selected_playlist_tracks_albums.clone(),
         app.selected_playlist_tracks_duration.clone(),
+        row_marks(app, &app.selected_playlist_tracks_links),
         playlist_block,
-        theme.main_highlight_color,
-        theme.main_background_color,
-        theme.main_inactive_border_color,
+        TableColors::main(theme),
     );
 
     f.render_widget(Clear, content_chunk[1]);
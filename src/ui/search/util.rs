@@ -1,19 +1,18 @@
 use ratatui::{
     layout::Constraint,
-    style::{Color, Style, Stylize},
+    style::Stylize,
     widgets::{Block, Cell, Row, Table},
 };
 
-use crate::ui::util::format_duration;
+use crate::ui::util::{format_duration, liked_cell, RowMarks, TableColors};
 
 pub fn searched_track_table_for_album_ui(
     names: Vec<String>,
     artist_names: Vec<String>,
     duration: Vec<i64>,
+    marks: RowMarks,
     block: Block,
-    highlight_color: Color,
-    background_color: Color,
-    inactive_border_color: Color,
+    colors: TableColors,
 ) -> Table {
     let tracks: Vec<(usize, String, String, String)> = names
         .iter()
@@ -31,15 +30,18 @@ pub fn searched_track_table_for_album_ui(
             .map(|(index, name, artist, duration)| {
                 Row::new(vec![
                     Cell::from(format!("{}", index)),
+                    liked_cell(&marks.liked, *index - 1),
                     Cell::from(name.clone()),
                     Cell::from(artist.clone()),
                     Cell::from(duration.clone()),
                 ])
+                .style(marks.row_style(*index - 1))
             })
             .collect::<Vec<_>>(),
         [
             Constraint::Percentage(5),
-            Constraint::Percentage(57),
+            Constraint::Percentage(3),
+            Constraint::Percentage(54),
             Constraint::Percentage(23),
             Constraint::Percentage(15),
         ],
@@ -47,19 +49,16 @@ pub fn searched_track_table_for_album_ui(
     .header(
         Row::new(vec![
             Cell::from("#"),
+            Cell::from("♥"),
             Cell::from("Title"),
             Cell::from("Artist"),
             Cell::from("Duration"),
         ])
         .bold(),
     )
-    .block(block.clone())
-    .highlight_style(Style::default().fg(highlight_color))
-    .style(
-        Style::default()
-            .bg(background_color)
-            .fg(inactive_border_color),
-    );
+    .block(marks.title_block(block))
+    .highlight_style(colors.highlight_style())
+    .style(colors.style());
 
     table
 }
@@ -68,10 +67,9 @@ pub fn searched_track_table_for_artist_ui(
     names: Vec<String>,
     album_names: Vec<String>,
     duration: Vec<i64>,
+    marks: RowMarks,
     block: Block,
-    highlight_color: Color,
-    background_color: Color,
-    inactive_border_color: Color,
+    colors: TableColors,
 ) -> Table {
     let tracks: Vec<(usize, String, String, String)> = names
         .iter()
@@ -89,15 +87,18 @@ pub fn searched_track_table_for_artist_ui(
             .map(|(index, name, album, duration)| {
                 Row::new(vec![
                     Cell::from(format!("{}", index)),
+                    liked_cell(&marks.liked, *index - 1),
                     Cell::from(name.clone()),
                     Cell::from(album.clone()),
                     Cell::from(duration.clone()),
                 ])
+                .style(marks.row_style(*index - 1))
             })
             .collect::<Vec<_>>(),
         [
             Constraint::Percentage(5),
-            Constraint::Percentage(57),
+            Constraint::Percentage(3),
+            Constraint::Percentage(54),
             Constraint::Percentage(23),
             Constraint::Percentage(15),
         ],
@@ -105,19 +106,16 @@ pub fn searched_track_table_for_artist_ui(
     .header(
         Row::new(vec![
             Cell::from("#"),
+            Cell::from("♥"),
             Cell::from("Title"),
             Cell::from("Album"),
             Cell::from("Duration"),
         ])
         .bold(),
     )
-    .block(block.clone())
-    .highlight_style(Style::default().fg(highlight_color))
-    .style(
-        Style::default()
-            .bg(background_color)
-            .fg(inactive_border_color),
-    );
+    .block(marks.title_block(block))
+    .highlight_style(colors.highlight_style())
+    .style(colors.style());
 
     table
 }
@@ -0,0 +1,31 @@
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    widgets::{block::Title, Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::{app::App, structs::Themes, ui::util::format_duration};
+
+/// Renders the position being typed over the player
+pub fn render_seek_input(f: &mut Frame, player_area: Rect, app: &mut App, theme: &mut Themes) {
+    let seek_label = format!(
+        "Seek to (m:ss, 0:00-{}), Enter to jump",
+        format_duration(app.ending_timestamp.round() as i64)
+    );
+
+    let seek_block = Block::default()
+        .borders(Borders::ALL)
+        .title(Title::from(seek_label))
+        .border_style(Style::new().fg(theme.player_border_color))
+        .style(
+            Style::default()
+                .bg(theme.player_background_color)
+                .fg(theme.player_highlight_color),
+        );
+
+    let seek_input = Paragraph::new(format!("{}_", app.seek_input)).block(seek_block);
+
+    f.render_widget(Clear, player_area);
+    f.render_widget(seek_input, player_area);
+}
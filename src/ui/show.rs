@@ -0,0 +1,59 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    widgets::{block::Title, Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{app::App, structs::Themes, ui::util::episode_table_ui};
+
+/// Renders the episode table, with the show's name and description above it when a show is open
+pub fn render_show_episodes(f: &mut Frame, area: Rect, app: &mut App, theme: &mut Themes) {
+    let (episode_area, episode_label) = if app.selected_show_name.is_empty() {
+        (area, "Your Episodes")
+    } else {
+        // The show description sits above its episodes
+        let show_chunk = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(6), Constraint::Min(0)])
+            .split(area);
+
+        let show_label = if app.selected_show_saved {
+            format!("{} (Saved)", app.selected_show_name)
+        } else {
+            app.selected_show_name.clone()
+        };
+        let description_block = Block::default()
+            .borders(Borders::ALL)
+            .title(Title::from(show_label))
+            .border_style(Style::default().fg(theme.main_inactive_border_color))
+            .style(Style::default().bg(theme.main_background_color));
+
+        let description_paragraph = Paragraph::new(app.selected_show_description.clone())
+            .block(description_block)
+            .wrap(Wrap { trim: true });
+
+        f.render_widget(description_paragraph, show_chunk[0]);
+        (show_chunk[1], "Episodes")
+    };
+
+    let episode_block = Block::default()
+        .borders(Borders::ALL)
+        .title(Title::from(episode_label))
+        .border_style(if app.podcast_episode_selected {
+            Style::default().fg(theme.main_border_color)
+        } else {
+            Style::default().fg(theme.main_inactive_border_color)
+        })
+        .style(Style::default().bg(theme.main_background_color));
+
+    let episode_table = episode_table_ui(
+        app,
+        episode_block,
+        theme.main_highlight_color,
+        theme.main_background_color,
+        theme.main_inactive_border_color,
+    );
+
+    f.render_stateful_widget(episode_table, episode_area, &mut app.podcast_episode_state);
+}
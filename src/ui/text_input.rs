@@ -0,0 +1,28 @@
+use ratatui::{
+    style::Style,
+    widgets::{block::Title, Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::{app::App, structs::Themes, ui::util::centered_rect};
+
+/// Renders the text input dialog over the rest of the screen
+pub fn render_text_input(f: &mut Frame, app: &mut App, theme: &mut Themes) {
+    let text_input_label = format!("{} (Enter to confirm, Esc to cancel)", app.text_input_title);
+    let popup_area = centered_rect(60, 10, f.size());
+
+    let text_input_block = Block::default()
+        .borders(Borders::ALL)
+        .title(Title::from(text_input_label))
+        .border_style(Style::new().fg(theme.main_border_color))
+        .style(
+            Style::default()
+                .bg(theme.main_background_color)
+                .fg(theme.main_highlight_color),
+        );
+
+    let text_input = Paragraph::new(format!("{}_", app.text_input)).block(text_input_block);
+
+    f.render_widget(Clear, popup_area);
+    f.render_widget(text_input, popup_area);
+}
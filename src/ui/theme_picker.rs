@@ -0,0 +1,38 @@
+use ratatui::{
+    style::Style,
+    widgets::{block::Title, Block, Borders, Clear, List},
+    Frame,
+};
+
+use crate::{
+    app::App,
+    structs::{Key, Themes},
+    ui::util::{centered_rect, convert_to_list},
+};
+
+/// Renders the theme picker as a popup so the rest of the screen shows the previewed theme
+pub fn render_theme_picker(f: &mut Frame, app: &mut App, key: &mut Key, theme: &mut Themes) {
+    let theme_picker_label = format!(
+        "Themes (Enter to save, {} to cancel)",
+        key.exit_application_key
+    );
+    let popup_area = centered_rect(40, 50, f.size());
+
+    let theme_picker_block = Block::default()
+        .borders(Borders::ALL)
+        .title(Title::from(theme_picker_label))
+        .border_style(Style::new().fg(theme.main_border_color))
+        .style(
+            Style::default()
+                .bg(theme.main_background_color)
+                .fg(theme.main_inactive_border_color),
+        );
+
+    let theme_names = convert_to_list(&app.theme_names);
+    let theme_list = List::new(theme_names)
+        .block(theme_picker_block)
+        .highlight_style(Style::default().fg(theme.main_highlight_color));
+
+    f.render_widget(Clear, popup_area);
+    f.render_stateful_widget(theme_list, popup_area, &mut app.theme_picker_state);
+}
@@ -0,0 +1,51 @@
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use std::time::Duration;
+
+use crate::{app::App, structs::Themes};
+
+// How long a toast stays on screen
+const TOAST_DURATION: Duration = Duration::from_secs(4);
+
+/// Renders the latest toast in the bottom right corner of `area` until it expires
+pub fn render_toast(f: &mut Frame, area: Rect, app: &mut App, theme: &mut Themes) {
+    if app.toast_message.is_empty() {
+        return;
+    }
+    if app.toast_shown_at.elapsed() >= TOAST_DURATION {
+        app.toast_message.clear();
+        return;
+    }
+
+    let width = (app.toast_message.chars().count() as u16 + 4)
+        .min(area.width / 2)
+        .max(10)
+        .min(area.width);
+    let height = 3.min(area.height);
+    let toast_area = Rect::new(
+        area.x + area.width - width,
+        area.y + area.height - height,
+        width,
+        height,
+    );
+
+    let toast_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::new().fg(theme.main_border_color))
+        .style(
+            Style::default()
+                .bg(theme.main_background_color)
+                .fg(theme.main_highlight_color),
+        );
+
+    let toast = Paragraph::new(app.toast_message.clone())
+        .wrap(Wrap { trim: true })
+        .block(toast_block);
+
+    f.render_widget(Clear, toast_area);
+    f.render_widget(toast, toast_area);
+}
@@ -1,19 +1,32 @@
 use crate::app::App;
-use crate::enums::Menu;
-use crate::structs::{Key, Themes};
+use crate::enums::{ExportSource, ImageProtocol, InputMode, Library, Menu};
+use crate::structs::{Key, Settings, Themes};
 
 use ratatui::prelude::*;
 
+use super::album_art::render_album_art;
+use super::device_picker::render_device_picker;
+use super::duplicates::render_duplicates;
 use super::error_screen::render_error;
+use super::export::render_export;
 use super::fullscreen_player::render_player_in_fullscreen;
 use super::help::{render_default_help, render_help};
+use super::import_review::render_import_review;
 use super::library::{render_default_library, render_library};
+use super::lyrics::render_lyrics;
 use super::main_area::render_main_area;
 use super::new_release::{render_default_new_releases, render_new_releases};
 use super::player::render_player;
 use super::playlist_control::add_to_playlist::render_add_track_to_playlist_screen;
+use super::playlist_control::playlist_editor::render_playlist_editor;
+use super::queue::render_queue;
 use super::search::search::{render_default_search, render_search};
+use super::seek::render_seek_input;
+use super::text_input::render_text_input;
+use super::theme_picker::render_theme_picker;
+use super::toast::render_toast;
 use super::user_playlist::{render_default_user_playlist, render_user_playlist};
+use super::volume::render_volume_input;
 
 /// Renders the main frame of the application's user interface
 pub fn render_frame(
@@ -22,7 +35,11 @@ pub fn render_frame(
     app: &mut App,
     key: &mut Key,
     theme: &mut Themes,
+    settings: &Settings,
 ) {
+    app.album_art_placements.clear();
+    let show_album_art = settings.album_art_protocol != ImageProtocol::Off;
+
     // Calculate the layout constraints
     let size = f.size();
 
@@ -75,17 +92,47 @@ pub fn render_frame(
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(main_chunk[0]);
 
-    // Dividing the midlle screen lower layout into two section: Album and Playlist section
+    // Dividing the midlle screen lower layout into three section: Album, Playlist and Show section
     let main_chunk_lower = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .constraints([
+            Constraint::Percentage(34),
+            Constraint::Percentage(33),
+            Constraint::Percentage(33),
+        ])
         .split(main_chunk[1]);
 
+    // Reserving a square on the left of the player for the album art
+    let album_art_width = if show_album_art {
+        chunks[2].height * 2
+    } else {
+        0
+    };
+    let player_chunk = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(album_art_width), Constraint::Min(0)])
+        .split(chunks[2]);
+
     // Making the live player layout
     let player_layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(chunks[2]);
+        .split(player_chunk[1]);
+
+    // Giving the album cover a column next to the track table of the opened playlist or album
+    let (detail_content_chunk, detail_art_area) = match detail_image_url(selected_menu, app) {
+        Some(image_url) if show_album_art => {
+            let detail_chunk = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
+                .split(content_chunk[1]);
+            (
+                vec![content_chunk[0], detail_chunk[0], content_chunk[2]],
+                Some((detail_chunk[1], image_url)),
+            )
+        }
+        _ => (content_chunk.to_vec(), None),
+    };
 
     let player_fullscreen_vertical_chunk = Layout::default()
         .direction(Direction::Vertical)
@@ -106,8 +153,16 @@ pub fn render_frame(
     render_default_library(f, &content_sub_chunk, theme);
     render_default_user_playlist(f, &content_chunk, app, theme);
     render_player(f, &player_layout, app, theme);
+    let current_playing_image_url = app.current_playing_image_url.clone();
+    render_album_art(
+        f,
+        player_chunk[0],
+        &current_playing_image_url,
+        app,
+        settings,
+    );
     render_main_area(f, &content_chunk, &front_chunk, app, theme);
-    render_default_help(f, &header_chunk, theme);
+    render_default_help(f, &header_chunk, key, theme);
     render_default_new_releases(f, &content_sub_chunk, app, theme);
 
     // Render different sections based on the selected menu
@@ -119,10 +174,10 @@ pub fn render_frame(
             render_main_area(f, &content_chunk, &front_chunk, app, theme);
         }
         Menu::Library => {
-            render_library(f, &content_sub_chunk, &content_chunk, app, theme);
+            render_library(f, &content_sub_chunk, &detail_content_chunk, app, theme);
         }
         Menu::Playlists => {
-            render_user_playlist(f, &content_chunk, app, theme);
+            render_user_playlist(f, &detail_content_chunk, app, theme);
         }
         Menu::Search => {
             render_search(
@@ -136,15 +191,18 @@ pub fn render_frame(
             );
         }
         Menu::Help => {
-            render_help(f, key, theme);
+            app.album_art_placements.clear();
+            render_help(f, app, key, theme);
         }
         Menu::NewRelease => {
-            render_new_releases(f, &content_sub_chunk, &content_chunk, app, theme);
+            render_new_releases(f, &content_sub_chunk, &detail_content_chunk, app, theme);
         }
         Menu::Error => {
+            app.album_art_placements.clear();
             render_error(f, app, key, theme);
         }
         Menu::Player => {
+            app.album_art_placements.clear();
             render_player_in_fullscreen(
                 f,
                 &player_fullscreen_layout,
@@ -152,9 +210,111 @@ pub fn render_frame(
                 app,
                 theme,
             );
+            render_album_art(
+                f,
+                player_fullscreen_vertical_chunk[0],
+                &current_playing_image_url,
+                app,
+                settings,
+            );
+            render_lyrics(f, player_fullscreen_vertical_chunk[2], app, theme);
         }
         Menu::AddTrackToPlaylist => {
+            app.album_art_placements.clear();
             render_add_track_to_playlist_screen(f, app, key, theme);
         }
+        Menu::Queue => {
+            render_queue(f, &content_chunk, app, theme);
+        }
+        Menu::DevicePicker => {
+            app.album_art_placements.clear();
+            render_device_picker(f, app, key, theme);
+        }
+        Menu::ThemePicker => {
+            app.album_art_placements.clear();
+            render_theme_picker(f, app, key, theme);
+        }
+        Menu::PlaylistEditor => {
+            app.album_art_placements.clear();
+            render_user_playlist(f, &detail_content_chunk, app, theme);
+            render_playlist_editor(f, app, key, theme);
+        }
+        Menu::Export => {
+            app.album_art_placements.clear();
+            if app.export_source == ExportSource::Playlist {
+                render_user_playlist(f, &detail_content_chunk, app, theme);
+            } else {
+                render_library(f, &content_sub_chunk, &detail_content_chunk, app, theme);
+            }
+            render_export(f, app, key, theme);
+        }
+        Menu::ImportReview => {
+            app.album_art_placements.clear();
+            render_user_playlist(f, &detail_content_chunk, app, theme);
+            render_import_review(f, app, key, theme);
+        }
+        Menu::Duplicates => {
+            app.album_art_placements.clear();
+            if app.duplicates_in_liked_songs {
+                render_library(f, &content_sub_chunk, &detail_content_chunk, app, theme);
+            } else {
+                render_user_playlist(f, &detail_content_chunk, app, theme);
+            }
+            render_duplicates(f, app, key, theme);
+        }
+    }
+
+    if let Some((area, image_url)) = detail_art_area {
+        render_album_art(f, area, &image_url, app, settings);
+    }
+
+    if app.input_mode == InputMode::SeekInput {
+        render_seek_input(f, player_chunk[1], app, theme);
+    }
+    if app.input_mode == InputMode::VolumeInput {
+        render_volume_input(f, player_chunk[1], app, theme);
+    }
+    if app.input_mode == InputMode::TextInput {
+        app.album_art_placements.clear();
+        render_text_input(f, app, theme);
+    }
+
+    // The lyrics pane takes the place of the playlist list unless the playlists are in use
+    if app.lyrics_display
+        && matches!(
+            selected_menu,
+            Menu::Default
+                | Menu::Main
+                | Menu::Library
+                | Menu::NewRelease
+                | Menu::Search
+                | Menu::Queue
+        )
+    {
+        render_lyrics(f, content_chunk[2], app, theme);
+    }
+
+    render_toast(f, chunks[1], app, theme);
+}
+
+/// Returns the cover of the playlist or album whose tracks are open in the selected menu
+fn detail_image_url(selected_menu: Menu, app: &App) -> Option<String> {
+    match selected_menu {
+        Menu::Playlists if app.user_playlist_display => app
+            .user_playlist_state
+            .selected()
+            .and_then(|index| app.user_playlist_image_urls.get(index)),
+        Menu::Library
+            if app.selected_library == Library::Albums && app.user_album_track_display =>
+        {
+            app.user_album_image_urls.get(app.user_album_index)
+        }
+        Menu::NewRelease if app.new_release_display => app
+            .new_release_state
+            .selected()
+            .and_then(|index| app.new_release_image_urls.get(index)),
+        _ => None,
     }
+    .filter(|image_url| !image_url.is_empty())
+    .cloned()
 }
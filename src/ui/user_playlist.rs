@@ -7,7 +7,7 @@ use ratatui::{
 
 use crate::{app::App, structs::Themes};
 
-use super::util::{convert_to_list, track_table_ui};
+use super::util::{convert_to_list, row_marks, track_table_ui, TableColors};
 
 /// Renders the user playlist UI section, including playlist selection list and track table
 pub fn render_user_playlist(
@@ -61,10 +61,13 @@ pub fn render_user_playlist(
             app.user_playlist_artist_names.clone(),
             app.user_playlist_album_names.clone(),
             app.user_playlist_track_duration.clone(),
+            row_marks(app, &app.user_playlist_track_links),
             user_playlist_block,
-            theme.playlist_highlight_color.clone(),
-            theme.playlist_background_color.clone(),
-            theme.playlist_inactive_border_color.clone(),
+            TableColors {
+                highlight: theme.playlist_highlight_color,
+                background: theme.playlist_background_color,
+                inactive_border: theme.playlist_inactive_border_color,
+            },
         );
 
         f.render_widget(Clear, content_chunk[1]);
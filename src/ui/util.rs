@@ -1,11 +1,15 @@
 use ratatui::{
-    layout::Constraint,
-    style::{Color, Style, Stylize},
-    widgets::{Block, Cell, Row, Table},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    widgets::{block::Title, Block, Cell, Row, Table},
 };
 
 use ratatui::widgets::ListItem;
 
+use crate::app::App;
+use crate::spotify::library_section::saved_tracks::track_id;
+use crate::structs::Themes;
+
 // Helper function to convert a list of strings to a vector of ListItems
 pub fn convert_to_list<'a>(names: &'a [String]) -> Vec<ListItem<'a>> {
     let mut search_results = Vec::new();
@@ -16,6 +20,27 @@ pub fn convert_to_list<'a>(names: &'a [String]) -> Vec<ListItem<'a>> {
     search_results
 }
 
+/// Returns a rectangle of the given percentage size centered inside `area`, used for popups
+pub fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let vertical_chunk = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(area);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(vertical_chunk[1])[1]
+}
+
 /// Formats a duration in milliseconds to minutes and seconds with leading zeros
 pub fn format_duration(duration: i64) -> String {
     let minutes = duration / 60000;
@@ -23,11 +48,122 @@ pub fn format_duration(duration: i64) -> String {
     format!("{}:{:02}", minutes, seconds)
 }
 
+/// Builds the player block title with the playback state, followed by any problem the player thread reported
+pub fn player_title(app: &App) -> String {
+    let mut title = format!(
+        "{} ({} | Shuffle: {} | Repeat: {} | Volume: {}%)",
+        app.playback_status,
+        app.current_device_name,
+        app.shuffle_status,
+        app.repeat_status,
+        app.current_device_volume
+    );
+    if app.currently_playing_media_type == "track"
+        && app.liked_track_ids.get(&app.current_playing_id) == Some(&true)
+    {
+        title.push_str(" ♥");
+    }
+
+    if app.player_status_message.is_empty() {
+        title
+    } else {
+        format!("{} | ⚠ {}", title, app.player_status_message)
+    }
+}
+
+/// Per row state drawn in a track table, which rows are liked and which are part of the selection
+pub struct RowMarks {
+    pub liked: Vec<bool>,
+    pub selected: Vec<bool>,
+}
+
+impl RowMarks {
+    /// Selected rows are drawn reversed
+    pub fn row_style(&self, row: usize) -> Style {
+        if self.selected.get(row).copied().unwrap_or(false) {
+            Style::default().add_modifier(Modifier::REVERSED)
+        } else {
+            Style::default()
+        }
+    }
+
+    /// Adds the number of selected rows to the right of the table title
+    pub fn title_block<'a>(&self, block: Block<'a>) -> Block<'a> {
+        let selected = self.selected.iter().filter(|selected| **selected).count();
+        if selected == 0 {
+            block
+        } else {
+            block.title(Title::from(format!(" {} selected ", selected)).alignment(Alignment::Right))
+        }
+    }
+}
+
+/// Colours of a track table, taken from the part of the theme the table is drawn in
+pub struct TableColors {
+    pub highlight: Color,
+    pub background: Color,
+    pub inactive_border: Color,
+}
+
+impl TableColors {
+    /// Colours of the tables in the main area
+    pub fn main(theme: &Themes) -> Self {
+        TableColors {
+            highlight: theme.main_highlight_color,
+            background: theme.main_background_color,
+            inactive_border: theme.main_inactive_border_color,
+        }
+    }
+
+    pub fn highlight_style(&self) -> Style {
+        Style::default().fg(self.highlight)
+    }
+
+    pub fn style(&self) -> Style {
+        Style::default()
+            .bg(self.background)
+            .fg(self.inactive_border)
+    }
+}
+
+/// Looks up which of `track_links` are liked and selected, unchecked tracks count as not liked
+pub fn row_marks(app: &App, track_links: &[String]) -> RowMarks {
+    let liked = track_links
+        .iter()
+        .map(|link| {
+            track_id(link)
+                .and_then(|id| app.liked_track_ids.get(&id).copied())
+                .unwrap_or(false)
+        })
+        .collect();
+
+    // The selection belongs to the table it was made in
+    let mut selected = vec![false; track_links.len()];
+    if app.track_selection_links == track_links {
+        for row in &app.track_selection_rows {
+            if let Some(selected) = selected.get_mut(*row) {
+                *selected = true;
+            }
+        }
+    }
+
+    RowMarks { liked, selected }
+}
+
+/// Builds the heart cell of a track row
+pub fn liked_cell(liked: &[bool], row: usize) -> Cell<'static> {
+    if liked.get(row).copied().unwrap_or(false) {
+        Cell::from("♥")
+    } else {
+        Cell::from("")
+    }
+}
+
 /*
  Creates a table UI for displaying track information.
 
- This function takes various data about tracks (names, artists, albums, durations),
- a styling block, highlight color, and background color, and returns a Table widget
+ This function takes various data about tracks (names, artists, albums, durations,
+ liked state), a styling block, highlight color, and background color, and returns a Table widget
  configured to display the track information.
 */
 pub fn track_table_ui(
@@ -35,10 +171,9 @@ pub fn track_table_ui(
     artist_names: Vec<String>,
     album_names: Vec<String>,
     duration: Vec<i64>,
+    marks: RowMarks,
     block: Block,
-    highlight_color: Color,
-    background_color: Color,
-    inactive_border_color: Color,
+    colors: TableColors,
 ) -> Table {
     let tracks: Vec<(usize, String, String, String, String)> = names
         .iter()
@@ -63,16 +198,19 @@ pub fn track_table_ui(
             .map(|(index, name, artist, albums, duration)| {
                 Row::new(vec![
                     Cell::from(format!("{}", index)),
+                    liked_cell(&marks.liked, *index - 1),
                     Cell::from(name.clone()),
                     Cell::from(artist.clone()),
                     Cell::from(albums.clone()),
                     Cell::from(duration.clone()),
                 ])
+                .style(marks.row_style(*index - 1))
             })
             .collect::<Vec<_>>(),
         [
             Constraint::Percentage(3),
-            Constraint::Percentage(37),
+            Constraint::Percentage(3),
+            Constraint::Percentage(34),
             Constraint::Percentage(20),
             Constraint::Percentage(30),
             Constraint::Percentage(10),
@@ -81,6 +219,7 @@ pub fn track_table_ui(
     .header(
         Row::new(vec![
             Cell::from("#"),
+            Cell::from("♥"),
             Cell::from("Title"),
             Cell::from("Artist"),
             Cell::from("Album"),
@@ -88,13 +227,9 @@ pub fn track_table_ui(
         ])
         .bold(),
     )
-    .block(block.clone())
-    .highlight_style(Style::default().fg(highlight_color))
-    .style(
-        Style::default()
-            .bg(background_color)
-            .fg(inactive_border_color),
-    );
+    .block(marks.title_block(block))
+    .highlight_style(colors.highlight_style())
+    .style(colors.style());
 
     table
 }
@@ -246,41 +381,48 @@ pub fn artist_table_ui(
 }
 
 pub fn help_table_ui(
-    task: Vec<String>,
-    first_key: Vec<String>,
+    entries: Vec<(String, String, String)>,
     block: Block,
     highlight_color: Color,
     background_color: Color,
 ) -> Table {
-    let items: Vec<(usize, String, String)> = task
-        .iter()
-        .enumerate()
-        .zip(first_key.iter())
-        .map(|((index, task), first_key)| (index + 1, task.clone(), first_key.clone()))
+    // Only show the context name on the first row of each group
+    let mut previous_context = String::new();
+    let items: Vec<(String, String, String)> = entries
+        .into_iter()
+        .map(|(context, task, key)| {
+            let context_label = if context == previous_context {
+                String::new()
+            } else {
+                context.clone()
+            };
+            previous_context = context;
+            (context_label, task, key)
+        })
         .collect();
 
     let table = Table::new(
         items
             .iter()
-            .map(|(index, task, first_key)| {
+            .map(|(context, task, key)| {
                 Row::new(vec![
-                    Cell::from(format!("{}", index)),
+                    Cell::from(context.clone()).bold(),
                     Cell::from(task.clone()),
-                    Cell::from(first_key.clone()),
+                    Cell::from(key.clone()),
                 ])
             })
             .collect::<Vec<_>>(),
         [
-            Constraint::Percentage(10),
+            Constraint::Percentage(20),
             Constraint::Percentage(50),
-            Constraint::Percentage(40),
+            Constraint::Percentage(30),
         ],
     )
     .header(
         Row::new(vec![
-            Cell::from("#"),
+            Cell::from("Context"),
             Cell::from("Task"),
-            Cell::from("First Key"),
+            Cell::from("Key"),
         ])
         .bold(),
     )
@@ -295,10 +437,9 @@ pub fn new_release_table_ui(
     names: Vec<String>,
     artist_names: Vec<String>,
     duration: Vec<i64>,
+    marks: RowMarks,
     block: Block,
-    highlight_color: Color,
-    background_color: Color,
-    inactive_border_color: Color,
+    colors: TableColors,
 ) -> Table {
     let tracks: Vec<(usize, String, String, String)> = names
         .iter()
@@ -316,15 +457,18 @@ pub fn new_release_table_ui(
             .map(|(index, name, artist, duration)| {
                 Row::new(vec![
                     Cell::from(format!("{}", index)),
+                    liked_cell(&marks.liked, *index - 1),
                     Cell::from(name.clone()),
                     Cell::from(artist.clone()),
                     Cell::from(duration.clone()),
                 ])
+                .style(marks.row_style(*index - 1))
             })
             .collect::<Vec<_>>(),
         [
             Constraint::Percentage(3),
-            Constraint::Percentage(47),
+            Constraint::Percentage(3),
+            Constraint::Percentage(44),
             Constraint::Percentage(40),
             Constraint::Percentage(10),
         ],
@@ -332,19 +476,16 @@ pub fn new_release_table_ui(
     .header(
         Row::new(vec![
             Cell::from("#"),
+            Cell::from("♥"),
             Cell::from("Title"),
             Cell::from("Artist"),
             Cell::from("Duration"),
         ])
         .bold(),
     )
-    .block(block.clone())
-    .highlight_style(Style::default().fg(highlight_color))
-    .style(
-        Style::default()
-            .bg(background_color)
-            .fg(inactive_border_color),
-    );
+    .block(marks.title_block(block))
+    .highlight_style(colors.highlight_style())
+    .style(colors.style());
 
     table
 }
@@ -399,3 +540,163 @@ pub fn made_fy_table_ui(
 
     table
 }
+
+pub fn device_table_ui(
+    names: Vec<String>,
+    types: Vec<String>,
+    volumes: Vec<String>,
+    active: Vec<bool>,
+    block: Block,
+    highlight_color: Color,
+    background_color: Color,
+) -> Table {
+    let table = Table::new(
+        names
+            .iter()
+            .zip(types.iter())
+            .zip(volumes.iter())
+            .zip(active.iter())
+            .map(|(((name, device_type), volume), active)| {
+                Row::new(vec![
+                    Cell::from(if *active { "▶" } else { "" }),
+                    Cell::from(name.clone()),
+                    Cell::from(device_type.clone()),
+                    Cell::from(volume.clone()),
+                ])
+            })
+            .collect::<Vec<_>>(),
+        [
+            Constraint::Percentage(5),
+            Constraint::Percentage(55),
+            Constraint::Percentage(25),
+            Constraint::Percentage(15),
+        ],
+    )
+    .header(
+        Row::new(vec![
+            Cell::from(""),
+            Cell::from("Name"),
+            Cell::from("Type"),
+            Cell::from("Volume"),
+        ])
+        .bold(),
+    )
+    .block(block.clone())
+    .highlight_style(Style::default().fg(highlight_color))
+    .style(Style::default().bg(background_color));
+
+    table
+}
+
+/// Creates a table of the opened podcast's episodes with their release date, length and where playback resumes
+pub fn episode_table_ui<'a>(
+    app: &App,
+    block: Block<'a>,
+    highlight_color: Color,
+    background_color: Color,
+    inactive_border_color: Color,
+) -> Table<'a> {
+    let rows: Vec<Row> = (0..app.podcast_episode_names.len())
+        .map(|index| {
+            let resume_point = app.podcast_episode_resume_points[index];
+            let fully_played = app.podcast_episode_fully_played[index];
+            let resume = if fully_played || resume_point == 0 {
+                String::new()
+            } else {
+                format_duration(resume_point)
+            };
+
+            Row::new(vec![
+                Cell::from(format!("{}", index + 1)),
+                Cell::from(app.podcast_episode_names[index].clone()),
+                Cell::from(app.podcast_episode_dates[index].clone()),
+                Cell::from(format_duration(app.podcast_episode_durations[index])),
+                Cell::from(resume),
+                Cell::from(if fully_played { "✓" } else { "" }),
+                Cell::from(if app.podcast_episode_saved[index] {
+                    "♥"
+                } else {
+                    ""
+                }),
+            ])
+        })
+        .collect();
+
+    Table::new(
+        rows,
+        [
+            Constraint::Percentage(5),
+            Constraint::Percentage(45),
+            Constraint::Percentage(14),
+            Constraint::Percentage(9),
+            Constraint::Percentage(9),
+            Constraint::Percentage(9),
+            Constraint::Percentage(9),
+        ],
+    )
+    .header(
+        Row::new(vec![
+            Cell::from("#"),
+            Cell::from("Title"),
+            Cell::from("Released"),
+            Cell::from("Length"),
+            Cell::from("Resume"),
+            Cell::from("Played"),
+            Cell::from("Saved"),
+        ])
+        .bold(),
+    )
+    .block(block)
+    .highlight_style(Style::default().fg(highlight_color))
+    .style(
+        Style::default()
+            .bg(background_color)
+            .fg(inactive_border_color),
+    )
+}
+
+/// Creates a table of the user's saved audiobooks with their authors and narrators
+pub fn audiobook_table_ui<'a>(
+    app: &App,
+    block: Block<'a>,
+    highlight_color: Color,
+    background_color: Color,
+    inactive_border_color: Color,
+) -> Table<'a> {
+    let rows: Vec<Row> = (0..app.audiobook_names.len())
+        .map(|index| {
+            Row::new(vec![
+                Cell::from(format!("{}", index + 1)),
+                Cell::from(app.audiobook_names[index].clone()),
+                Cell::from(app.audiobook_authors[index].clone()),
+                Cell::from(app.audiobook_narrators[index].clone()),
+            ])
+        })
+        .collect();
+
+    Table::new(
+        rows,
+        [
+            Constraint::Percentage(10),
+            Constraint::Percentage(40),
+            Constraint::Percentage(25),
+            Constraint::Percentage(25),
+        ],
+    )
+    .header(
+        Row::new(vec![
+            Cell::from("#"),
+            Cell::from("Title"),
+            Cell::from("Authors"),
+            Cell::from("Narrators"),
+        ])
+        .bold(),
+    )
+    .block(block)
+    .highlight_style(Style::default().fg(highlight_color))
+    .style(
+        Style::default()
+            .bg(background_color)
+            .fg(inactive_border_color),
+    )
+}
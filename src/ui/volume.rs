@@ -0,0 +1,31 @@
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    widgets::{block::Title, Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::{app::App, structs::Themes};
+
+/// Renders the volume being typed over the player
+pub fn render_volume_input(f: &mut Frame, player_area: Rect, app: &mut App, theme: &mut Themes) {
+    let volume_label = format!(
+        "Set volume (0-100, now {}%), Enter to apply",
+        app.current_device_volume
+    );
+
+    let volume_block = Block::default()
+        .borders(Borders::ALL)
+        .title(Title::from(volume_label))
+        .border_style(Style::new().fg(theme.player_border_color))
+        .style(
+            Style::default()
+                .bg(theme.player_background_color)
+                .fg(theme.player_highlight_color),
+        );
+
+    let volume_input = Paragraph::new(format!("{}_", app.volume_input)).block(volume_block);
+
+    f.render_widget(Clear, player_area);
+    f.render_widget(volume_input, player_area);
+}
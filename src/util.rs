@@ -3,11 +3,18 @@ extern crate serde_yaml;
 extern crate yaml_rust;
 
 use crate::app::App;
-use crate::settings::keybindings::{parse_keybindings, read_keybindings, set_keybindings};
+use crate::enums::ImageProtocol;
+use crate::settings::keybindings::{build_help_entries, read_keybindings, set_keybindings};
 use crate::settings::settings::set_settings_values;
 use crate::settings::theme::{read_theme, set_theme};
+use crate::spotify::album_art::fetch_album_art::fetch_album_art;
+use crate::spotify::library_section::saved_tracks::{check_saved_tracks, track_id};
 use crate::spotify::new_release_section::new_releases::{new_releases, process_new_releases};
+use crate::spotify::player::devices::activate_preferred_device;
 use crate::spotify::player::player::{currently_playing, process_currently_playing};
+use crate::spotify::player::queue::{process_queue, user_queue};
+use crate::spotify::player::set_volume::apply_remembered_volume;
+use crate::spotify::player::util::rate_limit_retry_after;
 use crate::spotify::user_playlist::user_playlist::{get_playlists, process_user_playlists};
 use crate::spotify::user_stats::top_tracks::top_tracks;
 use crate::structs::Themes;
@@ -16,35 +23,215 @@ use std::fs::File;
 use std::io::{BufWriter, Write};
 use std::path::PathBuf;
 use std::sync::mpsc;
-use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+// Number of polls between attempts to activate the preferred device
+const PREFERRED_DEVICE_RETRY_POLLS: u32 = 30;
 
 /// Function to update the player information in a separate thread
-pub fn update_player_info(tx: mpsc::Sender<()>, app: &mut App, settings: &mut Settings) {
+///
+/// Sends `None` after each successful poll and a message describing the problem when polling fails.
+/// Whether the playing and queued tracks are liked goes through `liked_tx`, so the UI never waits on it.
+/// The poll interval depends on whether something is playing, and `wake_rx` cuts the wait short after user actions.
+pub fn update_player_info(
+    tx: mpsc::Sender<Option<String>>,
+    liked_tx: mpsc::Sender<Vec<(String, bool)>>,
+    wake_rx: mpsc::Receiver<()>,
+    app: &mut App,
+    settings: &mut Settings,
+) {
+    let mut polls_since_queue_update = 0;
+    let mut last_playing_id = String::new();
+    let mut polls_since_device_attempt = PREFERRED_DEVICE_RETRY_POLLS;
+    let mut failed_polls: u32 = 0;
+    let mut last_device_name = String::new();
+    // Why the preferred device could not be activated, shown in the player bar
+    let mut device_message: Option<String> = None;
+
     loop {
         // Get the user's current playback
-        currently_playing(app).unwrap();
-        process_currently_playing(app, settings);
+        let (wait, wakeable) = match currently_playing(app) {
+            Ok(()) => {
+                failed_polls = 0;
+                process_currently_playing(app, settings);
+
+                // Bring back the volume last chosen on a device when playback moves to it
+                if !app.current_device_name.is_empty()
+                    && app.current_device_name != last_device_name
+                {
+                    if !last_device_name.is_empty() {
+                        apply_remembered_volume(app, settings);
+                    }
+                    last_device_name = app.current_device_name.clone();
+                }
+
+                // Wake up the preferred device when nothing is playing anywhere, retrying now and then
+                // in case the device was offline
+                polls_since_device_attempt += 1;
+                if app
+                    .current_device_id
+                    .as_deref()
+                    .unwrap_or_default()
+                    .is_empty()
+                    && !settings.preferred_device.is_empty()
+                    && polls_since_device_attempt >= PREFERRED_DEVICE_RETRY_POLLS
+                {
+                    polls_since_device_attempt = 0;
+                    device_message = match activate_preferred_device(app, settings) {
+                        Ok(true) => {
+                            let _ = currently_playing(app);
+                            process_currently_playing(app, settings);
+                            None
+                        }
+                        Ok(false) => None,
+                        Err(e) => Some(format!(
+                            "Cannot switch to \"{}\" ({})",
+                            settings.preferred_device, e
+                        )),
+                    };
+                } else if !app.current_device_name.is_empty() {
+                    device_message = None;
+                }
+
+                // The queue only changes when the track changes or the user queues something, so it is
+                // fetched on track changes and every few polls to pick up changes made on other devices
+                polls_since_queue_update += 1;
+                if app.current_playing_id != last_playing_id || polls_since_queue_update >= 5 {
+                    let _ = user_queue(app);
+                    polls_since_queue_update = 0;
+                    last_playing_id = app.current_playing_id.clone();
+
+                    // Forget the last check so tracks liked or unliked elsewhere are picked up,
+                    // the playing and queued tracks fit in a single request
+                    process_queue(app);
+                    app.liked_track_ids.clear();
+                    let mut track_links = app.queue_links.clone();
+                    if app.currently_playing_media_type == "track" {
+                        track_links.push(app.current_playing_id.clone());
+                    }
+                    if check_saved_tracks(app, &track_links).is_ok() {
+                        let liked: Vec<(String, bool)> = track_links
+                            .iter()
+                            .filter_map(|link| track_id(link))
+                            .filter_map(|id| {
+                                let liked = app.liked_track_ids.get(&id).copied()?;
+                                Some((id, liked))
+                            })
+                            .collect();
+                        if liked_tx.send(liked).is_err() {
+                            break;
+                        }
+                    }
+                }
 
-        // Send a message to the main thread to update the UI
-        if tx.send(()).is_err() {
+                // Download the cover of the current item once, the UI reads it from the cache
+                if settings.album_art_protocol != ImageProtocol::Off {
+                    let _ = fetch_album_art(&app.current_playing_image_url);
+                }
+
+                // Send a message to the main thread to update the UI
+                if tx.send(None).is_err() {
+                    break;
+                }
+                // Sent after the update so it is not cleared by it, and kept until the next attempt
+                if let Some(message) = &device_message {
+                    if tx.send(Some(message.clone())).is_err() {
+                        break;
+                    }
+                }
+
+                (poll_interval(app, settings), true)
+            }
+            Err(e) => {
+                failed_polls += 1;
+                let (wait, message, wakeable) = match rate_limit_retry_after(&e) {
+                    // User actions must not cut a rate limit wait short
+                    Some(retry_after) => (
+                        retry_after,
+                        format!(
+                            "Rate limited by Spotify, retrying in {}s",
+                            retry_after.as_secs()
+                        ),
+                        false,
+                    ),
+                    None => {
+                        let wait = error_backoff(settings, failed_polls);
+                        (
+                            wait,
+                            format!(
+                                "Cannot reach Spotify ({}), retrying in {}s",
+                                e,
+                                wait.as_secs().max(1)
+                            ),
+                            true,
+                        )
+                    }
+                };
+
+                if tx.send(Some(message)).is_err() {
+                    break;
+                }
+                (wait, wakeable)
+            }
+        };
+
+        if !wait_for_next_poll(&wake_rx, wait, wakeable) {
             break;
         }
+    }
+}
 
-        // Wait one second before fetching playback again
-        thread::sleep(Duration::from_millis(900));
+/// Picks the time until the next poll: short while playing, longer when paused or when nothing is playing
+fn poll_interval(app: &App, settings: &Settings) -> Duration {
+    if app.current_playing_name.is_empty() {
+        return Duration::from_millis(settings.idle_poll_interval);
+    }
+    if !app.is_playing {
+        return Duration::from_millis(settings.paused_poll_interval);
+    }
+
+    // Poll right after the track ends so the next one shows up without delay
+    let remaining_ms = (app.ending_timestamp - app.currrent_timestamp).max(0.0) as u64 + 500;
+    Duration::from_millis(settings.poll_interval.min(remaining_ms))
+}
+
+/// Doubles the wait after every failed poll, up to a minute
+fn error_backoff(settings: &Settings, failed_polls: u32) -> Duration {
+    let backoff_ms = settings
+        .poll_interval
+        .max(1000)
+        .saturating_mul(2u64.saturating_pow(failed_polls.min(6)));
+    Duration::from_millis(backoff_ms.min(60_000))
+}
+
+/// Sleeps for `wait`, returning early when woken by a user action if `wakeable`.
+/// Returns false once the main thread is gone.
+fn wait_for_next_poll(wake_rx: &mpsc::Receiver<()>, wait: Duration, wakeable: bool) -> bool {
+    let deadline = Instant::now() + wait;
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return true;
+        }
+        match wake_rx.recv_timeout(remaining) {
+            Ok(()) if wakeable => {
+                // Several quick actions only need one poll
+                while wake_rx.try_recv().is_ok() {}
+                return true;
+            }
+            Ok(()) => {}
+            Err(mpsc::RecvTimeoutError::Timeout) => return true,
+            Err(mpsc::RecvTimeoutError::Disconnected) => return false,
+        }
     }
 }
 
 /// Function to run before starting the main app loop
 pub fn startup(app: &mut App, key: &mut Key, theme: &mut Themes, settings: &mut Settings) {
-    key.tasks.clear();
-    key.first_keys.clear();
-
     // Set the keybindings from the configure files
     read_keybindings(app);
     set_keybindings(app, key);
-    parse_keybindings(app, key);
+    build_help_entries(key);
 
     // Set the settings
     set_settings_values(app, settings);